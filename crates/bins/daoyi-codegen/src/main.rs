@@ -0,0 +1,285 @@
+mod render;
+mod table;
+
+use daoyi_common_support::configs::AppConfig;
+use daoyi_common_support::database;
+use sea_orm::DbBackend;
+use similar::TextDiff;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+use table::Table;
+
+const USAGE: &str = "用法: daoyi-codegen <表名> [--dry-run] [--force] [--ts-dir <目录>]
+  <表名>          如 system_post 或 system.system_post，未指定 schema 时使用 system
+  --dry-run       只输出将要产生的变更，不写入文件
+  --force         覆盖已存在的实体、服务、接口与 TypeScript 文件
+  --ts-dir <目录> TypeScript 类型的输出目录，默认 target/codegen/api";
+
+/// 目前只有 `system` 模块按实体、服务、VO、接口分层
+const SUPPORTED_SCHEMAS: [&str; 1] = ["system"];
+
+struct Args {
+    schema: String,
+    table: String,
+    dry_run: bool,
+    force: bool,
+    ts_dir: PathBuf,
+}
+
+/// 一个待写入的文件及其写入方式
+struct Output {
+    path: PathBuf,
+    content: String,
+    /// 整个文件由生成器产生，已存在时需要 `--force` 才覆盖
+    generated: bool,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = parse_args()?;
+    if !Path::new("crates/libs/daoyi-api-system").is_dir() {
+        anyhow::bail!("请在项目根目录执行");
+    }
+
+    let config = Arc::new(AppConfig::read(env!("CARGO_PKG_NAME")).await?);
+    AppConfig::install(config.clone());
+    if config.database().backend()? != DbBackend::Postgres {
+        anyhow::bail!("代码生成需要从 PostgreSQL 读取表结构，请使用 postgres 数据库配置");
+    }
+    let db = database::connect(config.database()).await?;
+    let table = table::read_table(&db, &args.schema, &args.table).await?;
+
+    for output in outputs(&table, &args.ts_dir)? {
+        let old = std::fs::read_to_string(&output.path).ok();
+        let path = output.path.display();
+        if old.as_deref() == Some(output.content.as_str()) {
+            println!("无变化 {path}");
+            continue;
+        }
+        if old.is_some() && output.generated && !args.force {
+            println!("跳过 {path}（文件已存在，使用 --force 覆盖）");
+            continue;
+        }
+        if args.dry_run {
+            print!(
+                "{}",
+                TextDiff::from_lines(old.as_deref().unwrap_or_default(), &output.content)
+                    .unified_diff()
+                    .header(&format!("a/{path}"), &format!("b/{path}"))
+            );
+            continue;
+        }
+        if let Some(parent) = output.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&output.path, &output.content)?;
+        println!("{} {path}", if old.is_some() { "更新" } else { "创建" });
+    }
+    Ok(())
+}
+
+fn parse_args() -> anyhow::Result<Args> {
+    let mut name = None;
+    let mut dry_run = false;
+    let mut force = false;
+    let mut ts_dir = PathBuf::from("target/codegen/api");
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--dry-run" => dry_run = true,
+            "--force" => force = true,
+            "--ts-dir" => {
+                ts_dir = args
+                    .next()
+                    .map(PathBuf::from)
+                    .ok_or_else(|| anyhow::anyhow!("--ts-dir 缺少目录\n{USAGE}"))?;
+            }
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                std::process::exit(0);
+            }
+            _ if arg.starts_with('-') || name.is_some() => {
+                anyhow::bail!("无法识别的参数: {arg}\n{USAGE}")
+            }
+            _ => name = Some(arg),
+        }
+    }
+    let name = name.ok_or_else(|| anyhow::anyhow!("缺少表名\n{USAGE}"))?;
+    let (schema, table) = name.split_once('.').unwrap_or(("system", &name));
+    if !SUPPORTED_SCHEMAS.contains(&schema) {
+        anyhow::bail!("暂不支持 {schema} schema 的代码生成");
+    }
+    Ok(Args {
+        schema: schema.to_string(),
+        table: table.to_string(),
+        dry_run,
+        force,
+        ts_dir,
+    })
+}
+
+/// 生成的文件，以及需要登记模块、实体别名与路由的已有文件
+fn outputs(table: &Table, ts_dir: &Path) -> anyhow::Result<Vec<Output>> {
+    let schema = &table.schema;
+    let business = table.business();
+    let entity_dir = PathBuf::from(format!("crates/libs/daoyi-entity-{schema}/src"));
+    let api_dir = PathBuf::from(format!("crates/libs/daoyi-api-{schema}/src/{schema}_api"));
+    let vo_file = PathBuf::from(format!(
+        "crates/libs/daoyi-common-support/src/vo/{schema}_vo/mod.rs"
+    ));
+    let entity_mod = entity_dir.join(format!("{schema}_entity"));
+    let service_mod = entity_dir.join(format!("{schema}_service"));
+
+    let mut outputs = vec![
+        Output {
+            path: entity_mod.join(format!("{}.rs", table.name)),
+            content: format_rust(&render::entity(table)),
+            generated: true,
+        },
+        Output {
+            path: service_mod.join(format!("{}_service.rs", table.name)),
+            content: format_rust(&render::service(table)),
+            generated: true,
+        },
+        Output {
+            path: api_dir.join(format!("{business}.rs")),
+            content: format_rust(&render::api(table)),
+            generated: true,
+        },
+        Output {
+            path: ts_dir.join(schema).join(business).join("index.ts"),
+            content: render::typescript(table),
+            generated: true,
+        },
+    ];
+
+    let vo = std::fs::read_to_string(&vo_file)?;
+    let class = table.class_name();
+    if ["SaveReqVO", "PageReqVO", "RespVO"]
+        .iter()
+        .any(|suffix| vo.contains(&format!("pub struct {class}{suffix} ")))
+    {
+        println!(
+            "跳过 {}（{class} 的 VO 已存在，请手动调整）",
+            vo_file.display()
+        );
+    } else {
+        outputs.push(Output {
+            content: vo + "\n" + &format_rust(&render::vo(table)),
+            path: vo_file,
+            generated: false,
+        });
+    }
+
+    let registrations = [
+        (
+            entity_mod.join("mod.rs"),
+            "pub mod ",
+            format!("pub mod {};", table.name),
+        ),
+        (
+            entity_mod.join("prelude.rs"),
+            "pub use super::",
+            format!(
+                "pub use super::{}::Entity as {};",
+                table.name,
+                table.entity_name()
+            ),
+        ),
+        (
+            service_mod.join("mod.rs"),
+            "pub mod ",
+            format!("pub mod {}_service;", table.name),
+        ),
+        (api_dir.join("mod.rs"), "mod ", format!("mod {business};")),
+        (
+            api_dir.join("mod.rs"),
+            "        .nest(",
+            format!(
+                "        .nest(\"/{}\", {business}::create_router())",
+                table.path()
+            ),
+        ),
+    ];
+    for (path, prefix, line) in registrations {
+        let content = match outputs.iter().position(|o| o.path == path) {
+            Some(index) => outputs.remove(index).content,
+            None => std::fs::read_to_string(&path)?,
+        };
+        outputs.push(Output {
+            content: insert_sorted_line(&content, prefix, &line),
+            path,
+            generated: false,
+        });
+    }
+    Ok(outputs)
+}
+
+/// 在以 `prefix` 开头的连续行中按字母序插入 `line`，已存在时原样返回
+fn insert_sorted_line(content: &str, prefix: &str, line: &str) -> String {
+    let mut lines = content.lines().collect::<Vec<_>>();
+    if lines.contains(&line) {
+        return content.to_string();
+    }
+    let group = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| l.starts_with(prefix))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    let index = group
+        .iter()
+        .find(|&&i| lines[i] > line)
+        .copied()
+        .or_else(|| group.last().map(|i| i + 1))
+        .unwrap_or(lines.len());
+    lines.insert(index, line);
+    let mut result = lines.join("\n");
+    if content.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+/// 使用 rustfmt 统一生成代码的格式，未安装 rustfmt 时保留原样
+fn format_rust(source: &str) -> String {
+    let child = Command::new("rustfmt")
+        .args(["--edition", "2024", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn();
+    let Ok(mut child) = child else {
+        return source.to_string();
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(source.as_bytes());
+    }
+    match child.wait_with_output() {
+        Ok(output) if output.status.success() => {
+            String::from_utf8(output.stdout).unwrap_or_else(|_| source.to_string())
+        }
+        _ => source.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::insert_sorted_line;
+
+    #[test]
+    fn insert_line_in_order() {
+        let content = "mod auth;\nmod user;\n\npub fn create_router() {}\n";
+        assert_eq!(
+            insert_sorted_line(content, "mod ", "mod post;"),
+            "mod auth;\nmod post;\nmod user;\n\npub fn create_router() {}\n"
+        );
+        assert_eq!(
+            insert_sorted_line(content, "mod ", "mod zone;"),
+            "mod auth;\nmod user;\nmod zone;\n\npub fn create_router() {}\n"
+        );
+        assert_eq!(insert_sorted_line(content, "mod ", "mod user;"), content);
+    }
+}
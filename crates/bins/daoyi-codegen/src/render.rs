@@ -0,0 +1,456 @@
+//! 按项目约定生成实体、服务、接口、VO 与 TypeScript 类型的源码
+
+use crate::table::{ColumnKind, Table};
+use std::fmt::Write;
+
+const GENERATED: &str = "@generated by daoyi-codegen";
+
+/// 实体，附带 `Model` 到 `RespVO` 的转换
+pub fn entity(table: &Table) -> String {
+    let class = table.class_name();
+    let mut out = String::new();
+    writeln!(out, "//! `SeaORM` Entity, {GENERATED}").unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "use daoyi_common_support::vo::{}_vo::{class}RespVO;",
+        table.schema
+    )
+    .unwrap();
+    out.push_str(
+        "use daoyi_macros::{DaoyiActiveModelBehavior, daoyi_model};\n\
+         use sea_orm::entity::prelude::*;\n\
+         use serde::{Deserialize, Serialize};\n\n",
+    );
+    out.push_str("#[daoyi_model]\n");
+    if table.has_float() {
+        out.push_str("#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize, DaoyiActiveModelBehavior)]\n");
+    } else {
+        out.push_str("#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize, DaoyiActiveModelBehavior)]\n");
+    }
+    writeln!(
+        out,
+        "#[sea_orm(schema_name = \"{}\", table_name = \"{}\")]",
+        table.schema, table.name
+    )
+    .unwrap();
+    out.push_str("#[serde(rename_all = \"camelCase\")]\npub struct Model {\n");
+    out.push_str("    #[sea_orm(primary_key, auto_increment = false)]\n    pub id: String,\n");
+    for column in &table.columns {
+        match (column.kind.column_type(), column.nullable) {
+            (Some(column_type), true) => {
+                writeln!(
+                    out,
+                    "    #[sea_orm(column_type = \"{column_type}\", nullable)]"
+                )
+                .unwrap();
+            }
+            (Some(column_type), false) => {
+                writeln!(out, "    #[sea_orm(column_type = \"{column_type}\")]").unwrap();
+            }
+            _ => {}
+        }
+        writeln!(
+            out,
+            "    pub {}: {},",
+            column.field(),
+            optional(column.kind.entity_type(), column.nullable)
+        )
+        .unwrap();
+    }
+    out.push_str("}\n\n");
+    out.push_str(
+        "#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]\npub enum Relation {}\n\n",
+    );
+    writeln!(out, "impl From<Model> for {class}RespVO {{").unwrap();
+    out.push_str(
+        "    fn from(value: Model) -> Self {\n        Self {\n            id: value.id,\n",
+    );
+    for column in &table.columns {
+        let field = column.field();
+        writeln!(out, "            {field}: value.{field},").unwrap();
+    }
+    out.push_str("            create_time: value.create_time,\n        }\n    }\n}\n");
+    out
+}
+
+/// 追加到 `vo/<schema>_vo/mod.rs` 的 VO 定义，沿用该文件已有的导入
+pub fn vo(table: &Table) -> String {
+    let class = table.class_name();
+    let mut out = String::new();
+
+    out.push_str("\n#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]\n#[serde(rename_all = \"camelCase\")]\n");
+    writeln!(out, "pub struct {class}SaveReqVO {{").unwrap();
+    out.push_str("    pub id: Option<String>,\n");
+    for column in &table.columns {
+        let label = column.label();
+        match (column.kind.is_string(), column.max_length, column.nullable) {
+            (true, Some(max), true) => writeln!(
+                out,
+                "    #[validate(length(max = {max}, message = \"{label}长度不能超过{max}\"))]"
+            )
+            .unwrap(),
+            (true, Some(1), false) => writeln!(
+                out,
+                "    #[validate(length(min = 1, max = 1, message = \"{label}不能为空\"))]"
+            )
+            .unwrap(),
+            (true, Some(max), false) => writeln!(
+                out,
+                "    #[validate(length(min = 1, max = {max}, message = \"{label}长度为1-{max}\"))]"
+            )
+            .unwrap(),
+            (true, None, false) => writeln!(
+                out,
+                "    #[validate(length(min = 1, message = \"{label}不能为空\"))]"
+            )
+            .unwrap(),
+            _ => {}
+        }
+        match (column.kind, column.nullable) {
+            (ColumnKind::DateTime, true) => {
+                out.push_str("    #[serde(default, with = \"option_datetime_format\")]\n    #[schemars(with = \"Option<String>\")]\n")
+            }
+            (ColumnKind::DateTime, false) => {
+                out.push_str("    #[serde(with = \"datetime_format\")]\n    #[schemars(with = \"String\")]\n")
+            }
+            _ => {}
+        }
+        writeln!(
+            out,
+            "    pub {}: {},",
+            column.field(),
+            optional(column.kind.vo_type(), column.nullable)
+        )
+        .unwrap();
+    }
+    out.push_str("}\n");
+
+    out.push_str("\n#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]\n#[serde(rename_all = \"camelCase\")]\n");
+    writeln!(out, "pub struct {class}PageReqVO {{").unwrap();
+    for column in table.columns.iter().filter(|c| c.is_page_filter()) {
+        if column.kind.is_scalar() {
+            out.push_str(
+                "    #[serde(default, deserialize_with = \"deserialize_option_numer\")]\n",
+            );
+        }
+        writeln!(
+            out,
+            "    pub {}: Option<{}>,",
+            column.field(),
+            column.kind.vo_type()
+        )
+        .unwrap();
+    }
+    out.push_str(
+        "    #[serde(flatten)]\n    #[validate(nested)]\n    pub pagination: PaginationParams,\n}\n",
+    );
+
+    out.push_str(
+        "\n#[derive(Debug, Serialize, JsonSchema)]\n#[serde(rename_all = \"camelCase\")]\n",
+    );
+    writeln!(out, "pub struct {class}RespVO {{").unwrap();
+    out.push_str("    pub id: String,\n");
+    for column in &table.columns {
+        match (column.kind, column.nullable) {
+            (ColumnKind::DateTime, true) => {
+                out.push_str("    #[serde(with = \"option_datetime_format\")]\n    #[schemars(with = \"Option<String>\")]\n")
+            }
+            (ColumnKind::DateTime, false) => {
+                out.push_str("    #[serde(with = \"datetime_format\")]\n    #[schemars(with = \"String\")]\n")
+            }
+            _ => {}
+        }
+        writeln!(
+            out,
+            "    pub {}: {},",
+            column.field(),
+            optional(column.kind.vo_type(), column.nullable)
+        )
+        .unwrap();
+    }
+    out.push_str("    #[serde(with = \"datetime_format\")]\n    #[schemars(with = \"String\")]\n    pub create_time: DateTime,\n}\n");
+    out
+}
+
+/// 增删改查服务
+pub fn service(table: &Table) -> String {
+    let class = table.class_name();
+    let business = table.business();
+    let entity = &table.name;
+    let label = table.label();
+    let filters = table
+        .columns
+        .iter()
+        .filter(|c| c.is_page_filter())
+        .collect::<Vec<_>>();
+    let mut out = String::new();
+
+    writeln!(out, "use crate::{}_entity::prelude::*;", table.schema).unwrap();
+    writeln!(out, "use crate::{}_entity::{entity};", table.schema).unwrap();
+    out.push_str(
+        "use daoyi_common_support::error::{ApiError, ApiResult};\n\
+         use daoyi_common_support::models::pagination::Page;\n",
+    );
+    writeln!(
+        out,
+        "use daoyi_common_support::vo::{}_vo::{{{class}PageReqVO, {class}RespVO, {class}SaveReqVO}};",
+        table.schema
+    )
+    .unwrap();
+    out.push_str("use sea_orm::entity::prelude::*;\n");
+    if filters.is_empty() {
+        out.push_str("use sea_orm::{IntoActiveModel, QueryOrder, Set};\n\n");
+    } else {
+        out.push_str("use sea_orm::{IntoActiveModel, QueryOrder, QueryTrait, Set};\n\n");
+    }
+
+    let assign = |out: &mut String| {
+        for column in &table.columns {
+            let field = column.field();
+            writeln!(out, "    active_model.{field} = Set(req.{field});").unwrap();
+        }
+    };
+
+    writeln!(
+        out,
+        "pub async fn create_{business}<C: ConnectionTrait>(db: &C, req: {class}SaveReqVO) -> ApiResult<String> {{"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "    let mut active_model = {entity}::ActiveModel::new();"
+    )
+    .unwrap();
+    assign(&mut out);
+    out.push_str("    let model = active_model.insert(db).await?;\n    Ok(model.id)\n}\n\n");
+
+    writeln!(
+        out,
+        "pub async fn update_{business}<C: ConnectionTrait>(db: &C, req: {class}SaveReqVO) -> ApiResult<()> {{"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "    let id = req.id.as_deref().ok_or_else(|| ApiError::biz(\"{label}编号不能为空\"))?;"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "    let mut active_model = get_{business}_by_id(db, id).await?.into_active_model();"
+    )
+    .unwrap();
+    assign(&mut out);
+    out.push_str("    active_model.update(db).await?;\n    Ok(())\n}\n\n");
+
+    writeln!(
+        out,
+        "pub async fn delete_{business}<C: ConnectionTrait>(db: &C, id: &str) -> ApiResult<()> {{"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "    let mut active_model = get_{business}_by_id(db, id).await?.into_active_model();"
+    )
+    .unwrap();
+    out.push_str(
+        "    active_model.deleted = Set(true);\n    active_model.update(db).await?;\n    Ok(())\n}\n\n",
+    );
+
+    writeln!(
+        out,
+        "pub async fn get_{business}_by_id<C: ConnectionTrait>(db: &C, id: &str) -> ApiResult<{entity}::Model> {{"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "    {}::find_perm()\n        .await\n        .filter({entity}::Column::Id.eq(id))\n        .one(db)\n        .await?\n        .ok_or_else(|| ApiError::biz(\"{label}不存在\"))\n}}\n",
+        table.entity_name()
+    )
+    .unwrap();
+
+    writeln!(
+        out,
+        "pub async fn get_{business}_page<C: ConnectionTrait>(db: &C, req: {class}PageReqVO) -> ApiResult<Page<{class}RespVO>> {{"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "    let paginator = {}::find_perm()\n        .await",
+        table.entity_name()
+    )
+    .unwrap();
+    for column in filters {
+        let field = column.field();
+        let operator = if column.is_fuzzy_filter() {
+            "contains"
+        } else {
+            "eq"
+        };
+        writeln!(
+            out,
+            "        .apply_if(req.{field}, |query, {field}| query.filter({entity}::Column::{variant}.{operator}({field})))",
+            variant = column.variant(),
+        )
+        .unwrap();
+    }
+    if table.has_sort() {
+        writeln!(out, "        .order_by_asc({entity}::Column::Sort)").unwrap();
+    }
+    writeln!(out, "        .order_by_desc({entity}::Column::Id)").unwrap();
+    out.push_str(
+        "        .paginate(db, req.pagination.size);\n\
+         \x20   let total = paginator.num_items().await?;\n\
+         \x20   let items = paginator\n\
+         \x20       .fetch_page(req.pagination.page - 1)\n\
+         \x20       .await?\n\
+         \x20       .into_iter()\n\
+         \x20       .map(|x| x.into())\n\
+         \x20       .collect();\n\
+         \x20   Ok(Page::from_pagination(req.pagination, total, items))\n}\n",
+    );
+    out
+}
+
+/// 接口路由
+pub fn api(table: &Table) -> String {
+    let class = table.class_name();
+    let business = table.business();
+    let schema = &table.schema;
+    let service = format!("{}_service", table.name);
+    let mut out = String::new();
+
+    out.push_str(
+        "use aide::axum::{ApiRouter, routing};\n\
+         use axum::debug_handler;\n\
+         use axum::extract::State;\n\
+         use daoyi_common_support::app::AppState;\n\
+         use daoyi_common_support::models::pagination::Page;\n\
+         use daoyi_common_support::request::valid::{ValidJson, ValidQuery};\n\
+         use daoyi_common_support::response::{ApiResponse, RestApiResult};\n",
+    );
+    writeln!(
+        out,
+        "use daoyi_common_support::vo::{schema}_vo::{{IdReqVO, {class}PageReqVO, {class}RespVO, {class}SaveReqVO}};"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "use daoyi_entity_{schema}::{schema}_service::{service};"
+    )
+    .unwrap();
+    out.push_str("use sea_orm::DatabaseConnection;\n\n");
+
+    writeln!(
+        out,
+        "pub fn create_router() -> ApiRouter<AppState> {{\n    ApiRouter::new()\n        \
+         .api_route(\"/create\", routing::post(create_{business}))\n        \
+         .api_route(\"/update\", routing::put(update_{business}))\n        \
+         .api_route(\"/delete\", routing::delete(delete_{business}))\n        \
+         .api_route(\"/get\", routing::get(get_{business}))\n        \
+         .api_route(\"/page\", routing::get(get_{business}_page))\n}}\n"
+    )
+    .unwrap();
+
+    writeln!(
+        out,
+        "#[debug_handler]\nasync fn create_{business}(\n    State(db): State<DatabaseConnection>,\n    \
+         ValidJson(req): ValidJson<{class}SaveReqVO>,\n) -> RestApiResult<String> {{\n    \
+         ApiResponse::success({service}::create_{business}(&db, req).await?)\n}}\n"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "#[debug_handler]\nasync fn update_{business}(\n    State(db): State<DatabaseConnection>,\n    \
+         ValidJson(req): ValidJson<{class}SaveReqVO>,\n) -> RestApiResult<bool> {{\n    \
+         {service}::update_{business}(&db, req).await?;\n    ApiResponse::success(true)\n}}\n"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "#[debug_handler]\nasync fn delete_{business}(\n    State(db): State<DatabaseConnection>,\n    \
+         ValidQuery(IdReqVO {{ id }}): ValidQuery<IdReqVO>,\n) -> RestApiResult<bool> {{\n    \
+         {service}::delete_{business}(&db, &id).await?;\n    ApiResponse::success(true)\n}}\n"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "#[debug_handler]\nasync fn get_{business}(\n    State(db): State<DatabaseConnection>,\n    \
+         ValidQuery(IdReqVO {{ id }}): ValidQuery<IdReqVO>,\n) -> RestApiResult<{class}RespVO> {{\n    \
+         ApiResponse::success({service}::get_{business}_by_id(&db, &id).await?.into())\n}}\n"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "#[debug_handler]\nasync fn get_{business}_page(\n    State(db): State<DatabaseConnection>,\n    \
+         ValidQuery(req): ValidQuery<{class}PageReqVO>,\n) -> RestApiResult<Page<{class}RespVO>> {{\n    \
+         ApiResponse::success({service}::get_{business}_page(&db, req).await?)\n}}"
+    )
+    .unwrap();
+    out
+}
+
+/// 前端使用的 TypeScript 类型，字段与 VO 的 camelCase 序列化结果一致
+pub fn typescript(table: &Table) -> String {
+    let class = table.class_name();
+    let mut out = String::new();
+    writeln!(
+        out,
+        "// {GENERATED}，对应表 {}.{}\n",
+        table.schema, table.name
+    )
+    .unwrap();
+
+    let doc = |out: &mut String, comment: &str| {
+        if !comment.is_empty() {
+            writeln!(out, "  /** {comment} */").unwrap();
+        }
+    };
+
+    if !table.comment.is_empty() {
+        writeln!(out, "/** {} */", table.comment).unwrap();
+    }
+    writeln!(out, "export interface {class}VO {{\n  id: string").unwrap();
+    for column in &table.columns {
+        doc(&mut out, &column.comment);
+        let nullable = if column.nullable { " | null" } else { "" };
+        writeln!(
+            out,
+            "  {}: {}{nullable}",
+            column.ts_field(),
+            column.kind.ts_type()
+        )
+        .unwrap();
+    }
+    out.push_str("  createTime: string\n}\n\n");
+
+    writeln!(out, "export interface {class}SaveReqVO {{\n  id?: string").unwrap();
+    for column in &table.columns {
+        doc(&mut out, &column.comment);
+        let optional = if column.nullable { "?" } else { "" };
+        writeln!(
+            out,
+            "  {}{optional}: {}",
+            column.ts_field(),
+            column.kind.ts_type()
+        )
+        .unwrap();
+    }
+    out.push_str("}\n\n");
+
+    writeln!(out, "export interface {class}PageReqVO {{").unwrap();
+    for column in table.columns.iter().filter(|c| c.is_page_filter()) {
+        doc(&mut out, &column.comment);
+        writeln!(out, "  {}?: {}", column.ts_field(), column.kind.ts_type()).unwrap();
+    }
+    out.push_str("  page: number\n  size: number\n}\n");
+    out
+}
+
+fn optional(ty: &str, nullable: bool) -> String {
+    if nullable {
+        format!("Option<{ty}>")
+    } else {
+        ty.to_string()
+    }
+}
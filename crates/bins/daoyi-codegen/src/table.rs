@@ -0,0 +1,363 @@
+//! 从 PostgreSQL `information_schema` 读取表结构与注释
+
+use sea_orm::{ConnectionTrait, DbBackend, Statement};
+
+/// `#[daoyi_model]` 自动追加的公共字段，生成代码时不重复声明
+pub const AUDIT_COLUMNS: [&str; 6] = [
+    "creator",
+    "create_time",
+    "updater",
+    "update_time",
+    "deleted",
+    "tenant_id",
+];
+
+const RUST_KEYWORDS: [&str; 12] = [
+    "as", "fn", "impl", "in", "loop", "match", "mod", "move", "ref", "self", "type", "use",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnKind {
+    String,
+    Text,
+    SmallInt,
+    Integer,
+    BigInteger,
+    Float,
+    Double,
+    Decimal,
+    Boolean,
+    Date,
+    DateTime,
+    Json,
+    Binary,
+}
+
+#[derive(Debug, Clone)]
+pub struct Column {
+    pub name: String,
+    pub kind: ColumnKind,
+    pub nullable: bool,
+    pub max_length: Option<i32>,
+    pub comment: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Table {
+    pub schema: String,
+    pub name: String,
+    pub comment: String,
+    /// 除 `id` 与公共字段外的业务字段
+    pub columns: Vec<Column>,
+}
+
+impl ColumnKind {
+    fn from_udt_name(udt_name: &str) -> Option<Self> {
+        Some(match udt_name {
+            "varchar" | "bpchar" => Self::String,
+            "text" => Self::Text,
+            "int2" => Self::SmallInt,
+            "int4" => Self::Integer,
+            "int8" => Self::BigInteger,
+            "float4" => Self::Float,
+            "float8" => Self::Double,
+            "numeric" => Self::Decimal,
+            "bool" => Self::Boolean,
+            "date" => Self::Date,
+            "timestamp" => Self::DateTime,
+            "json" | "jsonb" => Self::Json,
+            "bytea" => Self::Binary,
+            _ => return None,
+        })
+    }
+
+    /// 实体中的字段类型
+    pub fn entity_type(self) -> &'static str {
+        match self {
+            Self::String | Self::Text => "String",
+            Self::SmallInt => "i16",
+            Self::Integer => "i32",
+            Self::BigInteger => "i64",
+            Self::Float => "f32",
+            Self::Double => "f64",
+            Self::Decimal => "Decimal",
+            Self::Boolean => "bool",
+            Self::Date => "Date",
+            Self::DateTime => "DateTime",
+            Self::Json => "Json",
+            Self::Binary => "Vec<u8>",
+        }
+    }
+
+    /// VO 中的字段类型，`system_vo` 只导入了 `DateTime`，其余类型使用完整路径
+    pub fn vo_type(self) -> &'static str {
+        match self {
+            Self::Decimal => "sea_orm::prelude::Decimal",
+            Self::Date => "sea_orm::prelude::Date",
+            Self::Json => "serde_json::Value",
+            _ => self.entity_type(),
+        }
+    }
+
+    pub fn ts_type(self) -> &'static str {
+        match self {
+            Self::String | Self::Text | Self::Decimal | Self::Date | Self::DateTime => "string",
+            Self::SmallInt | Self::Integer | Self::BigInteger | Self::Float | Self::Double => {
+                "number"
+            }
+            Self::Boolean => "boolean",
+            Self::Json => "any",
+            Self::Binary => "number[]",
+        }
+    }
+
+    /// sea-orm 无法从 Rust 类型推断的列类型
+    pub fn column_type(self) -> Option<&'static str> {
+        match self {
+            Self::Text => Some("Text"),
+            Self::Json => Some("JsonBinary"),
+            Self::Binary => Some("VarBinary(StringLen::None)"),
+            _ => None,
+        }
+    }
+
+    pub fn is_string(self) -> bool {
+        matches!(self, Self::String | Self::Text)
+    }
+
+    /// 整数与布尔，作为查询参数时需要从字符串解析
+    pub fn is_scalar(self) -> bool {
+        matches!(
+            self,
+            Self::SmallInt | Self::Integer | Self::BigInteger | Self::Boolean
+        )
+    }
+
+    pub fn is_float(self) -> bool {
+        matches!(self, Self::Float | Self::Double)
+    }
+}
+
+impl Column {
+    /// Rust 字段名，关键字使用原始标识符
+    pub fn field(&self) -> String {
+        if RUST_KEYWORDS.contains(&self.name.as_str()) {
+            format!("r#{}", self.name)
+        } else {
+            self.name.clone()
+        }
+    }
+
+    /// `Column` 枚举中的变体名
+    pub fn variant(&self) -> String {
+        pascal_case(&self.name)
+    }
+
+    pub fn ts_field(&self) -> String {
+        camel_case(&self.name)
+    }
+
+    /// 分页查询条件：短字符串与数值、布尔字段，`sort` 除外
+    pub fn is_page_filter(&self) -> bool {
+        if self.name == "sort" {
+            return false;
+        }
+        match self.kind {
+            ColumnKind::String => self.max_length.is_some_and(|len| len <= 255),
+            kind => kind.is_scalar(),
+        }
+    }
+
+    /// 字符串条件使用模糊匹配，状态、类型等短编码使用精确匹配
+    pub fn is_fuzzy_filter(&self) -> bool {
+        self.kind == ColumnKind::String && self.max_length.is_some_and(|len| len > 2)
+    }
+
+    /// 校验提示中使用的字段名称
+    pub fn label(&self) -> &str {
+        if self.comment.is_empty() {
+            &self.name
+        } else {
+            &self.comment
+        }
+    }
+}
+
+impl Table {
+    /// 去掉 schema 前缀后的业务名，如 `system_tenant_package` 为 `tenant_package`
+    pub fn business(&self) -> &str {
+        self.name
+            .strip_prefix(&format!("{}_", self.schema))
+            .unwrap_or(&self.name)
+    }
+
+    /// VO 名前缀，如 `TenantPackage`
+    pub fn class_name(&self) -> String {
+        pascal_case(self.business())
+    }
+
+    /// 实体别名，如 `SystemTenantPackage`
+    pub fn entity_name(&self) -> String {
+        pascal_case(&self.name)
+    }
+
+    /// 路由路径，如 `tenant-package`
+    pub fn path(&self) -> String {
+        self.business().replace('_', "-")
+    }
+
+    /// 提示信息中使用的业务名称，如表注释“岗位信息表”为“岗位”
+    pub fn label(&self) -> &str {
+        let comment = self.comment.trim();
+        let label = comment
+            .strip_suffix("信息表")
+            .or_else(|| comment.strip_suffix('表'))
+            .unwrap_or(comment);
+        if label.is_empty() {
+            self.business()
+        } else {
+            label
+        }
+    }
+
+    pub fn has_sort(&self) -> bool {
+        self.columns
+            .iter()
+            .any(|c| c.name == "sort" && c.kind.is_scalar())
+    }
+
+    pub fn has_float(&self) -> bool {
+        self.columns.iter().any(|c| c.kind.is_float())
+    }
+}
+
+pub async fn read_table<C: ConnectionTrait>(
+    db: &C,
+    schema: &str,
+    name: &str,
+) -> anyhow::Result<Table> {
+    let row = db
+        .query_one(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT obj_description(c.oid, 'pg_class') AS comment FROM pg_class c \
+             JOIN pg_namespace n ON n.oid = c.relnamespace \
+             WHERE n.nspname = $1 AND c.relname = $2 AND c.relkind = 'r'",
+            [schema.into(), name.into()],
+        ))
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("表 {schema}.{name} 不存在"))?;
+    let comment = row.try_get::<Option<String>>("", "comment")?;
+
+    let rows = db
+        .query_all(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT c.column_name::text AS name, c.udt_name::text AS udt_name, \
+             c.is_nullable = 'YES' AS nullable, c.character_maximum_length::int4 AS max_length, \
+             col_description(format('%I.%I', c.table_schema, c.table_name)::regclass, \
+             c.ordinal_position::int4) AS comment \
+             FROM information_schema.columns c \
+             WHERE c.table_schema = $1 AND c.table_name = $2 ORDER BY c.ordinal_position",
+            [schema.into(), name.into()],
+        ))
+        .await?;
+    let mut columns = Vec::with_capacity(rows.len());
+    for row in rows {
+        let name = row.try_get::<String>("", "name")?;
+        let udt_name = row.try_get::<String>("", "udt_name")?;
+        let kind = ColumnKind::from_udt_name(&udt_name)
+            .ok_or_else(|| anyhow::anyhow!("字段 {name} 的类型 {udt_name} 暂不支持"))?;
+        columns.push(Column {
+            name,
+            kind,
+            nullable: row.try_get("", "nullable")?,
+            max_length: row.try_get("", "max_length")?,
+            comment: row
+                .try_get::<Option<String>>("", "comment")?
+                .unwrap_or_default(),
+        });
+    }
+
+    if !columns
+        .iter()
+        .any(|c| c.name == "id" && c.kind == ColumnKind::String)
+    {
+        anyhow::bail!("表 {schema}.{name} 缺少字符串类型的 id 主键");
+    }
+    for audit in AUDIT_COLUMNS {
+        if !columns.iter().any(|c| c.name == audit) {
+            anyhow::bail!("表 {schema}.{name} 缺少公共字段 {audit}");
+        }
+    }
+    columns.retain(|c| c.name != "id" && !AUDIT_COLUMNS.contains(&c.name.as_str()));
+
+    Ok(Table {
+        schema: schema.to_string(),
+        name: name.to_string(),
+        comment: comment.unwrap_or_default(),
+        columns,
+    })
+}
+
+pub fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            let mut chars = s.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+pub fn camel_case(name: &str) -> String {
+    let pascal = pascal_case(name);
+    let mut chars = pascal.chars();
+    chars
+        .next()
+        .map(|c| c.to_ascii_lowercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_table(name: &str, comment: &str) -> Table {
+        Table {
+            schema: "system".to_string(),
+            name: name.to_string(),
+            comment: comment.to_string(),
+            columns: vec![],
+        }
+    }
+
+    #[test]
+    fn table_names() {
+        let table = new_table("system_tenant_package", "租户套餐表");
+        assert_eq!(table.business(), "tenant_package");
+        assert_eq!(table.class_name(), "TenantPackage");
+        assert_eq!(table.entity_name(), "SystemTenantPackage");
+        assert_eq!(table.path(), "tenant-package");
+        assert_eq!(table.label(), "租户套餐");
+        assert_eq!(new_table("system_post", "岗位信息表").label(), "岗位");
+        assert_eq!(new_table("system_post", "").label(), "post");
+    }
+
+    #[test]
+    fn column_names() {
+        let column = Column {
+            name: "type".to_string(),
+            kind: ColumnKind::String,
+            nullable: false,
+            max_length: Some(1),
+            comment: String::new(),
+        };
+        assert_eq!(column.field(), "r#type");
+        assert_eq!(column.variant(), "Type");
+        assert!(column.is_page_filter());
+        assert!(!column.is_fuzzy_filter());
+        assert_eq!(camel_case("contact_user_id"), "contactUserId");
+    }
+}
@@ -0,0 +1,43 @@
+use daoyi_common_support::configs::AppConfig;
+use daoyi_common_support::{database, logger};
+use daoyi_migration::{Migrator, MigratorTrait};
+use std::sync::Arc;
+
+const USAGE: &str = "用法: daoyi-migrate [up [N] | down [N] | status | fresh]
+  up [N]    执行 N 个（默认全部）未执行的迁移
+  down [N]  回滚最近 N 个（默认 1 个）已执行的迁移
+  status    查看迁移执行状态
+  fresh     删除迁移创建的全部表后重新执行全部迁移";
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let command = args.first().map(String::as_str).unwrap_or("up");
+    let steps = match args.get(1) {
+        Some(steps) => Some(
+            steps
+                .parse::<u32>()
+                .map_err(|_| anyhow::anyhow!("迁移数量必须是正整数\n{USAGE}"))?,
+        ),
+        None => None,
+    };
+    if !matches!(command, "up" | "down" | "status" | "fresh") {
+        anyhow::bail!("未知的命令: {command}\n{USAGE}");
+    }
+
+    let config = Arc::new(AppConfig::read(env!("CARGO_PKG_NAME")).await?);
+    AppConfig::install(config.clone());
+    let _log_guard = logger::init().await?;
+    let db = database::connect(config.database()).await?;
+    match command {
+        "up" => Migrator::up(&db, steps).await?,
+        "down" => Migrator::down(&db, Some(steps.unwrap_or(1))).await?,
+        "status" => {
+            for migration in Migrator::get_migration_with_status(&db).await? {
+                println!("{:<8} {}", migration.status().to_string(), migration.name());
+            }
+        }
+        _ => daoyi_migration::fresh(&db).await?,
+    }
+    Ok(())
+}
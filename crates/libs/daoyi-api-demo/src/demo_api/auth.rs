@@ -1,26 +1,29 @@
+use aide::axum::{ApiRouter, routing};
+use axum::debug_handler;
 use axum::extract::ConnectInfo;
-use axum::{Router, debug_handler, routing};
 use daoyi_common_support::app::AppState;
 use daoyi_common_support::database;
 use daoyi_common_support::error::ApiError;
 use daoyi_common_support::password::verify_password;
 use daoyi_common_support::request::valid::ValidJson;
+use daoyi_common_support::request::validation::ValidateDict;
 use daoyi_common_support::response::{ApiResponse, RestApiResult};
 use daoyi_entity_demo::demo_entity::prelude::*;
 use daoyi_entity_demo::demo_entity::sys_user;
+use schemars::JsonSchema;
 use sea_orm::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::net::SocketAddr;
 use validator::Validate;
 
-pub fn create_router() -> Router<AppState> {
-    Router::new()
-        .route("/user-info", routing::get(get_user_info))
-        .route("/login", routing::post(login))
-        .route("/logout", routing::post(logout))
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/user-info", routing::get(get_user_info))
+        .api_route("/login", routing::post(login))
+        .api_route("/logout", routing::post(logout))
 }
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
 pub struct LoginParams {
     #[validate(length(min = 3, max = 16, message = "账号长度为3-16"))]
     account: String,
@@ -28,7 +31,7 @@ pub struct LoginParams {
     password: String,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct LoginResult {
     access_token: String,
@@ -1,11 +1,11 @@
-use axum::Router;
+use aide::axum::ApiRouter;
 use daoyi_common_support::app::AppState;
 
 pub mod user;
 pub mod auth;
 
-pub fn create_router() -> Router<AppState> {
-    Router::new()
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
         .nest("/user", user::create_router())
         .nest("/auth", auth::create_router())
 }
@@ -1,5 +1,6 @@
+use aide::axum::{ApiRouter, routing};
 use anyhow::Context;
-use axum::{Router, debug_handler, routing};
+use axum::debug_handler;
 use daoyi_common_support::app::AppState;
 use daoyi_common_support::database;
 use daoyi_common_support::enumeration::Gender;
@@ -9,25 +10,27 @@ use daoyi_common_support::password::hash_password;
 use daoyi_common_support::request::path::Path;
 use daoyi_common_support::request::valid::{ValidJson, ValidQuery};
 use daoyi_common_support::request::validation;
+use daoyi_common_support::request::validation::ValidateDict;
 use daoyi_common_support::response::{ApiResponse, RestApiResult};
 use daoyi_entity_demo::demo_entity::prelude::*;
 use daoyi_entity_demo::demo_entity::sys_user;
 use daoyi_entity_demo::demo_entity::sys_user::ActiveModel;
+use schemars::JsonSchema;
 use sea_orm::prelude::*;
 use sea_orm::{ActiveValue, Condition, IntoActiveModel, QueryOrder, QueryTrait};
 use serde::Deserialize;
 use validator::Validate;
 
-pub fn create_router() -> Router<AppState> {
-    Router::new()
-        .route("/list", routing::get(query_users))
-        .route("/page", routing::get(find_page))
-        .route("/", routing::post(create))
-        .route("/{id}", routing::put(update))
-        .route("/{id}", routing::delete(delete))
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/list", routing::get(query_users))
+        .api_route("/page", routing::get(find_page))
+        .api_route("/", routing::post(create))
+        .api_route("/{id}", routing::put(update))
+        .api_route("/{id}", routing::delete(delete))
 }
 
-#[derive(Debug, Deserialize, Validate, DeriveIntoActiveModel)]
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema, DeriveIntoActiveModel)]
 #[serde(rename_all = "camelCase")]
 pub struct UserParams {
     #[validate(length(min = 1, max = 16, message = "姓名长度为1-16"))]
@@ -87,7 +90,7 @@ async fn delete(Path(id): Path<String>) -> RestApiResult<u64> {
     Ok(ApiResponse::ok(Some(result)))
 }
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UserQueryParams {
     keyword: Option<String>,
@@ -1,10 +1,10 @@
-use axum::Router;
+use aide::axum::ApiRouter;
 use daoyi_common_support::app::AppState;
 
 pub mod demo_api;
 
-pub fn create_router() -> Router<AppState> {
-    Router::new()
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
         .nest("/admin-api/demo", demo_api::create_router())
         .nest("/app-api/demo", demo_api::create_router())
 }
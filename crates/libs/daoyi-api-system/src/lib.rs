@@ -1,10 +1,68 @@
 mod system_api;
 
-use axum::Router;
+use aide::axum::ApiRouter;
 use daoyi_common_support::app::AppState;
+use daoyi_common_support::configs::AppConfig;
+use daoyi_common_support::{app, config, database, dict, job};
+use daoyi_entity_system::system_service::{
+    system_access_token_service, system_config_service, system_dict_data_service,
+    system_dict_sync_service, system_job_service, system_notice_service, system_tenant_service,
+};
+use daoyi_migration::{Migrator, MigratorTrait};
 
-pub fn create_router() -> Router<AppState> {
-    Router::new()
+pub fn create_router() -> ApiRouter<AppState> {
+    // 按配置在启动时执行数据库迁移，需先于其它依赖系统表的启动任务注册
+    app::register_startup_task(|| {
+        Box::pin(async {
+            if AppConfig::get().await.database().auto_migrate() {
+                Migrator::up(database::get().await, None).await?;
+            }
+            Ok(())
+        })
+    });
+    // 系统服务持有字典表，字典校验直接使用本地的字典数据快照
+    dict::register_snapshot_loader(|| Box::pin(system_dict_data_service::get_dict_data_snapshot()));
+    // 系统服务持有参数配置表，参数未缓存时直接查询本地参数配置
+    config::register_param_loader(|key| Box::pin(system_config_service::load_param(key)));
+    // 监听参数配置变更通知
+    app::register_startup_task(|| {
+        Box::pin(async {
+            config::start_change_listener();
+            Ok(())
+        })
+    });
+    // 启动时将枚举派生的字典同步到字典表，保证前后端字典一致
+    app::register_startup_task(|| {
+        Box::pin(async { Ok(system_dict_sync_service::sync_enum_dicts().await?) })
+    });
+    // 定时发布公告
+    app::register_startup_task(|| {
+        Box::pin(async {
+            system_notice_service::start_notice_push_scheduler();
+            Ok(())
+        })
+    });
+    // 系统服务提供的定时任务处理器
+    job::register_handler("purge_expired_access_token", |_| {
+        Box::pin(async {
+            let count = system_access_token_service::purge_expired_access_tokens().await?;
+            Ok(format!("清理过期令牌{count}个"))
+        })
+    });
+    job::register_handler("disable_expired_tenant", |_| {
+        Box::pin(async {
+            let count = system_tenant_service::disable_expired_tenants().await?;
+            Ok(format!("禁用过期租户{count}个"))
+        })
+    });
+    // 系统服务持有定时任务表，由系统服务调度全部定时任务
+    app::register_startup_task(|| {
+        Box::pin(async {
+            system_job_service::start_job_scheduler();
+            Ok(())
+        })
+    });
+    ApiRouter::new()
         .nest("/admin-api/system", system_api::create_router())
         .nest("/app-api/system", system_api::create_router())
 }
@@ -1,28 +1,29 @@
+use aide::axum::{ApiRouter, routing};
+use axum::debug_handler;
 use axum::extract::ConnectInfo;
-use axum::{Router, debug_handler, routing};
+use axum::http::{HeaderMap, header};
 use daoyi_common_support::app::AppState;
 use daoyi_common_support::context::HttpRequestContext;
 use daoyi_common_support::enumeration::CommonStatusEnum;
-use daoyi_common_support::error::ApiError;
-use daoyi_common_support::password::verify_password;
 use daoyi_common_support::request::valid::ValidJson;
 use daoyi_common_support::response::{ApiResponse, RestApiResult};
 use daoyi_common_support::vo::system_vo::{
     AuthLoginReqVO, AuthLoginRespVO, AuthPermissionInfoRespVO,
 };
 use daoyi_entity_system::system_entity::system_role;
+use daoyi_entity_system::system_service::system_login_log_service::LoginClient;
 use daoyi_entity_system::system_service::{
-    system_access_token_service, system_menu_service, system_role_menu_service,
-    system_role_service, system_user_role_service, system_users_service,
+    system_auth_service, system_menu_service, system_role_menu_service, system_role_service,
+    system_tenant_package_service, system_user_role_service, system_users_service,
 };
 use std::collections::HashSet;
 use std::net::SocketAddr;
 
-pub fn create_router() -> Router<AppState> {
-    Router::new()
-        .route("/login", routing::post(login))
-        .route("/logout", routing::post(logout))
-        .route("/get-permission-info", routing::get(get_permission_info))
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/login", routing::post(login))
+        .api_route("/logout", routing::post(logout))
+        .api_route("/get-permission-info", routing::get(get_permission_info))
 }
 
 #[debug_handler]
@@ -38,7 +39,9 @@ async fn get_permission_info() -> RestApiResult<AuthPermissionInfoRespVO> {
     if user.is_err() {
         return ApiResponse::success(vo);
     }
-    vo.user = user?.into();
+    let user = user?;
+    let tenant_id = user.tenant_id.clone();
+    vo.user = system_users_service::build_user_vo(user).await?;
     // 1.2 获得角色列表
     let role_ids =
         system_user_role_service::get_user_role_id_list_by_user_id(&login_user_id).await?;
@@ -65,6 +68,9 @@ async fn get_permission_info() -> RestApiResult<AuthPermissionInfoRespVO> {
         .collect::<Vec<_>>();
     // 1.3 获得菜单列表
     let menu_ids = system_role_menu_service::get_role_menu_list_by_role_id(&role_ids).await?;
+    // 1.4 按租户套餐过滤菜单
+    let menu_ids =
+        system_tenant_package_service::filter_menu_ids_by_tenant(&tenant_id, menu_ids).await?;
     let menu_list = system_menu_service::get_menu_list(Some(&menu_ids))
         .await?
         .into_iter()
@@ -83,26 +89,20 @@ async fn get_permission_info() -> RestApiResult<AuthPermissionInfoRespVO> {
 }
 
 #[debug_handler]
-#[tracing::instrument(name="login",skip_all,fields(ip=%addr.ip(),account=%params.username,password=%params.password))]
+#[tracing::instrument(name = "login", skip_all, fields(ip = %addr.ip(), account = %params.username))]
 async fn login(
     ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
     ValidJson(params): ValidJson<AuthLoginReqVO>,
 ) -> RestApiResult<AuthLoginRespVO> {
-    tracing::info!("开始处理登录逻辑。。。");
-    let user = system_users_service::get_by_username(&params.username)
-        .await?
-        .ok_or_else(|| ApiError::Biz(String::from("账号或密码不正确")))?;
-    if !verify_password(&params.password, &user.password).await? {
-        return Err(ApiError::Biz(String::from("账号或密码不正确")));
-    }
-    tracing::info!(
-        "登录成功，HttpRequestContext={:?}",
-        HttpRequestContext::get_current()
-    );
-    let vo =
-        system_access_token_service::create_token_after_login_success(&user.tenant_id, &user.id)
-            .await?;
-    ApiResponse::success(vo)
+    let client = LoginClient {
+        user_ip: addr.ip().to_string(),
+        user_agent: headers
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(String::from),
+    };
+    ApiResponse::success(system_auth_service::login(params, client).await?)
 }
 
 #[debug_handler]
@@ -0,0 +1,67 @@
+use aide::axum::{ApiRouter, routing};
+use axum::debug_handler;
+use daoyi_common_support::app::AppState;
+use daoyi_common_support::config;
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::request::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::response::{ApiResponse, RestApiResult};
+use daoyi_common_support::vo::system_vo::{
+    ConfigKeyReqVO, ConfigPageReqVO, ConfigRespVO, ConfigSaveReqVO, IdReqVO,
+};
+use daoyi_entity_system::system_service::system_config_service;
+use std::collections::HashMap;
+
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/create", routing::post(create_config))
+        .api_route("/update", routing::put(update_config))
+        .api_route("/delete", routing::delete(delete_config))
+        .api_route("/get", routing::get(get_config))
+        .api_route("/get-value-by-key", routing::get(get_config_value_by_key))
+        .api_route("/page", routing::get(get_config_page))
+        .api_route("/visible-list", routing::get(get_visible_config_map))
+}
+
+#[debug_handler]
+async fn create_config(ValidJson(req): ValidJson<ConfigSaveReqVO>) -> RestApiResult<String> {
+    ApiResponse::success(system_config_service::create_config(req).await?)
+}
+
+#[debug_handler]
+async fn update_config(ValidJson(req): ValidJson<ConfigSaveReqVO>) -> RestApiResult<bool> {
+    system_config_service::update_config(req).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn delete_config(ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>) -> RestApiResult<bool> {
+    system_config_service::delete_config(&id).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn get_config(
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<ConfigRespVO> {
+    ApiResponse::success(system_config_service::get_config_by_id(&id).await?.into())
+}
+
+#[debug_handler]
+async fn get_config_value_by_key(
+    ValidQuery(ConfigKeyReqVO { key }): ValidQuery<ConfigKeyReqVO>,
+) -> RestApiResult<Option<String>> {
+    ApiResponse::success(config::get_param(&key).await?)
+}
+
+#[debug_handler]
+async fn get_config_page(
+    ValidQuery(req): ValidQuery<ConfigPageReqVO>,
+) -> RestApiResult<Page<ConfigRespVO>> {
+    ApiResponse::success(system_config_service::get_config_page(req).await?)
+}
+
+/// 对前端可见的参数，无需登录
+#[debug_handler]
+async fn get_visible_config_map() -> RestApiResult<HashMap<String, String>> {
+    ApiResponse::success(system_config_service::get_visible_config_map().await?)
+}
@@ -1,23 +1,79 @@
-use axum::{Router, debug_handler, routing};
+use aide::axum::{ApiRouter, routing};
+use axum::debug_handler;
+use axum::http::{HeaderMap, StatusCode, header};
+use axum::response::{IntoResponse, Response};
 use daoyi_common_support::app::AppState;
-use daoyi_common_support::enumeration::CommonStatusEnum;
+use daoyi_common_support::error::ApiResult;
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::request::valid::{ValidJson, ValidQuery};
 use daoyi_common_support::response::{ApiResponse, RestApiResult};
-use daoyi_common_support::vo::system_vo::DictDataSimpleRespVO;
+use daoyi_common_support::vo::system_vo::{
+    DictDataPageReqVO, DictDataRespVO, DictDataSaveReqVO, IdReqVO,
+};
 use daoyi_entity_system::system_service::system_dict_data_service;
 
-pub fn create_router() -> Router<AppState> {
-    Router::new()
-        .route("/list-all-simple", routing::get(get_simple_dict_data_list))
-        .route("/simple-list", routing::get(get_simple_dict_data_list))
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/create", routing::post(create_dict_data))
+        .api_route("/update", routing::put(update_dict_data))
+        .api_route("/delete", routing::delete(delete_dict_data))
+        .api_route("/get", routing::get(get_dict_data))
+        .api_route("/page", routing::get(get_dict_data_page))
+        .api_route("/list-all-simple", routing::get(get_simple_dict_data_list))
+        .api_route("/simple-list", routing::get(get_simple_dict_data_list))
 }
 
 #[debug_handler]
-async fn get_simple_dict_data_list() -> RestApiResult<Vec<DictDataSimpleRespVO>> {
+async fn create_dict_data(ValidJson(req): ValidJson<DictDataSaveReqVO>) -> RestApiResult<String> {
+    ApiResponse::success(system_dict_data_service::create_dict_data(req).await?)
+}
+
+#[debug_handler]
+async fn update_dict_data(ValidJson(req): ValidJson<DictDataSaveReqVO>) -> RestApiResult<bool> {
+    system_dict_data_service::update_dict_data(req).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn delete_dict_data(ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>) -> RestApiResult<bool> {
+    system_dict_data_service::delete_dict_data(&id).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn get_dict_data(
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<DictDataRespVO> {
     ApiResponse::success(
-        system_dict_data_service::get_dict_data_list(CommonStatusEnum::Enable, None)
+        system_dict_data_service::get_dict_data_by_id(&id)
             .await?
-            .into_iter()
-            .map(|x| x.into())
-            .collect(),
+            .into(),
     )
 }
+
+#[debug_handler]
+async fn get_dict_data_page(
+    ValidQuery(req): ValidQuery<DictDataPageReqVO>,
+) -> RestApiResult<Page<DictDataRespVO>> {
+    ApiResponse::success(system_dict_data_service::get_dict_data_page(req).await?)
+}
+
+/// 返回字典数据快照，携带 ETag；请求头 If-None-Match 与之相同时返回 304
+#[debug_handler]
+async fn get_simple_dict_data_list(headers: HeaderMap) -> ApiResult<Response> {
+    let snapshot = system_dict_data_service::get_dict_data_snapshot().await?;
+    let etag_header = [(header::ETAG, snapshot.etag.clone())];
+    let not_modified = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| {
+            value
+                .split(',')
+                .map(|tag| tag.trim().trim_start_matches("W/"))
+                .any(|tag| tag == "*" || tag == snapshot.etag)
+        });
+    if not_modified {
+        return Ok((StatusCode::NOT_MODIFIED, etag_header).into_response());
+    }
+    Ok((etag_header, ApiResponse::ok(Some(snapshot.list))).into_response())
+}
@@ -1,6 +1,67 @@
-use axum::Router;
+use aide::axum::{ApiRouter, routing};
+use axum::debug_handler;
 use daoyi_common_support::app::AppState;
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::request::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::response::{ApiResponse, RestApiResult};
+use daoyi_common_support::vo::system_vo::{
+    DictTypePageReqVO, DictTypeRespVO, DictTypeSaveReqVO, DictTypeSimpleRespVO, IdReqVO,
+};
+use daoyi_entity_system::system_service::system_dict_type_service;
 
-pub fn create_router() -> Router<AppState> {
-    Router::new()
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/create", routing::post(create_dict_type))
+        .api_route("/update", routing::put(update_dict_type))
+        .api_route("/delete", routing::delete(delete_dict_type))
+        .api_route("/get", routing::get(get_dict_type))
+        .api_route("/page", routing::get(get_dict_type_page))
+        .api_route("/list-all-simple", routing::get(get_simple_dict_type_list))
+        .api_route("/simple-list", routing::get(get_simple_dict_type_list))
+}
+
+#[debug_handler]
+async fn create_dict_type(ValidJson(req): ValidJson<DictTypeSaveReqVO>) -> RestApiResult<String> {
+    ApiResponse::success(system_dict_type_service::create_dict_type(req).await?)
+}
+
+#[debug_handler]
+async fn update_dict_type(ValidJson(req): ValidJson<DictTypeSaveReqVO>) -> RestApiResult<bool> {
+    system_dict_type_service::update_dict_type(req).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn delete_dict_type(ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>) -> RestApiResult<bool> {
+    system_dict_type_service::delete_dict_type(&id).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn get_dict_type(
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<DictTypeRespVO> {
+    ApiResponse::success(
+        system_dict_type_service::get_dict_type_by_id(&id)
+            .await?
+            .into(),
+    )
+}
+
+#[debug_handler]
+async fn get_dict_type_page(
+    ValidQuery(req): ValidQuery<DictTypePageReqVO>,
+) -> RestApiResult<Page<DictTypeRespVO>> {
+    ApiResponse::success(system_dict_type_service::get_dict_type_page(req).await?)
+}
+
+#[debug_handler]
+async fn get_simple_dict_type_list() -> RestApiResult<Vec<DictTypeSimpleRespVO>> {
+    ApiResponse::success(
+        system_dict_type_service::get_dict_type_list()
+            .await?
+            .into_iter()
+            .map(|x| x.into())
+            .collect(),
+    )
 }
@@ -0,0 +1,130 @@
+use aide::axum::{ApiRouter, routing};
+use axum::debug_handler;
+use axum::extract::{Multipart, Path};
+use axum::http::{HeaderValue, header};
+use axum::response::{IntoResponse, Response};
+use daoyi_common_support::app::AppState;
+use daoyi_common_support::error::{ApiError, ApiResult};
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::request::valid::ValidQuery;
+use daoyi_common_support::response::{ApiResponse, RestApiResult};
+use daoyi_common_support::vo::system_vo::{
+    FilePageReqVO, FilePresignedUrlReqVO, FilePresignedUrlRespVO, FileRespVO, IdReqVO,
+};
+use daoyi_entity_system::system_entity::system_file;
+use daoyi_entity_system::system_service::system_file_service;
+
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/upload", routing::post(upload_file))
+        .api_route("/delete", routing::delete(delete_file))
+        .api_route("/get", routing::get(get_file))
+        .api_route("/page", routing::get(get_file_page))
+        .api_route("/download", routing::get(download_file))
+        .api_route("/presigned-url", routing::get(get_presigned_url))
+        .api_route("/temp-download/{token}", routing::get(download_temp_file))
+}
+
+/// 上传文件，表单字段名为 `file`，大小受 `server.max_body_size` 限制
+#[debug_handler]
+async fn upload_file(mut multipart: Multipart) -> RestApiResult<FileRespVO> {
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| ApiError::biz(format!("读取上传文件失败: {e}")))?
+    {
+        if field.name() != Some("file") {
+            continue;
+        }
+        let name = field.file_name().unwrap_or("file").to_string();
+        let content = field
+            .bytes()
+            .await
+            .map_err(|e| ApiError::biz(format!("读取上传文件失败: {e}")))?;
+        let file = system_file_service::upload_file(&name, content.to_vec()).await?;
+        return ApiResponse::success(file.into());
+    }
+    Err(ApiError::biz("上传文件不能为空"))
+}
+
+#[debug_handler]
+async fn delete_file(ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>) -> RestApiResult<bool> {
+    system_file_service::delete_file(&id).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn get_file(ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>) -> RestApiResult<FileRespVO> {
+    ApiResponse::success(system_file_service::get_file_by_id(&id).await?.into())
+}
+
+#[debug_handler]
+async fn get_file_page(
+    ValidQuery(req): ValidQuery<FilePageReqVO>,
+) -> RestApiResult<Page<FileRespVO>> {
+    ApiResponse::success(system_file_service::get_file_page(req).await?)
+}
+
+#[debug_handler]
+async fn download_file(ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>) -> ApiResult<Response> {
+    let (file, content) = system_file_service::download_file(&id).await?;
+    Ok(file_response(file, content))
+}
+
+#[debug_handler]
+async fn get_presigned_url(
+    ValidQuery(req): ValidQuery<FilePresignedUrlReqVO>,
+) -> RestApiResult<FilePresignedUrlRespVO> {
+    ApiResponse::success(system_file_service::get_presigned_url(req).await?)
+}
+
+/// 使用临时令牌下载文件，无需登录
+#[debug_handler]
+async fn download_temp_file(Path(token): Path<String>) -> ApiResult<Response> {
+    let (file, content) = system_file_service::download_temp_file(&token).await?;
+    Ok(file_response(file, content))
+}
+
+/// 图片、音视频与 PDF 在浏览器中直接打开，其它文件作为附件下载，避免 HTML 等内容被执行
+fn file_response(file: system_file::Model, content: Vec<u8>) -> Response {
+    let inline = ["image/", "video/", "audio/", "application/pdf"]
+        .iter()
+        .any(|x| file.r#type.starts_with(x))
+        && file.r#type != "image/svg+xml";
+    let disposition = format!(
+        "{}; filename*=UTF-8''{}",
+        if inline { "inline" } else { "attachment" },
+        encode_filename(&file.name)
+    );
+    let content_type = HeaderValue::from_str(&file.r#type)
+        .unwrap_or(HeaderValue::from_static("application/octet-stream"));
+    (
+        [
+            (header::CONTENT_TYPE, content_type),
+            (
+                header::CONTENT_DISPOSITION,
+                HeaderValue::from_str(&disposition)
+                    .unwrap_or(HeaderValue::from_static("attachment")),
+            ),
+            (
+                header::X_CONTENT_TYPE_OPTIONS,
+                HeaderValue::from_static("nosniff"),
+            ),
+        ],
+        content,
+    )
+        .into_response()
+}
+
+/// 按 RFC 5987 编码文件名
+fn encode_filename(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    for byte in name.bytes() {
+        if byte.is_ascii_alphanumeric() || b"!#$&+-.^_`|~".contains(&byte) {
+            result.push(byte as char);
+        } else {
+            result.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    result
+}
@@ -0,0 +1,74 @@
+use aide::axum::{ApiRouter, routing};
+use axum::debug_handler;
+use daoyi_common_support::app::AppState;
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::request::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::response::{ApiResponse, RestApiResult};
+use daoyi_common_support::vo::system_vo::{
+    FileConfigPageReqVO, FileConfigRespVO, FileConfigSaveReqVO, IdReqVO,
+};
+use daoyi_entity_system::system_service::system_file_config_service;
+
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/create", routing::post(create_file_config))
+        .api_route("/update", routing::put(update_file_config))
+        .api_route("/update-master", routing::put(update_file_config_master))
+        .api_route("/delete", routing::delete(delete_file_config))
+        .api_route("/get", routing::get(get_file_config))
+        .api_route("/page", routing::get(get_file_config_page))
+        .api_route("/test", routing::get(test_file_config))
+}
+
+#[debug_handler]
+async fn create_file_config(
+    ValidJson(req): ValidJson<FileConfigSaveReqVO>,
+) -> RestApiResult<String> {
+    ApiResponse::success(system_file_config_service::create_file_config(req).await?)
+}
+
+#[debug_handler]
+async fn update_file_config(ValidJson(req): ValidJson<FileConfigSaveReqVO>) -> RestApiResult<bool> {
+    system_file_config_service::update_file_config(req).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn update_file_config_master(
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<bool> {
+    system_file_config_service::update_file_config_master(&id).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn delete_file_config(
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<bool> {
+    system_file_config_service::delete_file_config(&id).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn get_file_config(
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<FileConfigRespVO> {
+    ApiResponse::success(
+        system_file_config_service::get_file_config_by_id(&id)
+            .await?
+            .into(),
+    )
+}
+
+#[debug_handler]
+async fn get_file_config_page(
+    ValidQuery(req): ValidQuery<FileConfigPageReqVO>,
+) -> RestApiResult<Page<FileConfigRespVO>> {
+    ApiResponse::success(system_file_config_service::get_file_config_page(req).await?)
+}
+
+#[debug_handler]
+async fn test_file_config(ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>) -> RestApiResult<bool> {
+    system_file_config_service::test_file_config(&id).await?;
+    ApiResponse::success(true)
+}
@@ -1,6 +1,6 @@
-use axum::Router;
+use aide::axum::ApiRouter;
 use daoyi_common_support::app::AppState;
 
-pub fn create_router() -> Router<AppState> {
-    Router::new()
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
 }
\ No newline at end of file
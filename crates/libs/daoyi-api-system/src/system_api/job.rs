@@ -0,0 +1,89 @@
+use aide::axum::{ApiRouter, routing};
+use axum::debug_handler;
+use daoyi_common_support::app::AppState;
+use daoyi_common_support::job;
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::request::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::response::{ApiResponse, RestApiResult};
+use daoyi_common_support::vo::system_vo::{
+    IdReqVO, JobNextTimesReqVO, JobPageReqVO, JobRespVO, JobSaveReqVO,
+};
+use daoyi_entity_system::system_service::system_job_service;
+
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/create", routing::post(create_job))
+        .api_route("/update", routing::put(update_job))
+        .api_route("/delete", routing::delete(delete_job))
+        .api_route("/get", routing::get(get_job))
+        .api_route("/page", routing::get(get_job_page))
+        .api_route("/pause", routing::put(pause_job))
+        .api_route("/resume", routing::put(resume_job))
+        .api_route("/trigger", routing::put(trigger_job))
+        .api_route("/get-next-times", routing::get(get_job_next_times))
+        .api_route("/handler-list", routing::get(get_handler_list))
+}
+
+#[debug_handler]
+async fn create_job(ValidJson(req): ValidJson<JobSaveReqVO>) -> RestApiResult<String> {
+    ApiResponse::success(system_job_service::create_job(req).await?)
+}
+
+#[debug_handler]
+async fn update_job(ValidJson(req): ValidJson<JobSaveReqVO>) -> RestApiResult<bool> {
+    system_job_service::update_job(req).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn delete_job(ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>) -> RestApiResult<bool> {
+    system_job_service::delete_job(&id).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn get_job(ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>) -> RestApiResult<JobRespVO> {
+    ApiResponse::success(system_job_service::get_job_by_id(&id).await?.into())
+}
+
+#[debug_handler]
+async fn get_job_page(ValidQuery(req): ValidQuery<JobPageReqVO>) -> RestApiResult<Page<JobRespVO>> {
+    ApiResponse::success(system_job_service::get_job_page(req).await?)
+}
+
+#[debug_handler]
+async fn pause_job(ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>) -> RestApiResult<bool> {
+    system_job_service::pause_job(&id).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn resume_job(ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>) -> RestApiResult<bool> {
+    system_job_service::resume_job(&id).await?;
+    ApiResponse::success(true)
+}
+
+/// 立即执行一次任务，任务在后台执行，结果见任务日志
+#[debug_handler]
+async fn trigger_job(ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>) -> RestApiResult<bool> {
+    system_job_service::trigger_job(&id).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn get_job_next_times(
+    ValidQuery(req): ValidQuery<JobNextTimesReqVO>,
+) -> RestApiResult<Vec<String>> {
+    let times = system_job_service::get_job_next_times(req)
+        .await?
+        .into_iter()
+        .map(|x| x.format("%Y-%m-%d %H:%M:%S").to_string())
+        .collect();
+    ApiResponse::success(times)
+}
+
+/// 已注册的任务处理器名称，用于新增任务时选择
+#[debug_handler]
+async fn get_handler_list() -> RestApiResult<Vec<&'static str>> {
+    ApiResponse::success(job::get_handler_names())
+}
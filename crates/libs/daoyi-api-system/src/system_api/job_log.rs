@@ -0,0 +1,28 @@
+use aide::axum::{ApiRouter, routing};
+use axum::debug_handler;
+use daoyi_common_support::app::AppState;
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::request::valid::ValidQuery;
+use daoyi_common_support::response::{ApiResponse, RestApiResult};
+use daoyi_common_support::vo::system_vo::{IdReqVO, JobLogPageReqVO, JobLogRespVO};
+use daoyi_entity_system::system_service::system_job_service;
+
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/get", routing::get(get_job_log))
+        .api_route("/page", routing::get(get_job_log_page))
+}
+
+#[debug_handler]
+async fn get_job_log(
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<JobLogRespVO> {
+    ApiResponse::success(system_job_service::get_job_log_by_id(&id).await?.into())
+}
+
+#[debug_handler]
+async fn get_job_log_page(
+    ValidQuery(req): ValidQuery<JobLogPageReqVO>,
+) -> RestApiResult<Page<JobLogRespVO>> {
+    ApiResponse::success(system_job_service::get_job_log_page(req).await?)
+}
@@ -0,0 +1,32 @@
+use aide::axum::{ApiRouter, routing};
+use axum::debug_handler;
+use daoyi_common_support::app::AppState;
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::request::valid::ValidQuery;
+use daoyi_common_support::response::{ApiResponse, RestApiResult};
+use daoyi_common_support::vo::system_vo::{IdReqVO, LoginLogPageReqVO, LoginLogRespVO};
+use daoyi_entity_system::system_service::system_login_log_service;
+
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/get", routing::get(get_login_log))
+        .api_route("/page", routing::get(get_login_log_page))
+}
+
+#[debug_handler]
+async fn get_login_log(
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<LoginLogRespVO> {
+    ApiResponse::success(
+        system_login_log_service::get_login_log_by_id(&id)
+            .await?
+            .into(),
+    )
+}
+
+#[debug_handler]
+async fn get_login_log_page(
+    ValidQuery(req): ValidQuery<LoginLogPageReqVO>,
+) -> RestApiResult<Page<LoginLogRespVO>> {
+    ApiResponse::success(system_login_log_service::get_login_log_page(req).await?)
+}
@@ -1,6 +1,170 @@
-use axum::Router;
+use aide::axum::{ApiRouter, routing};
+use axum::debug_handler;
 use daoyi_common_support::app::AppState;
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::request::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::response::{ApiResponse, RestApiResult};
+use daoyi_common_support::vo::system_vo::{
+    IdReqVO, MailAccountPageReqVO, MailAccountRespVO, MailAccountSaveReqVO,
+    MailAccountSimpleRespVO, MailLogPageReqVO, MailLogRespVO, MailTemplatePageReqVO,
+    MailTemplateRespVO, MailTemplateSaveReqVO, MailTemplateSendReqVO,
+};
+use daoyi_entity_system::system_service::{
+    system_mail_account_service, system_mail_log_service, system_mail_send_service,
+    system_mail_template_service,
+};
 
-pub fn create_router() -> Router<AppState> {
-    Router::new()
-}
\ No newline at end of file
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .nest("/account", create_account_router())
+        .nest("/template", create_template_router())
+        .nest("/log", create_log_router())
+}
+
+fn create_account_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/create", routing::post(create_mail_account))
+        .api_route("/update", routing::put(update_mail_account))
+        .api_route("/delete", routing::delete(delete_mail_account))
+        .api_route("/get", routing::get(get_mail_account))
+        .api_route("/page", routing::get(get_mail_account_page))
+        .api_route(
+            "/list-all-simple",
+            routing::get(get_simple_mail_account_list),
+        )
+        .api_route("/simple-list", routing::get(get_simple_mail_account_list))
+}
+
+fn create_template_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/create", routing::post(create_mail_template))
+        .api_route("/update", routing::put(update_mail_template))
+        .api_route("/delete", routing::delete(delete_mail_template))
+        .api_route("/get", routing::get(get_mail_template))
+        .api_route("/page", routing::get(get_mail_template_page))
+        .api_route("/send-mail", routing::post(send_mail))
+}
+
+fn create_log_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/get", routing::get(get_mail_log))
+        .api_route("/page", routing::get(get_mail_log_page))
+}
+
+#[debug_handler]
+async fn create_mail_account(
+    ValidJson(req): ValidJson<MailAccountSaveReqVO>,
+) -> RestApiResult<String> {
+    ApiResponse::success(system_mail_account_service::create_mail_account(req).await?)
+}
+
+#[debug_handler]
+async fn update_mail_account(
+    ValidJson(req): ValidJson<MailAccountSaveReqVO>,
+) -> RestApiResult<bool> {
+    system_mail_account_service::update_mail_account(req).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn delete_mail_account(
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<bool> {
+    system_mail_account_service::delete_mail_account(&id).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn get_mail_account(
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<MailAccountRespVO> {
+    ApiResponse::success(
+        system_mail_account_service::get_mail_account_by_id(&id)
+            .await?
+            .into(),
+    )
+}
+
+#[debug_handler]
+async fn get_mail_account_page(
+    ValidQuery(req): ValidQuery<MailAccountPageReqVO>,
+) -> RestApiResult<Page<MailAccountRespVO>> {
+    ApiResponse::success(system_mail_account_service::get_mail_account_page(req).await?)
+}
+
+#[debug_handler]
+async fn get_simple_mail_account_list() -> RestApiResult<Vec<MailAccountSimpleRespVO>> {
+    ApiResponse::success(system_mail_account_service::get_simple_mail_account_list().await?)
+}
+
+#[debug_handler]
+async fn create_mail_template(
+    ValidJson(req): ValidJson<MailTemplateSaveReqVO>,
+) -> RestApiResult<String> {
+    ApiResponse::success(system_mail_template_service::create_mail_template(req).await?)
+}
+
+#[debug_handler]
+async fn update_mail_template(
+    ValidJson(req): ValidJson<MailTemplateSaveReqVO>,
+) -> RestApiResult<bool> {
+    system_mail_template_service::update_mail_template(req).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn delete_mail_template(
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<bool> {
+    system_mail_template_service::delete_mail_template(&id).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn get_mail_template(
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<MailTemplateRespVO> {
+    ApiResponse::success(
+        system_mail_template_service::get_mail_template_by_id(&id)
+            .await?
+            .into(),
+    )
+}
+
+#[debug_handler]
+async fn get_mail_template_page(
+    ValidQuery(req): ValidQuery<MailTemplatePageReqVO>,
+) -> RestApiResult<Page<MailTemplateRespVO>> {
+    ApiResponse::success(system_mail_template_service::get_mail_template_page(req).await?)
+}
+
+#[debug_handler]
+async fn send_mail(ValidJson(req): ValidJson<MailTemplateSendReqVO>) -> RestApiResult<String> {
+    ApiResponse::success(
+        system_mail_send_service::send_single_mail(
+            &req.mail,
+            req.user_id,
+            &req.template_code,
+            req.template_params,
+        )
+        .await?,
+    )
+}
+
+#[debug_handler]
+async fn get_mail_log(
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<MailLogRespVO> {
+    ApiResponse::success(
+        system_mail_log_service::get_mail_log_by_id(&id)
+            .await?
+            .into(),
+    )
+}
+
+#[debug_handler]
+async fn get_mail_log_page(
+    ValidQuery(req): ValidQuery<MailLogPageReqVO>,
+) -> RestApiResult<Page<MailLogRespVO>> {
+    ApiResponse::success(system_mail_log_service::get_mail_log_page(req).await?)
+}
@@ -1,41 +1,57 @@
-use axum::Router;
+use aide::axum::ApiRouter;
 use daoyi_common_support::app::AppState;
 
 mod auth;
 mod captcha;
+mod config;
 mod dept;
 mod dict_data;
 mod dict_type;
+mod file;
+mod file_config;
 mod ip;
+mod job;
+mod job_log;
 mod logger;
+mod login_log;
 mod mail;
 mod notice;
 mod notify_message;
 mod notify_template;
 mod oauth2;
 mod permission;
+mod post;
 mod sms;
 mod social;
 mod tenant;
+mod tenant_package;
 mod user;
 
-pub fn create_router() -> Router<AppState> {
-    Router::new()
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
         .nest("/auth", auth::create_router())
         .nest("/captcha", captcha::create_router())
+        .nest("/config", config::create_router())
         .nest("/dept", dept::create_router())
         .nest("/dict-data", dict_data::create_router())
         .nest("/dict-type", dict_type::create_router())
+        .nest("/file", file::create_router())
+        .nest("/file-config", file_config::create_router())
         .nest("/ip", ip::create_router())
+        .nest("/job", job::create_router())
+        .nest("/job-log", job_log::create_router())
         .nest("/logger", logger::create_router())
+        .nest("/login-log", login_log::create_router())
         .nest("/mail", mail::create_router())
         .nest("/notice", notice::create_router())
         .nest("/notify-message", notify_message::create_router())
         .nest("/notify-template", notify_template::create_router())
         .nest("/oauth2", oauth2::create_router())
         .nest("/permission", permission::create_router())
+        .nest("/post", post::create_router())
         .nest("/sms", sms::create_router())
         .nest("/social", social::create_router())
         .nest("/tenant", tenant::create_router())
+        .nest("/tenant-package", tenant_package::create_router())
         .nest("/user", user::create_router())
 }
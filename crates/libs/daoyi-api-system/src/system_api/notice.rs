@@ -1,6 +1,116 @@
-use axum::Router;
+use aide::axum::{ApiRouter, routing};
+use axum::debug_handler;
+use axum::response::Sse;
+use axum::response::sse::{Event, KeepAlive};
 use daoyi_common_support::app::AppState;
+use daoyi_common_support::context::HttpRequestContext;
+use daoyi_common_support::error::{ApiError, ApiResult};
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::push;
+use daoyi_common_support::request::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::response::{ApiResponse, RestApiResult};
+use daoyi_common_support::vo::system_vo::{
+    IdReqVO, NoticePageReqVO, NoticeReadReqVO, NoticeRespVO, NoticeSaveReqVO,
+};
+use daoyi_entity_system::system_service::system_notice_service;
+use std::convert::Infallible;
+use tokio_stream::Stream;
 
-pub fn create_router() -> Router<AppState> {
-    Router::new()
-}
\ No newline at end of file
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/create", routing::post(create_notice))
+        .api_route("/update", routing::put(update_notice))
+        .api_route("/delete", routing::delete(delete_notice))
+        .api_route("/get", routing::get(get_notice))
+        .api_route("/page", routing::get(get_notice_page))
+        .api_route("/publish", routing::put(publish_notice))
+        .api_route("/my-unread-list", routing::get(get_my_unread_notice_list))
+        .api_route(
+            "/get-unread-count",
+            routing::get(get_my_unread_notice_count),
+        )
+        .api_route("/mark-read", routing::put(mark_notice_read))
+        .api_route("/mark-all-read", routing::put(mark_all_notice_read))
+        .route("/subscribe", axum::routing::get(subscribe))
+}
+
+#[debug_handler]
+async fn create_notice(ValidJson(req): ValidJson<NoticeSaveReqVO>) -> RestApiResult<String> {
+    ApiResponse::success(system_notice_service::create_notice(req).await?)
+}
+
+#[debug_handler]
+async fn update_notice(ValidJson(req): ValidJson<NoticeSaveReqVO>) -> RestApiResult<bool> {
+    system_notice_service::update_notice(req).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn delete_notice(ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>) -> RestApiResult<bool> {
+    system_notice_service::delete_notice(&id).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn get_notice(
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<NoticeRespVO> {
+    ApiResponse::success(system_notice_service::get_notice_by_id(&id).await?.into())
+}
+
+#[debug_handler]
+async fn get_notice_page(
+    ValidQuery(req): ValidQuery<NoticePageReqVO>,
+) -> RestApiResult<Page<NoticeRespVO>> {
+    ApiResponse::success(system_notice_service::get_notice_page(req).await?)
+}
+
+#[debug_handler]
+async fn publish_notice(ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>) -> RestApiResult<bool> {
+    system_notice_service::publish_notice(&id).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn get_my_unread_notice_list() -> RestApiResult<Vec<NoticeRespVO>> {
+    ApiResponse::success(
+        system_notice_service::get_my_unread_notice_list()
+            .await?
+            .into_iter()
+            .map(|x| x.into())
+            .collect(),
+    )
+}
+
+#[debug_handler]
+async fn get_my_unread_notice_count() -> RestApiResult<usize> {
+    ApiResponse::success(
+        system_notice_service::get_my_unread_notice_list()
+            .await?
+            .len(),
+    )
+}
+
+#[debug_handler]
+async fn mark_notice_read(ValidJson(req): ValidJson<NoticeReadReqVO>) -> RestApiResult<bool> {
+    system_notice_service::mark_notice_read(&req.ids).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn mark_all_notice_read() -> RestApiResult<bool> {
+    system_notice_service::mark_all_notice_read().await?;
+    ApiResponse::success(true)
+}
+
+/// 订阅当前登录用户的推送消息（SSE），建立连接即视为在线
+#[debug_handler]
+async fn subscribe() -> ApiResult<Sse<impl Stream<Item = Result<Event, Infallible>>>> {
+    let tenant_id = HttpRequestContext::get_tenant_id()
+        .await
+        .ok_or_else(|| ApiError::unauthenticated("No Tenant header"))?;
+    let user_id = HttpRequestContext::get_login_id()
+        .await
+        .ok_or_else(|| ApiError::unauthenticated("用户未登录"))?;
+    Ok(Sse::new(push::subscribe(tenant_id, user_id)).keep_alive(KeepAlive::default()))
+}
@@ -1,15 +1,50 @@
-use axum::{Router, debug_handler, routing};
+use aide::axum::{ApiRouter, routing};
+use axum::debug_handler;
 use daoyi_common_support::app::AppState;
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::request::valid::{ValidJson, ValidQuery};
 use daoyi_common_support::response::{ApiResponse, RestApiResult};
+use daoyi_common_support::vo::system_vo::{
+    NotifyMessageMyPageReqVO, NotifyMessageReadReqVO, NotifyMessageRespVO,
+};
+use daoyi_entity_system::system_service::system_notify_message_service;
 
-pub fn create_router() -> Router<AppState> {
-    Router::new().route(
-        "/get-unread-count",
-        routing::get(get_unread_notify_message_count),
-    )
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/my-page", routing::get(get_my_notify_message_page))
+        .api_route("/update-read", routing::put(update_notify_message_read))
+        .api_route(
+            "/update-all-read",
+            routing::put(update_all_notify_message_read),
+        )
+        .api_route(
+            "/get-unread-count",
+            routing::get(get_unread_notify_message_count),
+        )
+}
+
+#[debug_handler]
+async fn get_my_notify_message_page(
+    ValidQuery(req): ValidQuery<NotifyMessageMyPageReqVO>,
+) -> RestApiResult<Page<NotifyMessageRespVO>> {
+    ApiResponse::success(system_notify_message_service::get_my_notify_message_page(req).await?)
+}
+
+#[debug_handler]
+async fn update_notify_message_read(
+    ValidJson(req): ValidJson<NotifyMessageReadReqVO>,
+) -> RestApiResult<bool> {
+    system_notify_message_service::update_notify_message_read(&req.ids).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn update_all_notify_message_read() -> RestApiResult<bool> {
+    system_notify_message_service::update_all_notify_message_read().await?;
+    ApiResponse::success(true)
 }
 
 #[debug_handler]
 async fn get_unread_notify_message_count() -> RestApiResult<u64> {
-    ApiResponse::success(0)
+    ApiResponse::success(system_notify_message_service::get_unread_notify_message_count().await?)
 }
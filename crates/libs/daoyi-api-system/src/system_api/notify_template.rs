@@ -1,6 +1,78 @@
-use axum::Router;
+use aide::axum::{ApiRouter, routing};
+use axum::debug_handler;
 use daoyi_common_support::app::AppState;
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::request::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::response::{ApiResponse, RestApiResult};
+use daoyi_common_support::vo::system_vo::{
+    IdReqVO, NotifyTemplatePageReqVO, NotifyTemplateRespVO, NotifyTemplateSaveReqVO,
+    NotifyTemplateSendReqVO,
+};
+use daoyi_entity_system::system_service::{
+    system_notify_send_service, system_notify_template_service,
+};
 
-pub fn create_router() -> Router<AppState> {
-    Router::new()
-}
\ No newline at end of file
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/create", routing::post(create_notify_template))
+        .api_route("/update", routing::put(update_notify_template))
+        .api_route("/delete", routing::delete(delete_notify_template))
+        .api_route("/get", routing::get(get_notify_template))
+        .api_route("/page", routing::get(get_notify_template_page))
+        .api_route("/send-notify", routing::post(send_notify))
+}
+
+#[debug_handler]
+async fn create_notify_template(
+    ValidJson(req): ValidJson<NotifyTemplateSaveReqVO>,
+) -> RestApiResult<String> {
+    ApiResponse::success(system_notify_template_service::create_notify_template(req).await?)
+}
+
+#[debug_handler]
+async fn update_notify_template(
+    ValidJson(req): ValidJson<NotifyTemplateSaveReqVO>,
+) -> RestApiResult<bool> {
+    system_notify_template_service::update_notify_template(req).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn delete_notify_template(
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<bool> {
+    system_notify_template_service::delete_notify_template(&id).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn get_notify_template(
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<NotifyTemplateRespVO> {
+    ApiResponse::success(
+        system_notify_template_service::get_notify_template_by_id(&id)
+            .await?
+            .into(),
+    )
+}
+
+#[debug_handler]
+async fn get_notify_template_page(
+    ValidQuery(req): ValidQuery<NotifyTemplatePageReqVO>,
+) -> RestApiResult<Page<NotifyTemplateRespVO>> {
+    ApiResponse::success(system_notify_template_service::get_notify_template_page(req).await?)
+}
+
+#[debug_handler]
+async fn send_notify(
+    ValidJson(req): ValidJson<NotifyTemplateSendReqVO>,
+) -> RestApiResult<Option<String>> {
+    ApiResponse::success(
+        system_notify_send_service::send_single_to_user(
+            &req.user_id,
+            &req.template_code,
+            req.template_params,
+        )
+        .await?,
+    )
+}
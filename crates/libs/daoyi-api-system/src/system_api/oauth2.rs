@@ -1,17 +1,20 @@
-use axum::{debug_handler, routing, Router};
+use aide::axum::{ApiRouter, routing};
+use axum::debug_handler;
 use daoyi_common_support::app::AppState;
 use daoyi_common_support::request::valid::ValidQuery;
+use daoyi_common_support::request::validation::ValidateDict;
 use daoyi_common_support::response::{ApiResponse, RestApiResult};
 use daoyi_common_support::vo::system_vo::AuthLoginRespVO;
 use daoyi_entity_system::system_service::system_access_token_service;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use validator::Validate;
 
-pub fn create_router() -> Router<AppState> {
-    Router::new().route("/check-token", routing::post(check_token))
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new().api_route("/check-token", routing::post(check_token))
 }
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CheckTokenParams {
     token: String,
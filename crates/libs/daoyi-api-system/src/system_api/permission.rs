@@ -1,6 +1,39 @@
-use axum::Router;
+use aide::axum::{ApiRouter, routing};
+use axum::debug_handler;
 use daoyi_common_support::app::AppState;
+use daoyi_common_support::request::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::request::validation::ValidateDict;
+use daoyi_common_support::response::{ApiResponse, RestApiResult};
+use daoyi_common_support::vo::system_vo::PermissionAssignRoleMenuReqVO;
+use daoyi_entity_system::system_service::system_role_menu_service;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use validator::Validate;
 
-pub fn create_router() -> Router<AppState> {
-    Router::new()
-}
\ No newline at end of file
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/list-role-menus", routing::get(get_role_menu_list))
+        .api_route("/assign-role-menu", routing::post(assign_role_menu))
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleIdParams {
+    role_id: String,
+}
+#[debug_handler]
+async fn get_role_menu_list(
+    ValidQuery(RoleIdParams { role_id }): ValidQuery<RoleIdParams>,
+) -> RestApiResult<Vec<String>> {
+    ApiResponse::success(
+        system_role_menu_service::get_role_menu_list_by_role_id(&vec![role_id]).await?,
+    )
+}
+
+#[debug_handler]
+async fn assign_role_menu(
+    ValidJson(req): ValidJson<PermissionAssignRoleMenuReqVO>,
+) -> RestApiResult<bool> {
+    system_role_menu_service::assign_role_menu(&req.role_id, req.menu_ids).await?;
+    ApiResponse::success(true)
+}
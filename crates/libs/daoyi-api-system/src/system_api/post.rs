@@ -0,0 +1,79 @@
+use aide::axum::{ApiRouter, routing};
+use axum::debug_handler;
+use axum::extract::State;
+use daoyi_common_support::app::AppState;
+use daoyi_common_support::enumeration::CommonStatusEnum;
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::request::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::response::{ApiResponse, RestApiResult};
+use daoyi_common_support::vo::system_vo::{
+    IdReqVO, PostPageReqVO, PostRespVO, PostSaveReqVO, PostSimpleRespVO,
+};
+use daoyi_entity_system::system_service::system_post_service;
+use sea_orm::DatabaseConnection;
+
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/create", routing::post(create_post))
+        .api_route("/update", routing::put(update_post))
+        .api_route("/delete", routing::delete(delete_post))
+        .api_route("/get", routing::get(get_post))
+        .api_route("/page", routing::get(get_post_page))
+        .api_route("/list-all-simple", routing::get(get_simple_post_list))
+        .api_route("/simple-list", routing::get(get_simple_post_list))
+}
+
+#[debug_handler]
+async fn create_post(
+    State(db): State<DatabaseConnection>,
+    ValidJson(req): ValidJson<PostSaveReqVO>,
+) -> RestApiResult<String> {
+    ApiResponse::success(system_post_service::create_post(&db, req).await?)
+}
+
+#[debug_handler]
+async fn update_post(
+    State(db): State<DatabaseConnection>,
+    ValidJson(req): ValidJson<PostSaveReqVO>,
+) -> RestApiResult<bool> {
+    system_post_service::update_post(&db, req).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn delete_post(
+    State(db): State<DatabaseConnection>,
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<bool> {
+    system_post_service::delete_post(&db, &id).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn get_post(
+    State(db): State<DatabaseConnection>,
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<PostRespVO> {
+    ApiResponse::success(system_post_service::get_post_by_id(&db, &id).await?.into())
+}
+
+#[debug_handler]
+async fn get_post_page(
+    State(db): State<DatabaseConnection>,
+    ValidQuery(req): ValidQuery<PostPageReqVO>,
+) -> RestApiResult<Page<PostRespVO>> {
+    ApiResponse::success(system_post_service::get_post_page(&db, req).await?)
+}
+
+#[debug_handler]
+async fn get_simple_post_list(
+    State(db): State<DatabaseConnection>,
+) -> RestApiResult<Vec<PostSimpleRespVO>> {
+    ApiResponse::success(
+        system_post_service::get_post_list(&db, None, Some(CommonStatusEnum::Enable))
+            .await?
+            .into_iter()
+            .map(|x| x.into())
+            .collect(),
+    )
+}
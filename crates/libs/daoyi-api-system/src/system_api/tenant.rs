@@ -1,19 +1,36 @@
-use axum::{debug_handler, routing, Router};
+use aide::axum::{ApiRouter, routing};
+use axum::debug_handler;
 use daoyi_common_support::app::AppState;
 use daoyi_common_support::enumeration::CommonStatusEnum;
-use daoyi_common_support::request::valid::ValidQuery;
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::request::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::request::validation::ValidateDict;
 use daoyi_common_support::response::{ApiResponse, RestApiResult};
-use daoyi_common_support::vo::system_vo::TenantRespVO;
+use daoyi_common_support::vo::system_vo::{
+    IdReqVO, TenantPageReqVO, TenantRespVO, TenantSaveReqVO, TenantUpdateExpireTimeReqVO,
+    TenantUpdateStatusReqVO,
+};
 use daoyi_entity_system::system_service::system_tenant_service;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use validator::Validate;
 
-pub fn create_router() -> Router<AppState> {
-    Router::new()
-        .route("/check-tenant-id", routing::post(check_tenant_id))
-        .route("/get-by-website", routing::get(get_tenant_by_website))
-        .route("/get-id-by-name", routing::get(get_tenant_id_by_name))
-        .route("/simple-list", routing::get(get_tenant_simple_list))
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/check-tenant-id", routing::post(check_tenant_id))
+        .api_route("/get-by-website", routing::get(get_tenant_by_website))
+        .api_route("/get-id-by-name", routing::get(get_tenant_id_by_name))
+        .api_route("/simple-list", routing::get(get_tenant_simple_list))
+        .api_route("/create", routing::post(create_tenant))
+        .api_route("/update", routing::put(update_tenant))
+        .api_route("/update-status", routing::put(update_tenant_status))
+        .api_route(
+            "/update-expire-time",
+            routing::put(update_tenant_expire_time),
+        )
+        .api_route("/delete", routing::delete(delete_tenant))
+        .api_route("/get", routing::get(get_tenant))
+        .api_route("/page", routing::get(get_tenant_page))
 }
 #[debug_handler]
 async fn get_tenant_simple_list() -> RestApiResult<Vec<TenantRespVO>> {
@@ -25,7 +42,7 @@ async fn get_tenant_simple_list() -> RestApiResult<Vec<TenantRespVO>> {
     ApiResponse::success(list)
 }
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct GetTenantIdByNameParams {
     name: String,
@@ -40,7 +57,7 @@ async fn get_tenant_id_by_name(
     ApiResponse::success(None)
 }
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct GetTenantByWebsiteParams {
     website: String,
@@ -58,7 +75,7 @@ async fn get_tenant_by_website(
     ApiResponse::success(None)
 }
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CheckTenantParams {
     tenant_id: String,
@@ -69,3 +86,50 @@ async fn check_tenant_id(
 ) -> RestApiResult<TenantRespVO> {
     ApiResponse::success(system_tenant_service::check_tenant_id(&tenant_id).await?)
 }
+
+#[debug_handler]
+async fn create_tenant(ValidJson(req): ValidJson<TenantSaveReqVO>) -> RestApiResult<String> {
+    ApiResponse::success(system_tenant_service::create_tenant(req).await?)
+}
+
+#[debug_handler]
+async fn update_tenant(ValidJson(req): ValidJson<TenantSaveReqVO>) -> RestApiResult<bool> {
+    system_tenant_service::update_tenant(req).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn update_tenant_status(
+    ValidJson(req): ValidJson<TenantUpdateStatusReqVO>,
+) -> RestApiResult<bool> {
+    system_tenant_service::update_tenant_status(req).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn update_tenant_expire_time(
+    ValidJson(req): ValidJson<TenantUpdateExpireTimeReqVO>,
+) -> RestApiResult<bool> {
+    system_tenant_service::update_tenant_expire_time(req).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn delete_tenant(ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>) -> RestApiResult<bool> {
+    system_tenant_service::delete_tenant(&id).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn get_tenant(
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<TenantRespVO> {
+    ApiResponse::success(system_tenant_service::get_tenant_by_id(&id).await?.into())
+}
+
+#[debug_handler]
+async fn get_tenant_page(
+    ValidQuery(req): ValidQuery<TenantPageReqVO>,
+) -> RestApiResult<Page<TenantRespVO>> {
+    ApiResponse::success(system_tenant_service::get_tenant_page(req).await?)
+}
@@ -0,0 +1,80 @@
+use aide::axum::{ApiRouter, routing};
+use axum::debug_handler;
+use daoyi_common_support::app::AppState;
+use daoyi_common_support::enumeration::CommonStatusEnum;
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::request::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::response::{ApiResponse, RestApiResult};
+use daoyi_common_support::vo::system_vo::{
+    IdReqVO, TenantPackagePageReqVO, TenantPackageRespVO, TenantPackageSaveReqVO,
+    TenantPackageSimpleRespVO,
+};
+use daoyi_entity_system::system_service::system_tenant_package_service;
+
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/create", routing::post(create_tenant_package))
+        .api_route("/update", routing::put(update_tenant_package))
+        .api_route("/delete", routing::delete(delete_tenant_package))
+        .api_route("/get", routing::get(get_tenant_package))
+        .api_route("/page", routing::get(get_tenant_package_page))
+        .api_route(
+            "/get-simple-list",
+            routing::get(get_simple_tenant_package_list),
+        )
+        .api_route("/simple-list", routing::get(get_simple_tenant_package_list))
+}
+
+#[debug_handler]
+async fn create_tenant_package(
+    ValidJson(req): ValidJson<TenantPackageSaveReqVO>,
+) -> RestApiResult<String> {
+    ApiResponse::success(system_tenant_package_service::create_tenant_package(req).await?)
+}
+
+#[debug_handler]
+async fn update_tenant_package(
+    ValidJson(req): ValidJson<TenantPackageSaveReqVO>,
+) -> RestApiResult<bool> {
+    system_tenant_package_service::update_tenant_package(req).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn delete_tenant_package(
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<bool> {
+    system_tenant_package_service::delete_tenant_package(&id).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn get_tenant_package(
+    ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>,
+) -> RestApiResult<TenantPackageRespVO> {
+    ApiResponse::success(
+        system_tenant_package_service::get_tenant_package_by_id(&id)
+            .await?
+            .into(),
+    )
+}
+
+#[debug_handler]
+async fn get_tenant_package_page(
+    ValidQuery(req): ValidQuery<TenantPackagePageReqVO>,
+) -> RestApiResult<Page<TenantPackageRespVO>> {
+    ApiResponse::success(system_tenant_package_service::get_tenant_package_page(req).await?)
+}
+
+#[debug_handler]
+async fn get_simple_tenant_package_list() -> RestApiResult<Vec<TenantPackageSimpleRespVO>> {
+    ApiResponse::success(
+        system_tenant_package_service::get_tenant_package_list_by_status(Some(
+            CommonStatusEnum::Enable,
+        ))
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect(),
+    )
+}
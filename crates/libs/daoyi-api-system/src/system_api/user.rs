@@ -1,6 +1,40 @@
-use axum::Router;
+use aide::axum::{ApiRouter, routing};
+use axum::debug_handler;
 use daoyi_common_support::app::AppState;
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::request::valid::{ValidJson, ValidQuery};
+use daoyi_common_support::response::{ApiResponse, RestApiResult};
+use daoyi_common_support::vo::system_vo::{IdReqVO, UserPageReqVO, UserRespVO, UserSaveReqVO};
+use daoyi_entity_system::system_service::system_users_service;
 
-pub fn create_router() -> Router<AppState> {
-    Router::new()
-}
\ No newline at end of file
+pub fn create_router() -> ApiRouter<AppState> {
+    ApiRouter::new()
+        .api_route("/create", routing::post(create_user))
+        .api_route("/update", routing::put(update_user))
+        .api_route("/get", routing::get(get_user))
+        .api_route("/page", routing::get(get_user_page))
+}
+
+#[debug_handler]
+async fn create_user(ValidJson(req): ValidJson<UserSaveReqVO>) -> RestApiResult<String> {
+    ApiResponse::success(system_users_service::create_user(req).await?)
+}
+
+#[debug_handler]
+async fn update_user(ValidJson(req): ValidJson<UserSaveReqVO>) -> RestApiResult<bool> {
+    system_users_service::update_user(req).await?;
+    ApiResponse::success(true)
+}
+
+#[debug_handler]
+async fn get_user(ValidQuery(IdReqVO { id }): ValidQuery<IdReqVO>) -> RestApiResult<UserRespVO> {
+    let user = system_users_service::get_by_id(&id).await?;
+    ApiResponse::success(system_users_service::build_user_resp_vo(user).await?)
+}
+
+#[debug_handler]
+async fn get_user_page(
+    ValidQuery(req): ValidQuery<UserPageReqVO>,
+) -> RestApiResult<Page<UserRespVO>> {
+    ApiResponse::success(system_users_service::get_user_page(req).await?)
+}
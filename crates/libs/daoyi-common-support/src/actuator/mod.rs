@@ -0,0 +1,176 @@
+//! 健康检查接口，供负载均衡与容器编排探测服务状态
+//!
+//! - `/actuator/health`、`/actuator/health/readiness`：检查数据库、Redis 以及启用时的 Nacos，
+//!   任一依赖不可用时返回 503
+//! - `/actuator/health/liveness`：只表示进程仍在响应，不检查外部依赖，避免依赖故障时服务被反复重启
+//!
+//! 路由在认证层之后合并，无需令牌与租户，也不需要配置 `ignored_urls`
+
+use crate::app::AppState;
+use crate::configs::nacos_config::NacosConfig;
+use crate::telemetry;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::{Json, Router, routing};
+use deadpool_redis::redis;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+pub const HEALTH_PATH: &str = "/actuator/health";
+pub const READINESS_PATH: &str = "/actuator/health/readiness";
+pub const LIVENESS_PATH: &str = "/actuator/health/liveness";
+
+/// 单个依赖的检查超时时间
+const CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Status {
+    Up,
+    Down,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentHealth {
+    pub status: Status,
+    pub latency_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Health {
+    pub status: Status,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub components: BTreeMap<&'static str, ComponentHealth>,
+}
+
+impl Health {
+    /// 汇总各依赖的状态，任一依赖不可用时整体不可用
+    pub fn from_components(components: BTreeMap<&'static str, ComponentHealth>) -> Self {
+        let status = if components.values().all(|c| c.status == Status::Up) {
+            Status::Up
+        } else {
+            Status::Down
+        };
+        Health { status, components }
+    }
+}
+
+impl IntoResponse for Health {
+    fn into_response(self) -> Response {
+        let status_code = match self.status {
+            Status::Up => StatusCode::OK,
+            Status::Down => StatusCode::SERVICE_UNAVAILABLE,
+        };
+        (status_code, Json(self)).into_response()
+    }
+}
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route(HEALTH_PATH, routing::get(health))
+        .route(READINESS_PATH, routing::get(health))
+        .route(LIVENESS_PATH, routing::get(liveness))
+}
+
+async fn health(State(state): State<AppState>) -> Health {
+    let nacos = state.config().nacos();
+    let (db, redis, nacos) = tokio::join!(
+        check(async { state.db().ping().await.map_err(|e| e.to_string()) }),
+        check(ping_redis(&state)),
+        async {
+            if nacos.enable() {
+                Some(check(ping_nacos(nacos)).await)
+            } else {
+                None
+            }
+        },
+    );
+    let mut components = BTreeMap::from([("db", db), ("redis", redis)]);
+    if let Some(nacos) = nacos {
+        components.insert("nacos", nacos);
+    }
+    let health = Health::from_components(components);
+    if health.status == Status::Down {
+        tracing::warn!("Health check failed: {:?}", health.components);
+    }
+    health
+}
+
+async fn liveness() -> Health {
+    Health::from_components(BTreeMap::new())
+}
+
+/// 执行一次依赖检查，记录耗时，超时视为不可用
+async fn check(future: impl Future<Output = Result<(), String>>) -> ComponentHealth {
+    let start = Instant::now();
+    let result = tokio::time::timeout(CHECK_TIMEOUT, future)
+        .await
+        .unwrap_or_else(|_| Err(format!("检查超时（{}秒）", CHECK_TIMEOUT.as_secs())));
+    ComponentHealth {
+        status: if result.is_ok() {
+            Status::Up
+        } else {
+            Status::Down
+        },
+        latency_ms: start.elapsed().as_millis() as u64,
+        error: result.err(),
+    }
+}
+
+async fn ping_redis(state: &AppState) -> Result<(), String> {
+    let mut conn = state.redis().get().await.map_err(|e| e.to_string())?;
+    redis::cmd("PING")
+        .query_async::<String>(&mut conn)
+        .await
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+async fn ping_nacos(config: &NacosConfig) -> Result<(), String> {
+    let url = format!(
+        "http://{}/nacos/v1/console/health/readiness",
+        config.server_addr()
+    );
+    let response = telemetry::traced(reqwest::Client::new().get(url))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(format!("Nacos 返回状态码 {}", response.status()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component(status: Status) -> ComponentHealth {
+        ComponentHealth {
+            status,
+            latency_ms: 1,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn down_when_any_component_down() {
+        let health = Health::from_components(BTreeMap::from([
+            ("db", component(Status::Up)),
+            ("redis", component(Status::Down)),
+        ]));
+        assert_eq!(health.status, Status::Down);
+        assert_eq!(
+            health.into_response().status(),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+        let health = Health::from_components(BTreeMap::from([("db", component(Status::Up))]));
+        assert_eq!(health.into_response().status(), StatusCode::OK);
+    }
+}
@@ -1,25 +1,153 @@
 use crate::configs::AppConfig;
+use crate::id::IdGenerator;
 use crate::{database, id, logger, redis_utils, server};
-use axum::Router;
+use aide::axum::ApiRouter;
+use axum::extract::FromRef;
+use deadpool_redis::Pool;
+use sea_orm::DatabaseConnection;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
 use tracing::log;
 
+/// 应用状态，持有配置、数据库连接池、Redis 连接池与 ID 生成器
+///
+/// 处理函数通过 `State<DatabaseConnection>`、`State<Pool>`、`State<Arc<AppConfig>>`、
+/// `State<IdGenerator>` 提取所需的组件；尚未迁移的代码仍通过 `database::get()`、
+/// `AppConfig::get()` 等全局入口访问由 [`AppState::install_global`] 安装的默认实例
 #[derive(Clone)]
-pub struct AppState {}
+pub struct AppState {
+    config: Arc<AppConfig>,
+    db: DatabaseConnection,
+    redis: Pool,
+    id_generator: IdGenerator,
+}
+
+impl AppState {
+    pub fn new(
+        config: Arc<AppConfig>,
+        db: DatabaseConnection,
+        redis: Pool,
+        id_generator: IdGenerator,
+    ) -> Self {
+        Self {
+            config,
+            db,
+            redis,
+            id_generator,
+        }
+    }
+
+    /// 按配置连接 Redis 与数据库，并创建 ID 生成器
+    pub async fn connect(config: Arc<AppConfig>) -> anyhow::Result<Self> {
+        let redis = redis_utils::connect(config.redis()).await?;
+        log::info!("redis组件初始化完成... Starting id generator...");
+        let id_generator = IdGenerator::new(id::DEFAULT_WORKER_ID)?;
+        log::info!("id generator 初始化完成... Starting database...");
+        let db = database::connect(config.database()).await?;
+        log::info!("database 初始化完成...");
+        Ok(Self::new(config, db, redis, id_generator))
+    }
+
+    pub fn config(&self) -> &AppConfig {
+        &self.config
+    }
+
+    pub fn db(&self) -> &DatabaseConnection {
+        &self.db
+    }
+
+    pub fn redis(&self) -> &Pool {
+        &self.redis
+    }
+
+    pub fn id_generator(&self) -> &IdGenerator {
+        &self.id_generator
+    }
+
+    /// 将本实例的组件安装为全局默认实例，兼容仍通过全局入口访问的代码，只有首次安装生效
+    pub fn install_global(&self) {
+        AppConfig::install(self.config.clone());
+        database::install(self.db.clone());
+        redis_utils::install(self.redis.clone());
+        id::install(self.id_generator.clone());
+    }
+}
+
+impl FromRef<AppState> for Arc<AppConfig> {
+    fn from_ref(state: &AppState) -> Self {
+        state.config.clone()
+    }
+}
+
+impl FromRef<AppState> for DatabaseConnection {
+    fn from_ref(state: &AppState) -> Self {
+        state.db.clone()
+    }
+}
+
+impl FromRef<AppState> for Pool {
+    fn from_ref(state: &AppState) -> Self {
+        state.redis.clone()
+    }
+}
+
+impl FromRef<AppState> for IdGenerator {
+    fn from_ref(state: &AppState) -> Self {
+        state.id_generator.clone()
+    }
+}
+
+pub type StartupTask = fn() -> Pin<Box<dyn Future<Output = anyhow::Result<()>> + Send>>;
 
-pub async fn run(app_name: Option<&str>, router: Router<AppState>) -> anyhow::Result<()> {
+static STARTUP_TASKS: Mutex<Vec<StartupTask>> = Mutex::new(Vec::new());
+
+/// 注册启动任务，在数据库等基础组件初始化完成之后、服务启动之前按注册顺序执行
+pub fn register_startup_task(task: StartupTask) {
+    STARTUP_TASKS.lock().unwrap().push(task);
+}
+
+pub async fn run(app_name: Option<&str>, router: ApiRouter<AppState>) -> anyhow::Result<()> {
     println!("==============================================开始加载配置...");
-    AppConfig::load(app_name.unwrap_or("app")).await?;
-    println!("配置信息：\n{:#?}", AppConfig::get().await);
+    let config = Arc::new(AppConfig::read(app_name.unwrap_or("app")).await?);
+    AppConfig::install(config.clone());
+    println!("配置信息：\n{:#?}", config);
     println!("==============================================配置加载完成...开始初始化日志组件....");
-    logger::init().await;
+    // 守卫在服务关闭后随函数返回释放，写出剩余的文件日志
+    let _log_guard = logger::init().await?;
     log::info!("日志组件初始化完成... Starting redis_utils...");
-    redis_utils::init_redis().await?;
-    log::info!("redis组件初始化完成... Starting id generator...");
-    id::init().await?;
-    log::info!("id generator 初始化完成... Starting database...");
-    database::init().await?;
-    log::info!("database 初始化完成... Starting app server...");
-    let state = AppState {};
+    let state = AppState::connect(config).await?;
+    state.install_global();
+    log::info!("Running startup tasks...");
+    let tasks = STARTUP_TASKS.lock().unwrap().clone();
+    for task in tasks {
+        task().await?;
+    }
+    log::info!("startup tasks 执行完成... Starting app server...");
     let server = server::Server::new(AppConfig::get().await.server());
     server.start(state, router).await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use deadpool_redis::{Config, Runtime};
+
+    #[tokio::test]
+    async fn test_extract_from_state() {
+        let redis = Config::from_url("redis://127.0.0.1:1/0")
+            .create_pool(Some(Runtime::Tokio1))
+            .unwrap();
+        let state = AppState::new(
+            Arc::new(AppConfig::default()),
+            DatabaseConnection::Disconnected,
+            redis,
+            IdGenerator::new(2).unwrap(),
+        );
+        let db = DatabaseConnection::from_ref(&state);
+        assert!(matches!(db, DatabaseConnection::Disconnected));
+        let config = Arc::<AppConfig>::from_ref(&state);
+        assert_eq!(config.app_name(), "app");
+        let first = IdGenerator::from_ref(&state).next_i64();
+        assert!(state.id_generator().next_i64() > first);
+    }
+}
@@ -3,8 +3,9 @@ use crate::enumeration::redis_keys::RedisKey;
 use crate::error::{ApiError, ApiResult};
 use crate::redis_utils;
 use crate::response::ApiResponse;
+use crate::telemetry;
 use crate::vo::system_vo::{AuthLoginRespVO, TenantRespVO};
-use sea_orm::sqlx::types::chrono::Local;
+use chrono::Local;
 use serde::Serialize;
 
 #[derive(Debug, Clone, Serialize, Default)]
@@ -25,8 +26,7 @@ pub async fn check_token(token: &str) -> ApiResult<AuthLoginRespVO> {
     let token_check_url = AppConfig::get().await.auth().token_check_url();
     let client = reqwest::Client::new();
 
-    let resp = client
-        .post(token_check_url)
+    let resp = telemetry::traced(client.post(token_check_url))
         .query(&[("token", token)])
         .send()
         .await
@@ -78,8 +78,7 @@ pub async fn check_tenant_id(tenant_id: &str) -> ApiResult<TenantRespVO> {
     let tenant_check_url = AppConfig::get().await.auth().tenant_check_url();
     let client = reqwest::Client::new();
 
-    let resp = client
-        .post(tenant_check_url)
+    let resp = telemetry::traced(client.post(tenant_check_url))
         .query(&[("tenantId", tenant_id)])
         .send()
         .await
@@ -118,3 +117,49 @@ pub async fn check_tenant_id(tenant_id: &str) -> ApiResult<TenantRespVO> {
 
     Ok(vo)
 }
+
+/// 按绑定域名解析租户编号，未配置解析地址或未匹配到租户时返回 None
+pub async fn get_tenant_id_by_website(website: &str) -> ApiResult<Option<String>> {
+    let tenant_website_url = AppConfig::get().await.auth().tenant_website_url();
+    if tenant_website_url.is_empty() {
+        return Ok(None);
+    }
+    let redis_key = RedisKey::TenantIdByWebsite.key(website);
+
+    // 1. Try to get from Redis
+    if let Some(tenant_id) = redis_utils::cache_get::<String>(&redis_key).await? {
+        return Ok(Some(tenant_id));
+    }
+
+    // 2. Call remote website url
+    let client = reqwest::Client::new();
+    let resp = telemetry::traced(client.get(tenant_website_url))
+        .query(&[("website", website)])
+        .send()
+        .await
+        .map_err(|e| ApiError::unauthenticated(format!("租户域名解析失败: {}", e)))?;
+
+    if !resp.status().is_success() {
+        return Err(ApiError::unauthenticated(format!(
+            "租户域名解析失败：status: {}",
+            resp.status()
+        )));
+    }
+
+    let api_response = resp
+        .json::<ApiResponse<Option<TenantRespVO>>>()
+        .await
+        .map_err(|e| ApiError::unauthenticated(format!("租户域名解析失败: {}", e)))?;
+
+    if !api_response.success {
+        return Err(ApiError::unauthenticated(api_response.message));
+    }
+
+    // 3. Cache the result, misses are not cached
+    let tenant_id = api_response.data.flatten().map(|vo| vo.id);
+    if let Some(tenant_id) = &tenant_id {
+        redis_utils::cache_set(&redis_key, tenant_id.clone()).await?;
+    }
+
+    Ok(tenant_id)
+}
@@ -0,0 +1,129 @@
+//! 运行时参数配置（`system_config`），可在线修改，无需重新部署
+//!
+//! 参数按租户缓存在 Redis 中；修改后通过 Redis 发布订阅通知所有实例，实例内可订阅变更事件
+
+use crate::configs::AppConfig;
+use crate::context::HttpRequestContext;
+use crate::enumeration::redis_keys::RedisKey;
+use crate::error::{ApiError, ApiResult};
+use crate::redis_utils;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+use std::sync::{LazyLock, OnceLock};
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio_stream::StreamExt;
+
+/// 参数变更通知的 Redis 频道
+const CONFIG_CHANGE_CHANNEL: &str = "system_config:change";
+/// 订阅断开后的重连间隔
+const RESUBSCRIBE_INTERVAL: Duration = Duration::from_secs(5);
+
+pub type ParamFuture = Pin<Box<dyn Future<Output = ApiResult<Option<String>>> + Send>>;
+
+/// 参数加载器，由持有参数配置表的服务在启动时注册，按当前租户查询参数值
+pub type ParamLoader = fn(String) -> ParamFuture;
+
+static PARAM_LOADER: OnceLock<ParamLoader> = OnceLock::new();
+
+static CONFIG_CHANGE_SENDER: LazyLock<broadcast::Sender<ConfigChangeEvent>> =
+    LazyLock::new(|| broadcast::channel(64).0);
+
+/// 参数变更事件
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigChangeEvent {
+    pub tenant_id: String,
+    pub key: String,
+}
+
+/// 缓存的参数值，参数不存在时也缓存，避免反复查库
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedParam {
+    value: Option<String>,
+}
+
+pub fn register_param_loader(loader: ParamLoader) {
+    let _ = PARAM_LOADER.set(loader);
+}
+
+fn param_key(tenant_id: &str, key: &str) -> String {
+    RedisKey::ConfigParam.key(format!("{tenant_id}:{key}"))
+}
+
+/// 获取当前租户的参数值，参数不存在时返回 `None`
+pub async fn get_param(key: &str) -> ApiResult<Option<String>> {
+    let tenant_id = HttpRequestContext::get_tenant_id().await;
+    let cache_key = param_key(tenant_id.as_deref().unwrap_or("all"), key);
+    if let Some(cached) = redis_utils::cache_get_json::<CachedParam>(&cache_key).await? {
+        return Ok(cached.value);
+    }
+    let loader = PARAM_LOADER
+        .get()
+        .ok_or_else(|| ApiError::biz("参数配置尚未加载"))?;
+    let value = loader(key.to_string()).await?;
+    let expire_seconds = AppConfig::get().await.redis().expire_seconds();
+    redis_utils::cache_set_json_ex(
+        &cache_key,
+        &CachedParam {
+            value: value.clone(),
+        },
+        expire_seconds,
+    )
+    .await?;
+    Ok(value)
+}
+
+/// 获取参数值并解析为指定类型，参数不存在或无法解析时使用默认值
+pub async fn get_param_or<T: std::str::FromStr>(key: &str, default: T) -> ApiResult<T> {
+    Ok(get_param(key)
+        .await?
+        .and_then(|x| x.trim().parse().ok())
+        .unwrap_or(default))
+}
+
+/// 参数修改或删除后调用：清除缓存并通知所有实例
+pub async fn notify_change(tenant_id: &str, key: &str) -> ApiResult<()> {
+    redis_utils::cache_del(&param_key(tenant_id, key)).await?;
+    let event = ConfigChangeEvent {
+        tenant_id: tenant_id.to_string(),
+        key: key.to_string(),
+    };
+    redis_utils::publish(CONFIG_CHANGE_CHANNEL, &serde_json::to_string(&event)?).await
+}
+
+/// 订阅本实例收到的参数变更事件，包括其它实例发出的变更
+pub fn subscribe_changes() -> broadcast::Receiver<ConfigChangeEvent> {
+    CONFIG_CHANGE_SENDER.subscribe()
+}
+
+/// 启动参数变更监听，将 Redis 频道中的变更转发给实例内的订阅者，断开后自动重连
+pub fn start_change_listener() {
+    tokio::spawn(async {
+        loop {
+            match redis_utils::subscribe(CONFIG_CHANGE_CHANNEL).await {
+                Ok(mut pubsub) => {
+                    let mut messages = pubsub.on_message();
+                    while let Some(message) = messages.next().await {
+                        match message
+                            .get_payload::<String>()
+                            .map_err(anyhow::Error::from)
+                            .and_then(|x| Ok(serde_json::from_str::<ConfigChangeEvent>(&x)?))
+                        {
+                            Ok(event) => {
+                                tracing::info!("参数配置({}:{})已变更", event.tenant_id, event.key);
+                                let _ = CONFIG_CHANGE_SENDER.send(event);
+                            }
+                            Err(e) => tracing::warn!("无法解析参数变更通知: {e}"),
+                        }
+                    }
+                    tracing::warn!("参数变更订阅已断开，{RESUBSCRIBE_INTERVAL:?}后重连");
+                }
+                Err(e) => {
+                    tracing::error!("订阅参数变更失败，{RESUBSCRIBE_INTERVAL:?}后重试: {e}")
+                }
+            }
+            tokio::time::sleep(RESUBSCRIBE_INTERVAL).await;
+        }
+    });
+}
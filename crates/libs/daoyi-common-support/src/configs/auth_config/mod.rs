@@ -21,6 +21,20 @@ pub struct AuthConfig {
     token_check_url: Option<String>,
     #[merge(strategy = merge::option::overwrite_none)]
     tenant_check_url: Option<String>,
+    #[merge(strategy = merge::option::overwrite_none)]
+    tenant_website_url: Option<String>,
+    #[merge(strategy = merge::option::overwrite_none)]
+    tenant_resolve_order: Option<Vec<TenantResolver>>,
+}
+
+/// 租户解析方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TenantResolver {
+    /// 从租户请求头（`header_key_tenant`）解析
+    Header,
+    /// 从 X-Forwarded-Host / Host 请求头按绑定域名解析
+    Host,
 }
 impl AuthConfig {
     pub fn header_key_token(&self) -> &str {
@@ -34,11 +48,11 @@ impl AuthConfig {
 
     pub fn is_ignored_auth(&self, url: &str) -> bool {
         self.ignored_urls.is_some()
-            && path_any_matches(&self.ignored_urls.as_deref().unwrap(), url).unwrap_or(false)
+            && path_any_matches(self.ignored_urls.as_deref().unwrap(), url).unwrap_or(false)
     }
     pub fn is_ignored_tenant(&self, url: &str) -> bool {
         self.tenant_ignored_urls.is_some()
-            && path_any_matches(&self.tenant_ignored_urls.as_deref().unwrap(), url).unwrap_or(false)
+            && path_any_matches(self.tenant_ignored_urls.as_deref().unwrap(), url).unwrap_or(false)
     }
     pub fn token_expiration(&self) -> Duration {
         if let Some(token_expiration) = &self.token_expiration {
@@ -53,6 +67,15 @@ impl AuthConfig {
     pub fn tenant_check_url(&self) -> &str {
         self.tenant_check_url.as_deref().unwrap_or("")
     }
+    pub fn tenant_website_url(&self) -> &str {
+        self.tenant_website_url.as_deref().unwrap_or("")
+    }
+    /// 租户解析顺序，依次尝试，取第一个解析成功的结果，默认先请求头后域名
+    pub fn tenant_resolve_order(&self) -> &[TenantResolver] {
+        self.tenant_resolve_order
+            .as_deref()
+            .unwrap_or(&[TenantResolver::Header, TenantResolver::Host])
+    }
 }
 
 fn path_matches(pattern: &str, target: &str) -> ApiResult<bool> {
@@ -1,4 +1,5 @@
 use merge::Merge;
+use sea_orm::DbBackend;
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize, Default, Merge)]
@@ -17,6 +18,12 @@ pub struct DatabaseConfig {
     database: Option<String>,
     #[merge(strategy = merge::option::overwrite_none)]
     schema: Option<String>,
+    /// SQLite 没有 schema，按这里列出的 schema 名附加同名数据库文件
+    #[merge(strategy = merge::option::overwrite_none)]
+    attach_schemas: Option<Vec<String>>,
+    /// 服务启动时是否自动执行数据库迁移
+    #[merge(strategy = merge::option::overwrite_none)]
+    auto_migrate: Option<bool>,
 }
 
 impl DatabaseConfig {
@@ -27,7 +34,10 @@ impl DatabaseConfig {
         self.host.as_deref().unwrap_or("127.0.0.1")
     }
     pub fn port(&self) -> u16 {
-        self.port.unwrap_or(5432)
+        self.port.unwrap_or(match self.driver() {
+            "mysql" => 3306,
+            _ => 5432,
+        })
     }
     pub fn user(&self) -> &str {
         self.user.as_deref().unwrap_or("postgres")
@@ -41,4 +51,21 @@ impl DatabaseConfig {
     pub fn schema(&self) -> &str {
         self.schema.as_deref().unwrap_or("public")
     }
+    pub fn attach_schemas(&self) -> Vec<String> {
+        self.attach_schemas
+            .clone()
+            .unwrap_or_else(|| vec![String::from("system"), String::from("demo")])
+    }
+    pub fn auto_migrate(&self) -> bool {
+        self.auto_migrate.unwrap_or(false)
+    }
+    /// 按驱动名识别数据库类型
+    pub fn backend(&self) -> anyhow::Result<DbBackend> {
+        match self.driver() {
+            "postgres" | "postgresql" => Ok(DbBackend::Postgres),
+            "mysql" => Ok(DbBackend::MySql),
+            "sqlite" => Ok(DbBackend::Sqlite),
+            driver => Err(anyhow::anyhow!("Unsupported database driver: {driver}")),
+        }
+    }
 }
@@ -1,5 +1,7 @@
+use bytesize::ByteSize;
 use merge::Merge;
 use serde::Deserialize;
+use std::str::FromStr;
 
 #[derive(Debug, Deserialize, Default, Merge)]
 pub struct LogConfig {
@@ -11,6 +13,35 @@ pub struct LogConfig {
     filename: Option<String>,
     #[merge(strategy = merge::option::overwrite_none)]
     rolling: Option<String>,
+    #[merge(strategy = merge::option::overwrite_none)]
+    max_file_size: Option<String>,
+    #[merge(strategy = merge::option::overwrite_none)]
+    max_files: Option<usize>,
+    #[merge(strategy = merge::option::overwrite_none)]
+    max_total_size: Option<String>,
+    #[merge(strategy = merge::option::overwrite_none)]
+    console_level: Option<String>,
+    #[merge(strategy = merge::option::overwrite_none)]
+    console_format: Option<LogFormat>,
+    #[merge(strategy = merge::option::overwrite_none)]
+    file_level: Option<String>,
+    #[merge(strategy = merge::option::overwrite_none)]
+    file_format: Option<LogFormat>,
+    #[merge(strategy = merge::option::overwrite_none)]
+    otlp_endpoint: Option<String>,
+    #[merge(strategy = merge::option::overwrite_none)]
+    otlp_sample_ratio: Option<f64>,
+}
+
+/// 日志输出格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogFormat {
+    /// 便于阅读的文本
+    #[default]
+    Text,
+    /// 每行一个 JSON 对象，便于日志平台采集
+    Json,
 }
 
 impl LogConfig {
@@ -33,4 +64,53 @@ impl LogConfig {
     pub fn rolling(&self) -> &str {
         self.rolling.as_deref().unwrap_or("daily")
     }
+
+    /// 单个日志文件的大小上限，超过后在同一周期内切分新文件，未配置时只按时间切分
+    pub fn max_file_size(&self) -> Option<u64> {
+        parse_size(self.max_file_size.as_deref())
+    }
+
+    /// 保留的日志文件数量上限，超过时删除最旧的文件，未配置时不限制
+    pub fn max_files(&self) -> Option<usize> {
+        self.max_files.filter(|n| *n > 0)
+    }
+
+    /// 日志文件的总大小上限，超过时删除最旧的文件，未配置时不限制
+    pub fn max_total_size(&self) -> Option<u64> {
+        parse_size(self.max_total_size.as_deref())
+    }
+
+    /// 控制台的日志级别，未配置时使用 `level`
+    pub fn console_level(&self) -> &str {
+        self.console_level.as_deref().unwrap_or(self.level())
+    }
+
+    pub fn console_format(&self) -> LogFormat {
+        self.console_format.unwrap_or_default()
+    }
+
+    /// 日志文件的日志级别，未配置时使用 `level`
+    pub fn file_level(&self) -> &str {
+        self.file_level.as_deref().unwrap_or(self.level())
+    }
+
+    pub fn file_format(&self) -> LogFormat {
+        self.file_format.unwrap_or_default()
+    }
+
+    /// OTLP 链路导出地址（HTTP/protobuf），如 `http://127.0.0.1:4318/v1/traces`，未配置时不导出
+    pub fn otlp_endpoint(&self) -> Option<&str> {
+        self.otlp_endpoint.as_deref()
+    }
+
+    /// 链路采样比例，取值 0~1，默认全部采样；上游已决定采样时沿用上游的结果
+    pub fn otlp_sample_ratio(&self) -> f64 {
+        self.otlp_sample_ratio.unwrap_or(1.0).clamp(0.0, 1.0)
+    }
+}
+
+fn parse_size(size: Option<&str>) -> Option<u64> {
+    size.and_then(|size| ByteSize::from_str(size).ok())
+        .map(|size| size.as_u64())
+        .filter(|size| *size > 0)
 }
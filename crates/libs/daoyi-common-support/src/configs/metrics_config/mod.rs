@@ -0,0 +1,22 @@
+use merge::Merge;
+use serde::Deserialize;
+
+/// 监控指标配置
+#[derive(Debug, Deserialize, Default, Merge)]
+pub struct MetricsConfig {
+    #[merge(strategy = merge::option::overwrite_none)]
+    enabled: Option<bool>,
+    #[merge(strategy = merge::option::overwrite_none)]
+    port: Option<u16>,
+}
+
+impl MetricsConfig {
+    /// 是否采集指标并提供 `/metrics` 接口
+    pub fn enabled(&self) -> bool {
+        self.enabled.unwrap_or(true)
+    }
+    /// 单独监听的管理端口，未配置时 `/metrics` 与业务接口共用服务端口
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+}
@@ -1,32 +1,38 @@
 mod auth_config;
 pub mod database_config;
 pub mod log_config;
+pub mod metrics_config;
 pub mod nacos_config;
+pub mod openapi_config;
 pub mod redis_config;
 pub mod server_config;
 
 use crate::configs::nacos_config::NacosConfig;
 use crate::configs::redis_config::RedisConfig;
 use anyhow::{anyhow, Context};
-pub use auth_config::AuthConfig;
+pub use auth_config::{AuthConfig, TenantResolver};
 use config::{Config, FileFormat};
 pub use database_config::DatabaseConfig;
 pub use log_config::LogConfig;
+pub use metrics_config::MetricsConfig;
 use merge::Merge;
 use nacos_sdk::api::config::ConfigServiceBuilder;
+pub use openapi_config::OpenApiConfig;
 use serde::Deserialize;
 pub use server_config::ServerConfig;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
 use tokio::sync::OnceCell;
 
-static APP_CONFIG: OnceCell<AppConfig> = OnceCell::const_new();
-static DEFAULT_SERVER_CONFIG: LazyLock<ServerConfig> = LazyLock::new(|| ServerConfig::default());
-static DEFAULT_LOG_CONFIG: LazyLock<LogConfig> = LazyLock::new(|| LogConfig::default());
+static APP_CONFIG: OnceCell<Arc<AppConfig>> = OnceCell::const_new();
+static DEFAULT_SERVER_CONFIG: LazyLock<ServerConfig> = LazyLock::new(ServerConfig::default);
+static DEFAULT_LOG_CONFIG: LazyLock<LogConfig> = LazyLock::new(LogConfig::default);
 static DEFAULT_DATABASE_CONFIG: LazyLock<DatabaseConfig> =
-    LazyLock::new(|| DatabaseConfig::default());
-static DEFAULT_AUTH_CONFIG: LazyLock<AuthConfig> = LazyLock::new(|| AuthConfig::default());
-static DEFAULT_NACOS_CONFIG: LazyLock<NacosConfig> = LazyLock::new(|| NacosConfig::default());
-static DEFAULT_REDIS_CONFIG: LazyLock<RedisConfig> = LazyLock::new(|| RedisConfig::default());
+    LazyLock::new(DatabaseConfig::default);
+static DEFAULT_AUTH_CONFIG: LazyLock<AuthConfig> = LazyLock::new(AuthConfig::default);
+static DEFAULT_NACOS_CONFIG: LazyLock<NacosConfig> = LazyLock::new(NacosConfig::default);
+static DEFAULT_REDIS_CONFIG: LazyLock<RedisConfig> = LazyLock::new(RedisConfig::default);
+static DEFAULT_OPENAPI_CONFIG: LazyLock<OpenApiConfig> = LazyLock::new(OpenApiConfig::default);
+static DEFAULT_METRICS_CONFIG: LazyLock<MetricsConfig> = LazyLock::new(MetricsConfig::default);
 
 #[derive(Debug, Deserialize, Merge, Default)]
 pub struct AppConfig {
@@ -46,6 +52,10 @@ pub struct AppConfig {
     nacos: Option<NacosConfig>,
     #[merge(strategy = merge::option::recurse)]
     redis: Option<RedisConfig>,
+    #[merge(strategy = merge::option::recurse)]
+    openapi: Option<OpenApiConfig>,
+    #[merge(strategy = merge::option::recurse)]
+    metrics: Option<MetricsConfig>,
 }
 
 impl AppConfig {
@@ -73,11 +83,23 @@ impl AppConfig {
     pub fn redis(&self) -> &RedisConfig {
         self.redis.as_ref().unwrap_or(&DEFAULT_REDIS_CONFIG)
     }
+    pub fn openapi(&self) -> &OpenApiConfig {
+        self.openapi.as_ref().unwrap_or(&DEFAULT_OPENAPI_CONFIG)
+    }
+    pub fn metrics(&self) -> &MetricsConfig {
+        self.metrics.as_ref().unwrap_or(&DEFAULT_METRICS_CONFIG)
+    }
+    /// 加载配置并安装为全局默认配置，已安装时不再加载
     pub async fn load(app_name: &str) -> anyhow::Result<()> {
-        let app_config = APP_CONFIG.get();
-        if app_config.is_some() {
+        if APP_CONFIG.get().is_some() {
             return Ok(());
         }
+        Self::install(Arc::new(Self::read(app_name).await?));
+        Ok(())
+    }
+
+    /// 按配置文件、环境变量与 nacos 的顺序读取配置，不影响全局默认配置
+    pub async fn read(app_name: &str) -> anyhow::Result<Self> {
         // 从环境变量获取配置目录，默认为 resources
         let config_dir = std::env::var("CONFIG_DIR")
             .or_else(|_| std::env::var("DY_CONFIG_DIR"))
@@ -141,12 +163,15 @@ impl AppConfig {
                 load_nacos_config(data_id, &nacos_config).await?,
             )
         }
-        APP_CONFIG
-            .set(init_config)
-            .with_context(|| anyhow!("Failed to set application config"))?;
-        Ok(())
+        Ok(init_config)
+    }
+
+    /// 安装全局默认配置，供 [`AppConfig::get`] 使用，只有首次安装生效
+    pub fn install(config: Arc<AppConfig>) {
+        let _ = APP_CONFIG.set(config);
     }
 
+    /// 全局默认配置，新代码优先从 `AppState` 获取配置
     pub async fn get() -> &'static Self {
         APP_CONFIG
             .get()
@@ -168,7 +193,7 @@ async fn load_nacos_config(
         return Ok(Some(
             Config::builder()
                 .add_source(
-                    config::File::from_str(&config_resp.content(), FileFormat::Yaml)
+                    config::File::from_str(config_resp.content(), FileFormat::Yaml)
                         .required(false),
                 )
                 .build()?
@@ -25,32 +25,32 @@ impl NacosConfig {
         self.enable.unwrap_or(false)
     }
     pub fn server_addr(&self) -> &str {
-        &self.server_addr.as_deref().unwrap_or("127.0.0.1:8848")
+        self.server_addr.as_deref().unwrap_or("127.0.0.1:8848")
     }
     pub fn namespace(&self) -> &str {
-        &self.namespace.as_deref().unwrap_or("public")
+        self.namespace.as_deref().unwrap_or("public")
     }
     pub fn app_name(&self) -> &str {
-        &self.app_name.as_deref().unwrap_or("app")
+        self.app_name.as_deref().unwrap_or("app")
     }
     pub fn group(&self) -> &str {
-        &self.group.as_deref().unwrap_or("DEFAULT_GROUP")
+        self.group.as_deref().unwrap_or("DEFAULT_GROUP")
     }
     pub fn auth_username(&self) -> &str {
-        &self.auth_username.as_deref().unwrap_or("nacos")
+        self.auth_username.as_deref().unwrap_or("nacos")
     }
     pub fn auth_password(&self) -> &str {
-        &self.auth_password.as_deref().unwrap_or("nacos")
+        self.auth_password.as_deref().unwrap_or("nacos")
     }
 }
 
-impl Into<ClientProps> for &NacosConfig {
-    fn into(self) -> ClientProps {
+impl From<&NacosConfig> for ClientProps {
+    fn from(value: &NacosConfig) -> Self {
         ClientProps::new()
-            .server_addr(self.server_addr())
-            .namespace(self.namespace())
-            .app_name(self.app_name())
-            .auth_username(self.auth_username())
-            .auth_password(self.auth_password())
+            .server_addr(value.server_addr())
+            .namespace(value.namespace())
+            .app_name(value.app_name())
+            .auth_username(value.auth_username())
+            .auth_password(value.auth_password())
     }
 }
@@ -0,0 +1,27 @@
+use merge::Merge;
+use serde::Deserialize;
+
+/// 接口文档配置，各环境的配置文件中按需开启
+#[derive(Debug, Deserialize, Default, Merge)]
+pub struct OpenApiConfig {
+    #[merge(strategy = merge::option::overwrite_none)]
+    enabled: Option<bool>,
+    #[merge(strategy = merge::option::overwrite_none)]
+    swagger_ui: Option<bool>,
+    #[merge(strategy = merge::option::overwrite_none)]
+    title: Option<String>,
+}
+
+impl OpenApiConfig {
+    /// 是否提供 `/v3/api-docs` 接口文档
+    pub fn enabled(&self) -> bool {
+        self.enabled.unwrap_or(true)
+    }
+    /// 是否提供 `/swagger-ui` 页面，依赖接口文档开启
+    pub fn swagger_ui(&self) -> bool {
+        self.enabled() && self.swagger_ui.unwrap_or(false)
+    }
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+}
@@ -2,13 +2,15 @@
 //
 //! 上下文模块 - 用于在请求处理过程中传递 token 信息
 //!
-//! 注意：在实际应用中，建议通过框架的请求扩展（如 Axum 的 Extension）
-//! 来传递上下文，而不是使用 thread_local。这里提供的是一个简单的实现。
+//! 上下文保存在 `tokio::task_local!` 中，只在 [`HttpRequestContext::scope`] 包裹的异步任务内可见，
+//! 任务在不同工作线程之间切换时不会读到其它请求的上下文。
+//! `tokio::spawn` 出的新任务不会继承上下文，需要使用 [`HttpRequestContext::spawn`] 传递。
 
-use std::cell::RefCell;
+use std::future::Future;
+use tokio::task::JoinHandle;
 
-thread_local! {
-    static CONTEXT: RefCell<Option<HttpRequestContext >> = RefCell::new(None);
+tokio::task_local! {
+    static CONTEXT: HttpRequestContext;
 }
 
 /// http request 上下文 | http request Context
@@ -45,23 +47,54 @@ impl HttpRequestContext {
         }
     }
 
-    /// 设置当前上下文 | Set Current Context
+    /// 系统上下文：没有登录用户且忽略租户，用于后台任务跨租户处理数据
+    /// System context: no login user and tenant ignored
+    pub fn system() -> Self {
+        Self {
+            ignore_tenant: Some(true),
+            ..Self::new()
+        }
+    }
+
+    /// 指定租户的上下文：没有登录用户，用于后台任务处理单个租户的数据
+    /// Tenant context: no login user, scoped to the given tenant
+    pub fn tenant(tenant_id: impl Into<String>) -> Self {
+        Self {
+            tenant_id: Some(tenant_id.into()),
+            ..Self::new()
+        }
+    }
+
+    /// 在指定上下文中执行 | Run a future within this context
     ///
-    /// # 参数 | Parameters
-    /// - `ctx`: 要设置的上下文 | Context to set
-    pub fn set_current(ctx: HttpRequestContext) {
-        CONTEXT.with(|c| {
-            *c.borrow_mut() = Some(ctx);
-        });
+    /// 嵌套调用时，内层上下文在内层 future 执行期间覆盖外层上下文
+    /// The inner context shadows the outer one while the inner future runs
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        CONTEXT.scope(self, future).await
+    }
+
+    /// 启动新任务并沿用当前上下文 | Spawn a task that inherits the current context
+    ///
+    /// 当前没有上下文时，新任务同样没有上下文
+    /// If there is no current context, neither does the spawned task
+    pub fn spawn<F>(future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        match Self::get_current() {
+            Some(context) => tokio::spawn(CONTEXT.scope(context, future)),
+            None => tokio::spawn(future),
+        }
     }
 
     /// 获取当前上下文 | Get Current Context
     ///
     /// # 返回 | Returns
-    /// 当前线程的上下文，如果不存在则返回 None
-    /// Current thread's context, or None if not exists
+    /// 当前任务的上下文，如果不存在则返回 None
+    /// Current task's context, or None if not exists
     pub fn get_current() -> Option<HttpRequestContext> {
-        CONTEXT.with(|c| c.borrow().clone())
+        CONTEXT.try_with(|c| c.clone()).ok()
     }
     pub async fn get_login_id() -> Option<String> {
         if let Ok(login_id) = Self::get_login_id_as_string().await {
@@ -94,16 +127,6 @@ impl HttpRequestContext {
             .ok_or_else(|| anyhow::anyhow!("ignore_tenant is None"))
             .unwrap_or(false)
     }
-
-    /// 清除当前上下文 | Clear Current Context
-    ///
-    /// 清除当前线程的上下文信息
-    /// Clear current thread's context information
-    pub fn clear() {
-        CONTEXT.with(|c| {
-            *c.borrow_mut() = None;
-        });
-    }
 }
 
 impl Default for HttpRequestContext {
@@ -111,3 +134,34 @@ impl Default for HttpRequestContext {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_scope_and_spawn() {
+        assert!(HttpRequestContext::get_current().is_none());
+        let (tenant_id, inherited, detached) = HttpRequestContext::tenant("1")
+            .scope(async {
+                tokio::task::yield_now().await;
+                let inherited =
+                    HttpRequestContext::spawn(async { HttpRequestContext::get_tenant_id().await });
+                let detached = tokio::spawn(async { HttpRequestContext::get_current() });
+                (
+                    HttpRequestContext::get_tenant_id().await,
+                    inherited.await.unwrap(),
+                    detached.await.unwrap(),
+                )
+            })
+            .await;
+        assert_eq!(tenant_id.as_deref(), Some("1"));
+        assert_eq!(inherited.as_deref(), Some("1"));
+        assert!(detached.is_none());
+        assert!(HttpRequestContext::get_current().is_none());
+        let ignore_tenant = HttpRequestContext::system()
+            .scope(async { HttpRequestContext::get_ignore_tenant() })
+            .await;
+        assert!(ignore_tenant);
+    }
+}
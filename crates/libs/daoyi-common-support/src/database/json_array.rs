@@ -0,0 +1,166 @@
+//! 以 JSON 数组存储的字符串列表，替代只有 Postgres 支持的数组列，并按数据库类型生成数组查询条件
+//!
+//! Postgres 中列类型为 `jsonb`，MySQL 中为 `json`，SQLite 中为 `text`
+
+use sea_orm::sea_query::{Expr, IntoColumnRef, SimpleExpr};
+use sea_orm::{DbBackend, FromJsonQueryResult};
+use serde::{Deserialize, Serialize};
+use std::ops::{Deref, DerefMut};
+
+/// 以 JSON 数组存储的字符串列表
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, FromJsonQueryResult)]
+#[serde(transparent)]
+pub struct StringArray(pub Vec<String>);
+
+impl Deref for StringArray {
+    type Target = Vec<String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for StringArray {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Vec<String>> for StringArray {
+    fn from(value: Vec<String>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<StringArray> for Vec<String> {
+    fn from(value: StringArray) -> Self {
+        value.0
+    }
+}
+
+impl FromIterator<String> for StringArray {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for StringArray {
+    type Item = String;
+    type IntoIter = std::vec::IntoIter<String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a StringArray {
+    type Item = &'a String;
+    type IntoIter = std::slice::Iter<'a, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// 数组列包含任一给定值，给定值为空时条件不成立
+pub fn contains_any(
+    backend: DbBackend,
+    column: impl IntoColumnRef,
+    values: &[String],
+) -> SimpleExpr {
+    if values.is_empty() {
+        return Expr::cust("1 = 0");
+    }
+    let column_sql = placeholder(backend, 1);
+    let values_sql = (2..=values.len() + 1)
+        .map(|index| placeholder(backend, index))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let sql = match backend {
+        // `?|` 中的问号会被当作占位符，改用展开数组的写法
+        DbBackend::Postgres => format!(
+            "EXISTS (SELECT 1 FROM jsonb_array_elements_text({column_sql}) AS e(value) WHERE e.value IN ({values_sql}))"
+        ),
+        DbBackend::MySql => format!("JSON_OVERLAPS({column_sql}, JSON_ARRAY({values_sql}))"),
+        DbBackend::Sqlite => format!(
+            "EXISTS (SELECT 1 FROM json_each({column_sql}) WHERE json_each.value IN ({values_sql}))"
+        ),
+    };
+    let mut exprs = vec![Expr::col(column).into()];
+    exprs.extend(values.iter().map(|value| Expr::val(value.as_str()).into()));
+    Expr::cust_with_exprs(sql, exprs)
+}
+
+/// 数组列为空数组
+pub fn is_empty(backend: DbBackend, column: impl IntoColumnRef) -> SimpleExpr {
+    let function = match backend {
+        DbBackend::Postgres => "jsonb_array_length",
+        DbBackend::MySql => "JSON_LENGTH",
+        DbBackend::Sqlite => "json_array_length",
+    };
+    Expr::cust_with_expr(
+        format!("{function}({}) = 0", placeholder(backend, 1)),
+        Expr::col(column),
+    )
+}
+
+/// 自定义表达式的占位符，Postgres 按序号引用，其它数据库按出现顺序引用
+fn placeholder(backend: DbBackend, index: usize) -> String {
+    match backend {
+        DbBackend::Postgres => format!("${index}"),
+        _ => String::from("?"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::sea_query::{Alias, Query};
+
+    fn build(backend: DbBackend, expr: SimpleExpr) -> String {
+        let query = Query::select()
+            .column(Alias::new("id"))
+            .from(Alias::new("t"))
+            .and_where(expr)
+            .to_owned();
+        backend.build(&query).to_string()
+    }
+
+    #[test]
+    fn test_contains_any() {
+        let values = [String::from("1"), String::from("2")];
+        let column = Alias::new("post_ids");
+        assert_eq!(
+            build(
+                DbBackend::Postgres,
+                contains_any(DbBackend::Postgres, column.clone(), &values)
+            ),
+            r#"SELECT "id" FROM "t" WHERE EXISTS (SELECT 1 FROM jsonb_array_elements_text("post_ids") AS e(value) WHERE e.value IN ('1', '2'))"#
+        );
+        assert_eq!(
+            build(
+                DbBackend::MySql,
+                contains_any(DbBackend::MySql, column.clone(), &values)
+            ),
+            "SELECT `id` FROM `t` WHERE JSON_OVERLAPS(`post_ids`, JSON_ARRAY('1', '2'))"
+        );
+        assert_eq!(
+            build(
+                DbBackend::Sqlite,
+                contains_any(DbBackend::Sqlite, column.clone(), &values)
+            ),
+            r#"SELECT "id" FROM "t" WHERE EXISTS (SELECT 1 FROM json_each("post_ids") WHERE json_each.value IN ('1', '2'))"#
+        );
+        assert_eq!(
+            build(DbBackend::Sqlite, is_empty(DbBackend::Sqlite, column)),
+            r#"SELECT "id" FROM "t" WHERE json_array_length("post_ids") = 0"#
+        );
+    }
+
+    #[test]
+    fn test_string_array_json() {
+        let array = StringArray::from(vec![String::from("a"), String::from("b")]);
+        assert_eq!(serde_json::to_string(&array).unwrap(), r#"["a","b"]"#);
+        assert!(array.contains(&String::from("a")));
+    }
+}
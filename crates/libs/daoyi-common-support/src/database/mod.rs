@@ -1,5 +1,5 @@
-use crate::configs::AppConfig;
-use anyhow::{Context, anyhow};
+use crate::configs::{AppConfig, DatabaseConfig};
+use crate::{metrics, telemetry};
 use sea_orm::{
     ConnectOptions, ConnectionTrait, Database, DatabaseConnection, DbBackend, Statement,
 };
@@ -7,23 +7,25 @@ use std::cmp::max;
 use std::time::Duration;
 use tokio::sync::OnceCell;
 
+pub mod json_array;
+
 static DB_CONN: OnceCell<DatabaseConnection> = OnceCell::const_new();
 
+/// 按全局默认配置连接数据库，并安装为全局默认连接
 pub async fn init() -> anyhow::Result<()> {
     if DB_CONN.get().is_some() {
         return Ok(());
     }
+    install(connect(AppConfig::get().await.database()).await?);
+    Ok(())
+}
+
+/// 按配置创建数据库连接池，数据库类型由 `driver` 决定，对应的 cargo feature 需要启用
+pub async fn connect(database_config: &DatabaseConfig) -> anyhow::Result<DatabaseConnection> {
+    let backend = database_config.backend()?;
+    ensure_backend_enabled(backend)?;
     let cpus = num_cpus::get() as u32;
-    let database_config = AppConfig::get().await.database();
-    let mut options = ConnectOptions::new(format!(
-        "{}://{}:{}@{}:{}/{}",
-        database_config.driver(),
-        database_config.user(),
-        database_config.password(),
-        database_config.host(),
-        database_config.port(),
-        database_config.database()
-    ));
+    let mut options = ConnectOptions::new(connection_url(database_config, backend));
     options
         .min_connections(max(cpus * 4, 10))
         .max_connections(max(cpus * 8, 20))
@@ -31,17 +33,110 @@ pub async fn init() -> anyhow::Result<()> {
         .acquire_timeout(Duration::from_secs(30))
         .idle_timeout(Duration::from_secs(300))
         .max_lifetime(Duration::from_secs(300))
-        .sqlx_logging(false)
-        .set_schema_search_path(database_config.schema());
-    let db = Database::connect(options).await?;
+        .sqlx_logging(false);
+    match backend {
+        DbBackend::Postgres => {
+            options.set_schema_search_path(database_config.schema());
+        }
+        DbBackend::MySql => {}
+        DbBackend::Sqlite => {
+            // ATTACH 只对执行它的连接生效，SQLite 只保留一个长期存活的连接
+            let forever = Duration::from_secs(10 * 365 * 24 * 3600);
+            options
+                .min_connections(1)
+                .max_connections(1)
+                .idle_timeout(forever)
+                .max_lifetime(forever);
+        }
+    }
+    let mut db = Database::connect(options).await?;
+    db.set_metric_callback(|info| {
+        metrics::observe_db_query(info);
+        telemetry::record_db_query(info);
+    });
     db.ping().await?;
+    if backend == DbBackend::Sqlite {
+        attach_sqlite_schemas(&db, database_config).await?;
+    }
     tracing::info!("Database connection successful");
     log_database_version(&db).await?;
-    DB_CONN
-        .set(db)
-        .with_context(|| anyhow!("Failed to set database config"))?;
+    Ok(db)
+}
+
+fn connection_url(database_config: &DatabaseConfig, backend: DbBackend) -> String {
+    match backend {
+        DbBackend::Sqlite if database_config.database() == SQLITE_MEMORY => {
+            String::from("sqlite::memory:")
+        }
+        DbBackend::Sqlite => format!("sqlite://{}?mode=rwc", database_config.database()),
+        _ => format!(
+            "{}://{}:{}@{}:{}/{}",
+            database_config.driver(),
+            database_config.user(),
+            database_config.password(),
+            database_config.host(),
+            database_config.port(),
+            database_config.database()
+        ),
+    }
+}
+
+fn ensure_backend_enabled(backend: DbBackend) -> anyhow::Result<()> {
+    let enabled = match backend {
+        DbBackend::Postgres => cfg!(feature = "postgres"),
+        DbBackend::MySql => cfg!(feature = "mysql"),
+        DbBackend::Sqlite => cfg!(feature = "sqlite"),
+    };
+    if enabled {
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!(
+            "Database backend {backend:?} is not enabled, rebuild with the matching cargo feature"
+        ))
+    }
+}
+
+const SQLITE_MEMORY: &str = ":memory:";
+
+/// SQLite 没有 schema，把每个 schema 附加为一个独立的数据库文件，
+/// 使带 `schema_name` 的实体生成的 `"system"."system_users"` 可以直接使用
+async fn attach_sqlite_schemas(
+    db: &DatabaseConnection,
+    database_config: &DatabaseConfig,
+) -> anyhow::Result<()> {
+    for schema in database_config.attach_schemas() {
+        let file = sqlite_schema_file(database_config.database(), &schema);
+        db.execute_unprepared(&format!(
+            "ATTACH DATABASE '{}' AS \"{}\"",
+            file.replace('\'', "''"),
+            schema.replace('"', "\"\"")
+        ))
+        .await?;
+    }
     Ok(())
 }
+
+/// 附加数据库文件与主库同目录，命名为 `<主库文件名>_<schema>.db`
+fn sqlite_schema_file(database: &str, schema: &str) -> String {
+    if database == SQLITE_MEMORY {
+        return String::from(SQLITE_MEMORY);
+    }
+    let path = std::path::Path::new(database);
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| String::from("daoyi"));
+    path.with_file_name(format!("{stem}_{schema}.db"))
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// 安装全局默认的数据库连接，供 [`get`] 使用，只有首次安装生效
+pub fn install(db: DatabaseConnection) {
+    let _ = DB_CONN.set(db);
+}
+
+/// 全局默认的数据库连接，兼容尚未改为接收连接参数的服务，新代码从 `AppState` 获取连接
 pub async fn get() -> &'static DatabaseConnection {
     DB_CONN
         .get()
@@ -58,12 +153,15 @@ pub async fn shutdown() -> anyhow::Result<()> {
     // 这里我们只记录日志，不需要手动关闭
     Ok(())
 }
+
 async fn log_database_version(db: &DatabaseConnection) -> anyhow::Result<()> {
+    let backend = db.get_database_backend();
+    let sql = match backend {
+        DbBackend::Sqlite => "SELECT sqlite_version()",
+        _ => "SELECT VERSION()",
+    };
     let version_result = db
-        .query_one(Statement::from_string(
-            DbBackend::Postgres,
-            "SELECT VERSION()",
-        ))
+        .query_one(Statement::from_string(backend, sql))
         .await?
         .ok_or_else(|| anyhow::anyhow!("Database version unknown"))?;
     tracing::info!(
@@ -72,3 +170,43 @@ async fn log_database_version(db: &DatabaseConnection) -> anyhow::Result<()> {
     );
     Ok(())
 }
+
+#[cfg(all(test, feature = "sqlite"))]
+mod tests {
+    use super::*;
+    use sea_orm::Condition;
+    use sea_orm::sea_query::{Alias, Query};
+
+    #[tokio::test]
+    async fn test_sqlite_attach_and_json_array() -> anyhow::Result<()> {
+        let database_config: DatabaseConfig = serde_json::from_value(serde_json::json!({
+            "driver": "sqlite",
+            "database": ":memory:",
+        }))?;
+        let db = connect(&database_config).await?;
+        db.execute_unprepared(
+            "CREATE TABLE \"system\".\"t\" (id text PRIMARY KEY, post_ids text NOT NULL DEFAULT '[]');
+             INSERT INTO \"system\".\"t\" VALUES ('1', '[\"a\",\"b\"]'), ('2', '[]');",
+        )
+        .await?;
+        let query = Query::select()
+            .column(Alias::new("id"))
+            .from((Alias::new("system"), Alias::new("t")))
+            .cond_where(
+                Condition::any()
+                    .add(json_array::contains_any(
+                        DbBackend::Sqlite,
+                        Alias::new("post_ids"),
+                        &[String::from("b"), String::from("c")],
+                    ))
+                    .add(json_array::is_empty(
+                        DbBackend::Sqlite,
+                        Alias::new("post_ids"),
+                    )),
+            )
+            .to_owned();
+        let rows = db.query_all(DbBackend::Sqlite.build(&query)).await?;
+        assert_eq!(rows.len(), 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use crate::context::HttpRequestContext;
+use crate::enumeration::redis_keys::RedisKey;
+use crate::error::{ApiError, ApiResult};
+use crate::redis_utils;
+use crate::vo::system_vo::DictDataSnapshotVO;
+pub use daoyi_macros::DaoyiDict;
+use std::collections::HashSet;
+use std::pin::Pin;
+use std::sync::OnceLock;
+
+/// 由 `#[derive(DaoyiDict)]` 生成的字典类型定义，作为枚举与字典表之间的唯一来源
+#[derive(Debug)]
+pub struct DictTypeDefinition {
+    pub dict_type: &'static str,
+    pub name: &'static str,
+    pub data: &'static [DictDataDefinition],
+}
+
+#[derive(Debug)]
+pub struct DictDataDefinition {
+    pub value: &'static str,
+    pub label: &'static str,
+    pub sort: i32,
+    pub color_type: Option<&'static str>,
+    pub css_class: Option<&'static str>,
+}
+
+/// 可同步为字典数据的枚举，参见 `daoyi_macros::DaoyiDict`
+pub trait DaoyiDict {
+    const DICT: DictTypeDefinition;
+
+    /// 枚举值对应的字典键值
+    fn dict_value(&self) -> &'static str;
+}
+
+pub type DictSnapshotFuture = Pin<Box<dyn Future<Output = ApiResult<DictDataSnapshotVO>> + Send>>;
+
+/// 字典数据快照加载器，由持有字典表的服务在启动时注册
+pub type DictSnapshotLoader = fn() -> DictSnapshotFuture;
+
+static DICT_SNAPSHOT_LOADER: OnceLock<DictSnapshotLoader> = OnceLock::new();
+
+pub fn register_snapshot_loader(loader: DictSnapshotLoader) {
+    let _ = DICT_SNAPSHOT_LOADER.set(loader);
+}
+
+/// 当前租户字典数据快照的缓存 key
+pub async fn snapshot_key() -> String {
+    let tenant_id = HttpRequestContext::get_tenant_id().await;
+    tenant_snapshot_key(tenant_id.as_deref())
+}
+
+/// 指定租户字典数据快照的缓存 key，未指定租户时对应忽略租户的全量快照
+pub fn tenant_snapshot_key(tenant_id: Option<&str>) -> String {
+    RedisKey::DictDataSnapshot.key(tenant_id.unwrap_or("all"))
+}
+
+/// 获取当前租户的字典数据快照：优先使用注册的加载器，否则直接读取共享的 Redis 缓存
+pub async fn get_snapshot() -> ApiResult<DictDataSnapshotVO> {
+    if let Some(loader) = DICT_SNAPSHOT_LOADER.get() {
+        return loader().await;
+    }
+    redis_utils::cache_get_json::<DictDataSnapshotVO>(&snapshot_key().await)
+        .await?
+        .ok_or_else(|| ApiError::biz("字典数据尚未加载"))
+}
+
+/// 获取指定字典类型下所有开启的字典键值
+pub async fn get_dict_values(dict_type: &str) -> ApiResult<HashSet<String>> {
+    Ok(get_snapshot()
+        .await?
+        .list
+        .into_iter()
+        .filter(|x| x.dict_type == dict_type)
+        .map(|x| x.value)
+        .collect())
+}
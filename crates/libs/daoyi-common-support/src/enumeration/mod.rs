@@ -1,6 +1,8 @@
 pub mod redis_keys;
 
+use crate::dict::{DaoyiDict, DictTypeDefinition};
 use daoyi_macros::DaoyiIntoActiveValue;
+use schemars::JsonSchema;
 use sea_orm::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -12,6 +14,7 @@ use serde::{Deserialize, Serialize};
     Eq,
     Serialize,
     Deserialize,
+    JsonSchema,
     EnumIter,
     DeriveActiveEnum,
     DaoyiIntoActiveValue,
@@ -39,16 +42,21 @@ pub enum Gender {
     Eq,
     Serialize,
     Deserialize,
+    JsonSchema,
     EnumIter,
     DeriveActiveEnum,
     DaoyiIntoActiveValue,
+    DaoyiDict,
 )]
 #[serde(rename_all = "snake_case")]
 #[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+#[dict(dict_type = "common_status", name = "系统状态")]
 pub enum CommonStatusEnum {
     #[sea_orm(string_value = "0")]
+    #[dict(label = "开启", color_type = "primary")]
     Enable,
     #[sea_orm(string_value = "1")]
+    #[dict(label = "关闭", color_type = "info")]
     Disable,
 }
 #[derive(
@@ -59,16 +67,21 @@ pub enum CommonStatusEnum {
     Eq,
     Serialize,
     Deserialize,
+    JsonSchema,
     EnumIter,
     DeriveActiveEnum,
     DaoyiIntoActiveValue,
+    DaoyiDict,
 )]
 #[serde(rename_all = "snake_case")]
 #[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+#[dict(dict_type = "system_role_type", name = "角色类型")]
 pub enum RoleTypeEnum {
     #[sea_orm(string_value = "1")]
+    #[dict(label = "内置", color_type = "danger")]
     SYSTEM,
     #[sea_orm(string_value = "2")]
+    #[dict(label = "自定义", color_type = "primary")]
     CUSTOM,
 }
 
@@ -80,23 +93,31 @@ pub enum RoleTypeEnum {
     Eq,
     Serialize,
     Deserialize,
+    JsonSchema,
     EnumIter,
     DeriveActiveEnum,
     DaoyiIntoActiveValue,
+    DaoyiDict,
 )]
 #[serde(rename_all = "snake_case")]
 #[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+#[dict(dict_type = "system_data_scope", name = "数据范围")]
 pub enum DataScopeEnum {
     #[sea_orm(string_value = "1")]
-    ALL, // 全部数据权限
+    #[dict(label = "全部数据权限")]
+    ALL,
     #[sea_orm(string_value = "2")]
-    DeptCustom, // 指定部门数据权限
+    #[dict(label = "指定部门数据权限")]
+    DeptCustom,
     #[sea_orm(string_value = "3")]
-    DeptOnly, // 部门数据权限
+    #[dict(label = "本部门数据权限")]
+    DeptOnly,
     #[sea_orm(string_value = "4")]
-    DeptAndChild, // 部门及以下数据权限
+    #[dict(label = "本部门及以下数据权限")]
+    DeptAndChild,
     #[sea_orm(string_value = "5")]
-    SELF, // 仅本人数据权限
+    #[dict(label = "仅本人数据权限")]
+    SELF,
 }
 
 #[derive(
@@ -107,6 +128,7 @@ pub enum DataScopeEnum {
     Eq,
     Serialize,
     Deserialize,
+    JsonSchema,
     EnumIter,
     DeriveActiveEnum,
     DaoyiIntoActiveValue,
@@ -126,6 +148,9 @@ impl RoleCodeEnum {
     pub fn is_super_admin(role_code: &str) -> bool {
         role_code == "super_admin"
     }
+    pub fn is_tenant_admin(role_code: &str) -> bool {
+        role_code == "tenant_admin"
+    }
 }
 
 #[derive(
@@ -136,17 +161,301 @@ impl RoleCodeEnum {
     Eq,
     Serialize,
     Deserialize,
+    JsonSchema,
     EnumIter,
     DeriveActiveEnum,
     DaoyiIntoActiveValue,
+    DaoyiDict,
 )]
 #[serde(rename_all = "snake_case")]
 #[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+#[dict(dict_type = "system_menu_type", name = "菜单类型")]
 pub enum MenuTypeEnum {
     #[sea_orm(string_value = "1")]
-    DIR, // 目录
+    #[dict(label = "目录")]
+    DIR,
     #[sea_orm(string_value = "2")]
-    MENU, // 菜单
+    #[dict(label = "菜单")]
+    MENU,
     #[sea_orm(string_value = "3")]
-    BUTTON, // 按钮
+    #[dict(label = "按钮")]
+    BUTTON,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    EnumIter,
+    DeriveActiveEnum,
+    DaoyiIntoActiveValue,
+    DaoyiDict,
+)]
+#[serde(rename_all = "snake_case")]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+#[dict(dict_type = "system_notice_type", name = "通知类型")]
+pub enum NoticeTypeEnum {
+    #[sea_orm(string_value = "1")]
+    #[dict(label = "通知", color_type = "success")]
+    Notice,
+    #[sea_orm(string_value = "2")]
+    #[dict(label = "公告", color_type = "info")]
+    Announcement,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    EnumIter,
+    DeriveActiveEnum,
+    DaoyiIntoActiveValue,
+    DaoyiDict,
+)]
+#[serde(rename_all = "snake_case")]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+#[dict(dict_type = "system_notify_template_type", name = "站内信模版的类型")]
+pub enum NotifyTemplateTypeEnum {
+    #[sea_orm(string_value = "1")]
+    #[dict(label = "通知公告", color_type = "primary")]
+    Notice,
+    #[sea_orm(string_value = "2")]
+    #[dict(label = "系统消息", color_type = "success")]
+    SystemMessage,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    EnumIter,
+    DeriveActiveEnum,
+    DaoyiIntoActiveValue,
+    DaoyiDict,
+)]
+#[serde(rename_all = "snake_case")]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+#[dict(dict_type = "system_mail_send_status", name = "邮件发送状态")]
+pub enum MailSendStatusEnum {
+    #[sea_orm(string_value = "0")]
+    #[dict(label = "初始化", color_type = "primary")]
+    Init,
+    #[sea_orm(string_value = "10")]
+    #[dict(label = "发送成功", color_type = "success")]
+    Success,
+    #[sea_orm(string_value = "20")]
+    #[dict(label = "发送失败", color_type = "danger")]
+    Failure,
+    #[sea_orm(string_value = "30")]
+    #[dict(label = "不发送", color_type = "info")]
+    Ignore,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    EnumIter,
+    DeriveActiveEnum,
+    DaoyiIntoActiveValue,
+    DaoyiDict,
+)]
+#[serde(rename_all = "snake_case")]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+#[dict(dict_type = "system_file_storage", name = "文件存储器")]
+pub enum FileStorageEnum {
+    #[sea_orm(string_value = "1")]
+    #[dict(label = "数据库")]
+    Db,
+    #[sea_orm(string_value = "10")]
+    #[dict(label = "本地磁盘")]
+    Local,
+    #[sea_orm(string_value = "20")]
+    #[dict(label = "S3 对象存储")]
+    S3,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    EnumIter,
+    DeriveActiveEnum,
+    DaoyiIntoActiveValue,
+    DaoyiDict,
+)]
+#[serde(rename_all = "snake_case")]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+#[dict(dict_type = "system_config_type", name = "参数类型")]
+pub enum ConfigTypeEnum {
+    #[sea_orm(string_value = "1")]
+    #[dict(label = "系统内置", color_type = "danger")]
+    System,
+    #[sea_orm(string_value = "2")]
+    #[dict(label = "自定义", color_type = "primary")]
+    Custom,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    EnumIter,
+    DeriveActiveEnum,
+    DaoyiIntoActiveValue,
+    DaoyiDict,
+)]
+#[serde(rename_all = "snake_case")]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+#[dict(dict_type = "system_job_status", name = "定时任务状态")]
+pub enum JobStatusEnum {
+    #[sea_orm(string_value = "1")]
+    #[dict(label = "开启", color_type = "success")]
+    Normal,
+    #[sea_orm(string_value = "2")]
+    #[dict(label = "暂停", color_type = "danger")]
+    Stop,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    EnumIter,
+    DeriveActiveEnum,
+    DaoyiIntoActiveValue,
+    DaoyiDict,
+)]
+#[serde(rename_all = "snake_case")]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+#[dict(dict_type = "system_job_log_status", name = "定时任务日志状态")]
+pub enum JobLogStatusEnum {
+    #[sea_orm(string_value = "0")]
+    #[dict(label = "运行中", color_type = "primary")]
+    Running,
+    #[sea_orm(string_value = "1")]
+    #[dict(label = "成功", color_type = "success")]
+    Success,
+    #[sea_orm(string_value = "2")]
+    #[dict(label = "失败", color_type = "danger")]
+    Failure,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    EnumIter,
+    DeriveActiveEnum,
+    DaoyiIntoActiveValue,
+    DaoyiDict,
+)]
+#[serde(rename_all = "snake_case")]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+#[dict(dict_type = "system_login_result", name = "登录结果")]
+pub enum LoginResultEnum {
+    #[sea_orm(string_value = "0")]
+    #[dict(label = "成功", color_type = "success")]
+    Success,
+    #[sea_orm(string_value = "10")]
+    #[dict(label = "账号或密码不正确", color_type = "danger")]
+    BadCredentials,
+    #[sea_orm(string_value = "20")]
+    #[dict(label = "用户被禁用", color_type = "danger")]
+    UserDisabled,
+    #[sea_orm(string_value = "30")]
+    #[dict(label = "租户不存在", color_type = "danger")]
+    TenantNotFound,
+    #[sea_orm(string_value = "31")]
+    #[dict(label = "租户被禁用", color_type = "danger")]
+    TenantDisabled,
+    #[sea_orm(string_value = "32")]
+    #[dict(label = "租户已过期", color_type = "danger")]
+    TenantExpired,
+}
+
+/// 由枚举派生的字典定义，启动时同步到字典表，新增 `DaoyiDict` 枚举后需在此登记
+pub const ENUM_DICTS: &[&DictTypeDefinition] = &[
+    &CommonStatusEnum::DICT,
+    &RoleTypeEnum::DICT,
+    &DataScopeEnum::DICT,
+    &MenuTypeEnum::DICT,
+    &NoticeTypeEnum::DICT,
+    &NotifyTemplateTypeEnum::DICT,
+    &MailSendStatusEnum::DICT,
+    &FileStorageEnum::DICT,
+    &ConfigTypeEnum::DICT,
+    &JobStatusEnum::DICT,
+    &JobLogStatusEnum::DICT,
+    &LoginResultEnum::DICT,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_dict_matches_db_value<E: ActiveEnum<Value = String> + DaoyiDict>() {
+        let values = E::iter().map(|x| x.to_value()).collect::<Vec<_>>();
+        let dict_values = E::DICT.data.iter().map(|x| x.value).collect::<Vec<_>>();
+        assert_eq!(values, dict_values, "{}", E::DICT.dict_type);
+        for variant in E::iter() {
+            assert_eq!(variant.dict_value(), variant.to_value());
+        }
+    }
+
+    #[test]
+    fn test_enum_dicts_match_db_values() {
+        assert_dict_matches_db_value::<CommonStatusEnum>();
+        assert_dict_matches_db_value::<RoleTypeEnum>();
+        assert_dict_matches_db_value::<DataScopeEnum>();
+        assert_dict_matches_db_value::<MenuTypeEnum>();
+        assert_dict_matches_db_value::<NoticeTypeEnum>();
+        assert_dict_matches_db_value::<NotifyTemplateTypeEnum>();
+        assert_dict_matches_db_value::<MailSendStatusEnum>();
+        assert_dict_matches_db_value::<FileStorageEnum>();
+        assert_dict_matches_db_value::<ConfigTypeEnum>();
+        assert_dict_matches_db_value::<JobStatusEnum>();
+        assert_dict_matches_db_value::<JobLogStatusEnum>();
+        assert_dict_matches_db_value::<LoginResultEnum>();
+    }
 }
@@ -3,7 +3,12 @@
 pub enum RedisKey {
     CheckToken,
     CheckTenantId,
-    RoleById
+    RoleById,
+    TenantIdByWebsite,
+    DictDataSnapshot,
+    FileTempDownload,
+    ConfigParam,
+    JobTriggerLock,
 }
 
 impl RedisKey {
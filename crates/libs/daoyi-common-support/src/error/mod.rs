@@ -41,20 +41,24 @@ pub enum ApiError {
 impl From<ValidRejection<ApiError>> for ApiError {
     fn from(value: ValidRejection<ApiError>) -> Self {
         match value {
-            ValidationRejection::Valid(errors) => {
-                let error_messages = format_validation_errors(&errors, None);
-                let combined_message = if error_messages.is_empty() {
-                    "参数验证失败".to_string()
-                } else {
-                    error_messages.join(" | ")
-                };
-                ApiError::Validation(combined_message)
-            }
+            ValidationRejection::Valid(errors) => errors.into(),
             ValidationRejection::Inner(errors) => errors,
         }
     }
 }
 
+impl From<validator::ValidationErrors> for ApiError {
+    fn from(errors: validator::ValidationErrors) -> Self {
+        let error_messages = format_validation_errors(&errors, None);
+        let combined_message = if error_messages.is_empty() {
+            "参数验证失败".to_string()
+        } else {
+            error_messages.join(" | ")
+        };
+        ApiError::Validation(combined_message)
+    }
+}
+
 fn format_validation_errors(
     errors: &validator::ValidationErrors,
     prefix: Option<&str>,
@@ -0,0 +1,94 @@
+use super::{FileStorage, StorageFuture};
+use crate::database;
+use anyhow::Context;
+use sea_orm::entity::prelude::*;
+use sea_orm::{QueryOrder, Set};
+
+/// 数据库存储，文件内容保存在 `system_file_content` 表中
+pub struct DbFileStorage {
+    config_id: String,
+}
+
+impl DbFileStorage {
+    pub fn new(config_id: &str) -> Self {
+        Self {
+            config_id: config_id.to_string(),
+        }
+    }
+}
+
+impl FileStorage for DbFileStorage {
+    fn upload<'a>(
+        &'a self,
+        path: &'a str,
+        content: &'a [u8],
+        _content_type: &'a str,
+    ) -> StorageFuture<'a, ()> {
+        Box::pin(async move {
+            let db = database::get().await;
+            let mut active_model = file_content::ActiveModel::new();
+            active_model.config_id = Set(self.config_id.clone());
+            active_model.path = Set(path.to_string());
+            active_model.content = Set(content.to_vec());
+            active_model.insert(db).await?;
+            Ok(())
+        })
+    }
+
+    fn download<'a>(&'a self, path: &'a str) -> StorageFuture<'a, Vec<u8>> {
+        Box::pin(async move {
+            let db = database::get().await;
+            // 按配置编号定位，临时下载时没有租户上下文
+            let model = file_content::Entity::find_ignore_tenant()
+                .filter(file_content::Column::ConfigId.eq(&self.config_id))
+                .filter(file_content::Column::Path.eq(path))
+                .order_by_desc(file_content::Column::Id)
+                .one(db)
+                .await?
+                .with_context(|| format!("文件({path})不存在"))?;
+            Ok(model.content)
+        })
+    }
+
+    fn delete<'a>(&'a self, path: &'a str) -> StorageFuture<'a, ()> {
+        Box::pin(async move {
+            let db = database::get().await;
+            file_content::Entity::delete_many()
+                .filter(file_content::Column::ConfigId.eq(&self.config_id))
+                .filter(file_content::Column::Path.eq(path))
+                .exec(db)
+                .await?;
+            Ok(())
+        })
+    }
+}
+
+mod file_content {
+    use daoyi_macros::{DaoyiActiveModelBehavior, daoyi_model};
+    use sea_orm::entity::prelude::*;
+    use serde::{Deserialize, Serialize};
+
+    #[daoyi_model]
+    #[derive(
+        Clone,
+        Debug,
+        PartialEq,
+        Eq,
+        DeriveEntityModel,
+        Serialize,
+        Deserialize,
+        DaoyiActiveModelBehavior,
+    )]
+    #[sea_orm(schema_name = "system", table_name = "system_file_content")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub id: String,
+        pub config_id: String,
+        pub path: String,
+        #[sea_orm(column_type = "VarBinary(StringLen::None)")]
+        pub content: Vec<u8>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+}
@@ -0,0 +1,67 @@
+use super::{FileStorage, LocalStorageConfig, StorageFuture};
+use anyhow::{Context, bail};
+use std::path::{Component, Path, PathBuf};
+
+/// 本地磁盘存储
+pub struct LocalFileStorage {
+    base_path: PathBuf,
+}
+
+impl LocalFileStorage {
+    pub fn new(config: LocalStorageConfig) -> Self {
+        Self {
+            base_path: PathBuf::from(config.base_path),
+        }
+    }
+
+    /// 解析文件的完整路径，拒绝跳出存储根目录的路径
+    fn resolve(&self, path: &str) -> anyhow::Result<PathBuf> {
+        let relative = Path::new(path);
+        if path.is_empty()
+            || relative
+                .components()
+                .any(|x| !matches!(x, Component::Normal(_)))
+        {
+            bail!("文件路径({path})不合法");
+        }
+        Ok(self.base_path.join(relative))
+    }
+}
+
+impl FileStorage for LocalFileStorage {
+    fn upload<'a>(
+        &'a self,
+        path: &'a str,
+        content: &'a [u8],
+        _content_type: &'a str,
+    ) -> StorageFuture<'a, ()> {
+        Box::pin(async move {
+            let file = self.resolve(path)?;
+            if let Some(parent) = file.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            tokio::fs::write(&file, content)
+                .await
+                .with_context(|| format!("写入文件({})失败", file.display()))
+        })
+    }
+
+    fn download<'a>(&'a self, path: &'a str) -> StorageFuture<'a, Vec<u8>> {
+        Box::pin(async move {
+            let file = self.resolve(path)?;
+            tokio::fs::read(&file)
+                .await
+                .with_context(|| format!("读取文件({})失败", file.display()))
+        })
+    }
+
+    fn delete<'a>(&'a self, path: &'a str) -> StorageFuture<'a, ()> {
+        Box::pin(async move {
+            let file = self.resolve(path)?;
+            match tokio::fs::remove_file(&file).await {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+                _ => Ok(()),
+            }
+        })
+    }
+}
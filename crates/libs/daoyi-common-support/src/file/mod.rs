@@ -0,0 +1,213 @@
+//! 文件存储，支持数据库、本地磁盘与 S3 兼容对象存储
+//!
+//! 存储器由文件配置（`system_file_config`）创建，按配置编号缓存，配置更新后重新创建
+
+mod db;
+mod local;
+mod s3;
+
+use crate::configs::AppConfig;
+use crate::enumeration::FileStorageEnum;
+use crate::error::{ApiError, ApiResult};
+use bytesize::ByteSize;
+pub use db::DbFileStorage;
+pub use local::LocalFileStorage;
+pub use s3::S3FileStorage;
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::{Arc, LazyLock, RwLock};
+use std::time::Duration;
+
+pub type StorageFuture<'a, T> = Pin<Box<dyn Future<Output = anyhow::Result<T>> + Send + 'a>>;
+
+/// 文件存储器，`path` 为存储内的相对路径，如 `20250101/xxx.png`
+pub trait FileStorage: Send + Sync {
+    fn upload<'a>(
+        &'a self,
+        path: &'a str,
+        content: &'a [u8],
+        content_type: &'a str,
+    ) -> StorageFuture<'a, ()>;
+
+    fn download<'a>(&'a self, path: &'a str) -> StorageFuture<'a, Vec<u8>>;
+
+    /// 删除文件，文件不存在时不报错
+    fn delete<'a>(&'a self, path: &'a str) -> StorageFuture<'a, ()>;
+
+    /// 生成临时下载地址，存储器不支持时返回 `None`，由调用方提供临时下载
+    fn presigned_url<'a>(
+        &'a self,
+        _path: &'a str,
+        _expires: Duration,
+    ) -> StorageFuture<'a, Option<String>> {
+        Box::pin(async { Ok(None) })
+    }
+}
+
+/// 本地磁盘存储配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalStorageConfig {
+    /// 存储根目录
+    pub base_path: String,
+}
+
+/// S3 兼容对象存储配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct S3StorageConfig {
+    /// 服务地址，如 `http://127.0.0.1:9000`
+    pub endpoint: String,
+    pub bucket: String,
+    pub access_key: String,
+    pub access_secret: String,
+    /// 区域，为空时使用 `us-east-1`
+    pub region: Option<String>,
+    /// 是否使用路径风格（`endpoint/bucket/key`），MinIO 等自建服务通常需要开启
+    #[serde(default = "default_path_style")]
+    pub path_style: bool,
+}
+
+fn default_path_style() -> bool {
+    true
+}
+
+/// 配置编号 -> (配置版本, 存储器)
+type StorageCache = HashMap<String, (String, Arc<dyn FileStorage>)>;
+
+static STORAGE_CACHE: LazyLock<RwLock<StorageCache>> = LazyLock::new(Default::default);
+
+/// 根据存储器类型与配置创建存储器
+pub fn create_storage(
+    config_id: &str,
+    storage: FileStorageEnum,
+    config: &serde_json::Value,
+) -> anyhow::Result<Arc<dyn FileStorage>> {
+    let storage: Arc<dyn FileStorage> = match storage {
+        FileStorageEnum::Db => Arc::new(DbFileStorage::new(config_id)),
+        FileStorageEnum::Local => Arc::new(LocalFileStorage::new(serde_json::from_value(
+            config.clone(),
+        )?)),
+        FileStorageEnum::S3 => {
+            Arc::new(S3FileStorage::new(serde_json::from_value(config.clone())?)?)
+        }
+    };
+    Ok(storage)
+}
+
+/// 获取存储器，`version` 变化（如配置更新时间）时重新创建
+pub fn get_or_create_storage(
+    config_id: &str,
+    version: &str,
+    storage: FileStorageEnum,
+    config: &serde_json::Value,
+) -> anyhow::Result<Arc<dyn FileStorage>> {
+    if let Some((cached_version, storage)) = STORAGE_CACHE
+        .read()
+        .expect("storage cache poisoned")
+        .get(config_id)
+        && cached_version == version
+    {
+        return Ok(storage.clone());
+    }
+    let storage = create_storage(config_id, storage, config)?;
+    STORAGE_CACHE
+        .write()
+        .expect("storage cache poisoned")
+        .insert(
+            config_id.to_string(),
+            (version.to_string(), storage.clone()),
+        );
+    Ok(storage)
+}
+
+/// 校验上传文件的大小，上限与请求体大小限制（`ServerConfig::max_body_size`）一致
+pub async fn check_file_size(size: usize) -> ApiResult<()> {
+    let max_size = AppConfig::get().await.server().max_body_size();
+    if size == 0 {
+        return Err(ApiError::biz("文件内容不能为空"));
+    }
+    if size > max_size {
+        return Err(ApiError::biz(format!(
+            "文件大小不能超过{}",
+            ByteSize::b(max_size as u64)
+        )));
+    }
+    Ok(())
+}
+
+/// 按日期目录生成存储路径，保留原文件的扩展名
+pub fn generate_path(name: &str) -> String {
+    let date = Local::now().format("%Y%m%d");
+    match file_extension(name) {
+        Some(extension) => format!("{date}/{}.{extension}", xid::new()),
+        None => format!("{date}/{}", xid::new()),
+    }
+}
+
+/// 识别文件类型，优先按文件头识别，无法识别时按扩展名判断
+pub fn detect_content_type(content: &[u8], name: &str) -> String {
+    if let Some(kind) = infer::get(content) {
+        return kind.mime_type().to_string();
+    }
+    let content_type = match file_extension(name).as_deref() {
+        Some("txt" | "log") => "text/plain",
+        Some("html" | "htm") => "text/html",
+        Some("css") => "text/css",
+        Some("js") => "text/javascript",
+        Some("json") => "application/json",
+        Some("csv") => "text/csv",
+        Some("md") => "text/markdown",
+        Some("xml") => "application/xml",
+        Some("svg") => "image/svg+xml",
+        _ => "application/octet-stream",
+    };
+    content_type.to_string()
+}
+
+fn file_extension(name: &str) -> Option<String> {
+    Path::new(name)
+        .extension()
+        .and_then(|x| x.to_str())
+        .filter(|x| !x.is_empty() && x.chars().all(|c| c.is_ascii_alphanumeric()))
+        .map(|x| x.to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_content_type() {
+        let png = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 0];
+        assert_eq!(detect_content_type(&png, "avatar.txt"), "image/png");
+        assert_eq!(detect_content_type(b"a,b\n1,2", "import.CSV"), "text/csv");
+        assert_eq!(
+            detect_content_type(b"\x00\x01", "data"),
+            "application/octet-stream"
+        );
+        assert!(generate_path("头像.PNG").ends_with(".png"));
+        assert!(!generate_path("../a").contains(".."));
+    }
+
+    #[tokio::test]
+    async fn test_local_storage() {
+        let base_path = std::env::temp_dir().join(format!("daoyi-file-{}", xid::new()));
+        let storage = LocalFileStorage::new(LocalStorageConfig {
+            base_path: base_path.to_string_lossy().into_owned(),
+        });
+        storage
+            .upload("20250101/a.txt", b"hello", "text/plain")
+            .await
+            .unwrap();
+        assert_eq!(storage.download("20250101/a.txt").await.unwrap(), b"hello");
+        assert!(storage.download("../a.txt").await.is_err());
+        storage.delete("20250101/a.txt").await.unwrap();
+        storage.delete("20250101/a.txt").await.unwrap();
+        assert!(storage.download("20250101/a.txt").await.is_err());
+        std::fs::remove_dir_all(base_path).unwrap();
+    }
+}
@@ -1,26 +1,69 @@
-use idgenerator::{IdGeneratorOptions, IdInstance};
+use idgenerator::{CoreIdGenerator, IdGeneratorOptions};
 use sea_orm::prelude::Date;
+use std::sync::{Arc, Mutex, OnceLock};
 
+/// 默认的雪花算法机器码
+pub const DEFAULT_WORKER_ID: u32 = 1;
+
+static ID_GENERATOR: OnceLock<IdGenerator> = OnceLock::new();
+
+/// 雪花算法 ID 生成器，克隆出的实例共享同一个序列
+#[derive(Clone)]
+pub struct IdGenerator {
+    inner: Arc<Mutex<CoreIdGenerator>>,
+}
+
+impl IdGenerator {
+    pub fn new(worker_id: u32) -> anyhow::Result<Self> {
+        let options = IdGeneratorOptions::new()
+            .base_time(
+                Date::from_ymd_opt(2025, 11, 11)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap()
+                    .and_utc()
+                    .timestamp_millis(),
+            )
+            .worker_id(worker_id)
+            .worker_id_bit_len(4);
+        let mut generator = CoreIdGenerator::default();
+        generator.init(options)?;
+        Ok(Self {
+            inner: Arc::new(Mutex::new(generator)),
+        })
+    }
+
+    pub fn next_i64(&self) -> i64 {
+        self.inner.lock().expect("id generator poisoned").next_id()
+    }
+
+    pub fn next_string(&self) -> String {
+        self.next_i64().to_string()
+    }
+}
+
+/// 初始化全局默认的 ID 生成器
 pub async fn init() -> anyhow::Result<()> {
-    let options = IdGeneratorOptions::new()
-        .base_time(
-            Date::from_ymd_opt(2025, 11, 11)
-                .unwrap()
-                .and_hms_opt(0, 0, 0)
-                .unwrap()
-                .and_utc()
-                .timestamp_millis(),
-        )
-        .worker_id(1)
-        .worker_id_bit_len(4);
-
-    Ok(IdInstance::init(options)?)
+    install(IdGenerator::new(DEFAULT_WORKER_ID)?);
+    Ok(())
+}
+
+/// 安装全局默认的 ID 生成器，只有首次安装生效
+pub fn install(generator: IdGenerator) {
+    let _ = ID_GENERATOR.set(generator);
+}
+
+/// 全局默认的 ID 生成器，未安装时使用默认机器码创建
+fn global() -> &'static IdGenerator {
+    ID_GENERATOR.get_or_init(|| {
+        IdGenerator::new(DEFAULT_WORKER_ID).expect("Failed to create id generator")
+    })
 }
 
 pub fn next_i64() -> i64 {
-    IdInstance::next_id()
+    global().next_i64()
 }
 
 pub fn next_string() -> String {
-    next_i64().to_string()
+    global().next_string()
 }
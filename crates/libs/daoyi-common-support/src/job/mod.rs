@@ -0,0 +1,157 @@
+//! 定时任务：任务处理器注册表、cron 表达式计算、多实例触发锁与失败重试
+//!
+//! 任务定义保存在 `system_job` 表中，由持有该表的服务负责调度，各模块在启动时注册任务处理器
+
+use crate::enumeration::redis_keys::RedisKey;
+use crate::error::{ApiError, ApiResult};
+use crate::redis_utils;
+use cron::Schedule;
+use sea_orm::prelude::DateTime;
+use chrono::{Local, TimeZone};
+use std::collections::BTreeMap;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::sync::{LazyLock, RwLock};
+use std::time::Duration;
+
+pub type JobHandlerFuture = Pin<Box<dyn Future<Output = anyhow::Result<String>> + Send>>;
+
+/// 任务处理器，参数为任务配置的处理器参数，返回执行结果说明
+///
+/// 调度触发时处理器在任务所属租户的上下文中执行，手动触发时沿用触发请求的上下文
+pub type JobHandler = fn(Option<String>) -> JobHandlerFuture;
+
+static JOB_HANDLERS: LazyLock<RwLock<BTreeMap<&'static str, JobHandler>>> =
+    LazyLock::new(Default::default);
+
+/// 触发锁的过期时间，需大于各实例之间的时钟偏差
+const TRIGGER_LOCK_SECONDS: u64 = 600;
+
+/// 注册任务处理器，同名处理器后注册的覆盖先注册的
+pub fn register_handler(name: &'static str, handler: JobHandler) {
+    JOB_HANDLERS
+        .write()
+        .expect("job handlers poisoned")
+        .insert(name, handler);
+}
+
+pub fn get_handler(name: &str) -> Option<JobHandler> {
+    JOB_HANDLERS
+        .read()
+        .expect("job handlers poisoned")
+        .get(name)
+        .copied()
+}
+
+/// 已注册的任务处理器名称，按名称排序
+pub fn get_handler_names() -> Vec<&'static str> {
+    JOB_HANDLERS
+        .read()
+        .expect("job handlers poisoned")
+        .keys()
+        .copied()
+        .collect()
+}
+
+/// 解析 cron 表达式，格式为 `秒 分 时 日 月 周 [年]`
+pub fn parse_cron(expression: &str) -> ApiResult<Schedule> {
+    Schedule::from_str(expression.trim())
+        .map_err(|e| ApiError::biz(format!("CRON 表达式({expression})不正确: {e}")))
+}
+
+/// 计算 `after` 之后的若干次触发时间（本地时间）
+pub fn next_fire_times(
+    expression: &str,
+    after: DateTime,
+    count: usize,
+) -> ApiResult<Vec<DateTime>> {
+    let schedule = parse_cron(expression)?;
+    let after = Local
+        .from_local_datetime(&after)
+        .earliest()
+        .ok_or_else(|| ApiError::biz(format!("时间({after})不正确")))?;
+    Ok(schedule
+        .after(&after)
+        .take(count)
+        .map(|x| x.naive_local())
+        .collect())
+}
+
+/// 抢占任务某次触发的执行权，各实例按相同的 cron 计算出相同的触发时间，只有一个实例能抢到
+pub async fn try_acquire_trigger(job_id: &str, fire_time: DateTime) -> ApiResult<bool> {
+    let key = RedisKey::JobTriggerLock.key(format!("{job_id}:{}", fire_time.and_utc().timestamp()));
+    redis_utils::set_nx_ex(&key, 1, TRIGGER_LOCK_SECONDS).await
+}
+
+/// 任务执行结果
+#[derive(Debug)]
+pub struct JobExecution {
+    pub result: anyhow::Result<String>,
+    /// 执行次数，含首次执行
+    pub attempts: i32,
+}
+
+/// 执行任务处理器，失败时按重试次数与重试间隔重试
+pub async fn execute(
+    handler: JobHandler,
+    param: Option<String>,
+    retry_count: u32,
+    retry_interval: Duration,
+) -> JobExecution {
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        let result = handler(param.clone()).await;
+        match result {
+            Err(e) if attempts <= retry_count as i32 => {
+                tracing::warn!("任务第{attempts}次执行失败，{retry_interval:?}后重试: {e:#}");
+                tokio::time::sleep(retry_interval).await;
+            }
+            result => return JobExecution { result, attempts },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    fn flaky_handler(param: Option<String>) -> JobHandlerFuture {
+        Box::pin(async move {
+            if CALLS.fetch_add(1, Ordering::SeqCst) < 2 {
+                anyhow::bail!("失败");
+            }
+            Ok(param.unwrap_or_default())
+        })
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_retry() {
+        register_handler("flaky", flaky_handler);
+        assert!(get_handler_names().contains(&"flaky"));
+        let handler = get_handler("flaky").unwrap();
+        let execution = execute(handler, Some(String::from("ok")), 1, Duration::ZERO).await;
+        assert!(execution.result.is_err());
+        assert_eq!(execution.attempts, 2);
+        let execution = execute(handler, Some(String::from("ok")), 3, Duration::ZERO).await;
+        assert_eq!(execution.result.unwrap(), "ok");
+        assert_eq!(execution.attempts, 1);
+    }
+
+    #[test]
+    fn test_next_fire_times() {
+        let after = DateTime::from_str("2025-01-01T00:00:30").unwrap();
+        let times = next_fire_times("0 */15 * * * *", after, 3).unwrap();
+        let expected = [
+            "2025-01-01T00:15:00",
+            "2025-01-01T00:30:00",
+            "2025-01-01T00:45:00",
+        ]
+        .map(|x| DateTime::from_str(x).unwrap());
+        assert_eq!(times, expected);
+        assert!(parse_cron("* * *").is_err());
+    }
+}
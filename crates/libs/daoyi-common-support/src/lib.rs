@@ -1,18 +1,31 @@
+extern crate self as daoyi_common_support;
+
+pub mod actuator;
 pub mod app;
 pub mod auth;
+pub mod config;
 pub mod configs;
 pub mod database;
+pub mod dict;
 pub mod enumeration;
 pub mod error;
+pub mod file;
 pub mod id;
+pub mod job;
 pub mod logger;
+pub mod mail;
+pub mod metrics;
 pub mod middlewares;
 pub mod models;
+pub mod openapi;
 pub mod password;
+pub mod push;
 pub mod redis_utils;
 pub mod request;
 pub mod response;
 pub mod serde;
 pub mod server;
+pub mod telemetry;
+pub mod template;
 pub mod context;
 pub mod vo;
\ No newline at end of file
@@ -1,65 +1,133 @@
 use crate::configs::AppConfig;
-use tracing_appender::{non_blocking, rolling};
-use tracing_subscriber::EnvFilter;
+use crate::configs::log_config::LogFormat;
+use crate::telemetry;
+use rolling::{Retention, RollingFileWriter, Rotation};
+use tracing::Subscriber;
+use tracing_appender::non_blocking;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_subscriber::field::RecordFields;
+use tracing_subscriber::fmt::format::{DefaultFields, Writer};
+use tracing_subscriber::fmt::time::ChronoLocal;
+use tracing_subscriber::fmt::{FormatFields, MakeWriter};
 use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::registry::LookupSpan;
 use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer};
 
-pub async fn init() {
+pub mod rolling;
+
+/// 日志组件的守卫，需要在应用运行期间持有
+///
+/// 文件日志由后台线程写入，释放守卫时会写出缓冲中剩余的日志，
+/// 应用在服务优雅关闭之后释放，避免丢失关闭过程中的日志
+#[must_use = "释放守卫后文件日志将不再写入"]
+pub struct LogGuard {
+    _file: WorkerGuard,
+}
+
+pub async fn init() -> anyhow::Result<LogGuard> {
     let app_config = AppConfig::get().await;
     let log_config = app_config.log();
 
-    // 根据配置创建文件 appender
+    // 根据配置创建滚动日志文件
     let filename = log_config.filename().unwrap_or(app_config.app_name());
     let filename = if !filename.ends_with(".log") {
         format!("{}.log", filename)
     } else {
         filename.to_string()
     };
-    let file_appender = match log_config.rolling() {
-        "hourly" => rolling::hourly(log_config.dir(), filename),
-        "minutely" => rolling::minutely(log_config.dir(), filename),
-        "never" => rolling::never(log_config.dir(), filename),
-        _ => rolling::daily(log_config.dir(), filename), // 默认按天分割
-    };
+    let file_writer = RollingFileWriter::new(
+        log_config.dir(),
+        filename,
+        Rotation::parse(log_config.rolling()),
+        log_config.max_file_size(),
+        Retention {
+            max_files: log_config.max_files(),
+            max_total_size: log_config.max_total_size(),
+        },
+    )?;
 
     // 创建非阻塞写入器
-    let (non_blocking_file, _guard) = non_blocking(file_appender);
+    let (non_blocking_file, guard) = non_blocking(file_writer);
 
-    // 控制台输出层
-    let console_layer = tracing_subscriber::fmt::layer()
-        .with_timer(tracing_subscriber::fmt::time::ChronoLocal::new(
-            "%Y-%m-%d %H:%M:%S%.3f".to_string(),
-        ))
-        .with_file(true)
-        .with_line_number(true)
-        .with_thread_ids(true)
-        .with_thread_names(true)
-        .with_target(false);
+    // 控制台与文件分别设置格式与级别
+    let console_layer = fmt_layer(log_config.console_format(), std::io::stdout, true)
+        .with_filter(level_filter(log_config.console_level()));
+    let file_layer = fmt_layer(log_config.file_format(), non_blocking_file, false)
+        .with_filter(level_filter(log_config.file_level()));
+
+    // 链路追踪层，初始化失败时只输出日志，不影响服务启动
+    let (otel_layer, otel_error) = match telemetry::init(app_config) {
+        Ok(tracer) => (
+            Some(
+                tracing_opentelemetry::layer()
+                    .with_tracer(tracer)
+                    .with_filter(level_filter(log_config.level())),
+            ),
+            None,
+        ),
+        Err(e) => (None, Some(e)),
+    };
+
+    tracing_subscriber::registry()
+        .with(console_layer)
+        .with(file_layer)
+        .with(otel_layer)
+        .init();
+
+    if let Some(e) = otel_error {
+        tracing::error!("Failed to initialize OpenTelemetry tracer: {}", e);
+    }
+
+    Ok(LogGuard { _file: guard })
+}
+
+/// 日志级别过滤，设置了 `RUST_LOG` 环境变量时以环境变量为准
+fn level_filter(level: &str) -> EnvFilter {
+    let mut log_level = std::env::var("RUST_LOG").unwrap_or_else(|_| level.to_string());
+    log_level.push_str(",nacos=warn,h2=warn,hyper=warn,tower=warn");
+    EnvFilter::new(log_level)
+}
 
-    // 文件输出层
-    let file_layer = tracing_subscriber::fmt::layer()
-        .with_writer(non_blocking_file)
-        .with_timer(tracing_subscriber::fmt::time::ChronoLocal::new(
-            "%Y-%m-%d %H:%M:%S%.3f".to_string(),
-        ))
+fn fmt_layer<S, W>(format: LogFormat, writer: W, console: bool) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
+    let layer = tracing_subscriber::fmt::layer()
+        .with_writer(writer)
+        .with_timer(ChronoLocal::new("%Y-%m-%d %H:%M:%S%.3f".to_string()))
         .with_file(true)
         .with_line_number(true)
         .with_thread_ids(true)
         .with_thread_names(true)
         .with_target(false)
-        .with_ansi(false); // 文件输出不需要颜色代码
-
-    let mut log_level =
-        std::env::var("RUST_LOG").unwrap_or_else(|_| log_config.level().to_string());
-    log_level.push_str(",nacos=warn,h2=warn,hyper=warn,tower=warn");
+        .with_ansi(console && format == LogFormat::Text); // 文件与 JSON 输出不需要颜色代码
+    match format {
+        LogFormat::Text if console => layer.boxed(),
+        LogFormat::Text => layer.fmt_fields(FileFields::default()).boxed(),
+        LogFormat::Json => layer
+            .json()
+            .flatten_event(true)
+            .with_current_span(true)
+            .with_span_list(false)
+            .boxed(),
+    }
+}
 
-    tracing_subscriber::registry()
-        .with(EnvFilter::new(log_level))
-        .with(console_layer)
-        .with(file_layer)
-        .init();
+/// 文件输出层的字段格式
+///
+/// 各输出层按字段格式的类型缓存 span 字段，与控制台共用 `DefaultFields` 时，
+/// 文件中会混入控制台的颜色代码，延迟记录的字段（如 `trace_id`）也会重复输出
+#[derive(Default)]
+struct FileFields(DefaultFields);
 
-    // 注意：_guard 需要保持存活，否则日志会丢失
-    // 可以考虑将其存储在全局变量中
-    std::mem::forget(_guard);
+impl<'writer> FormatFields<'writer> for FileFields {
+    fn format_fields<R: RecordFields>(
+        &self,
+        writer: Writer<'writer>,
+        fields: R,
+    ) -> std::fmt::Result {
+        self.0.format_fields(writer, fields)
+    }
 }
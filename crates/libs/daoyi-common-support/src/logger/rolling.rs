@@ -0,0 +1,253 @@
+//! 滚动日志文件
+//!
+//! 按时间周期切分文件，文件名与 `tracing_appender::rolling` 一致（如 `app.log.2026-01-01`）；
+//! 配置单个文件的大小上限后，同一周期内超过上限时依次切分为 `app.log.2026-01-01.1`、`.2`……
+//! 每次切分后按文件数量与总大小清理最旧的文件
+
+use chrono::{DateTime, Local};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// 按时间切分的周期
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    Minutely,
+    Hourly,
+    Daily,
+    Never,
+}
+
+impl Rotation {
+    /// 按配置解析: daily, hourly, minutely, never，默认按天
+    pub fn parse(rolling: &str) -> Self {
+        match rolling {
+            "hourly" => Rotation::Hourly,
+            "minutely" => Rotation::Minutely,
+            "never" => Rotation::Never,
+            _ => Rotation::Daily,
+        }
+    }
+
+    fn period(&self, now: DateTime<Local>) -> Option<String> {
+        let format = match self {
+            Rotation::Minutely => "%Y-%m-%d-%H-%M",
+            Rotation::Hourly => "%Y-%m-%d-%H",
+            Rotation::Daily => "%Y-%m-%d",
+            Rotation::Never => return None,
+        };
+        Some(now.format(format).to_string())
+    }
+}
+
+/// 日志文件的保留策略，两项都未配置时不清理
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Retention {
+    pub max_files: Option<usize>,
+    pub max_total_size: Option<u64>,
+}
+
+pub struct RollingFileWriter {
+    dir: PathBuf,
+    filename: String,
+    rotation: Rotation,
+    max_file_size: Option<u64>,
+    retention: Retention,
+    period: Option<String>,
+    index: usize,
+    file: Option<File>,
+    size: u64,
+}
+
+impl RollingFileWriter {
+    pub fn new(
+        dir: impl Into<PathBuf>,
+        filename: impl Into<String>,
+        rotation: Rotation,
+        max_file_size: Option<u64>,
+        retention: Retention,
+    ) -> io::Result<Self> {
+        let mut writer = RollingFileWriter {
+            dir: dir.into(),
+            filename: filename.into(),
+            rotation,
+            max_file_size,
+            retention,
+            period: None,
+            index: 0,
+            file: None,
+            size: 0,
+        };
+        fs::create_dir_all(&writer.dir)?;
+        writer.period = rotation.period(Local::now());
+        // 重启后继续写入当前周期的最后一个文件
+        writer.index = writer.last_index()?;
+        writer.open()?;
+        Ok(writer)
+    }
+
+    /// 当前周期的文件名前缀，如 `app.log.2026-01-01`
+    fn base_name(&self) -> String {
+        match &self.period {
+            Some(period) => format!("{}.{}", self.filename, period),
+            None => self.filename.clone(),
+        }
+    }
+
+    fn path(&self) -> PathBuf {
+        let base = self.base_name();
+        match self.index {
+            0 => self.dir.join(base),
+            index => self.dir.join(format!("{base}.{index}")),
+        }
+    }
+
+    fn last_index(&self) -> io::Result<usize> {
+        let base = self.base_name();
+        let index = fs::read_dir(&self.dir)?
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                match name.strip_prefix(&base)? {
+                    "" => Some(0),
+                    suffix => suffix.strip_prefix('.')?.parse().ok(),
+                }
+            })
+            .max()
+            .unwrap_or(0);
+        Ok(index)
+    }
+
+    fn open(&mut self) -> io::Result<()> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.path())?;
+        self.size = file.metadata()?.len();
+        self.file = Some(file);
+        self.prune();
+        Ok(())
+    }
+
+    /// 写入前检查是否需要切分文件
+    fn rotate_if_needed(&mut self, len: usize) -> io::Result<()> {
+        let period = self.rotation.period(Local::now());
+        if period != self.period {
+            self.period = period;
+            self.index = self.last_index()?;
+            return self.open();
+        }
+        if let Some(max_file_size) = self.max_file_size
+            && self.size > 0
+            && self.size + len as u64 > max_file_size
+        {
+            self.index += 1;
+            return self.open();
+        }
+        Ok(())
+    }
+
+    /// 按保留策略删除最旧的日志文件，当前写入的文件不会被删除
+    fn prune(&self) {
+        let Retention {
+            max_files,
+            max_total_size,
+        } = self.retention;
+        if max_files.is_none() && max_total_size.is_none() {
+            return;
+        }
+        let current = self.path();
+        let Ok(entries) = fs::read_dir(&self.dir) else {
+            return;
+        };
+        let prefix = format!("{}.", self.filename);
+        let mut files: Vec<(PathBuf, SystemTime, u64)> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .to_str()
+                    .is_some_and(|name| name == self.filename || name.starts_with(&prefix))
+            })
+            .filter(|entry| entry.path() != current)
+            .filter_map(|entry| {
+                let metadata = entry.metadata().ok()?;
+                metadata.is_file().then(|| {
+                    let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                    (entry.path(), modified, metadata.len())
+                })
+            })
+            .collect();
+        // 从新到旧累计，超过上限之后的文件全部删除
+        files.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| b.0.cmp(&a.0)));
+        let mut total_size = self.size;
+        // 当前文件计为第一个
+        for (count, (path, _, len)) in (2..).zip(files) {
+            total_size += len;
+            let exceeded = max_files.is_some_and(|max| count > max)
+                || max_total_size.is_some_and(|max| total_size > max);
+            if exceeded && let Err(e) = fs::remove_file(&path) {
+                // 写日志的线程中不能再输出日志
+                eprintln!("Failed to remove log file {}: {}", path.display(), e);
+            }
+        }
+    }
+}
+
+impl Write for RollingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.rotate_if_needed(buf.len())?;
+        let file = self
+            .file
+            .as_mut()
+            .ok_or_else(|| io::Error::other("log file is not open"))?;
+        let written = file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.file.as_mut() {
+            Some(file) => file.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file_names(dir: &PathBuf) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn rotate_by_size_and_keep_max_files() {
+        let dir = std::env::temp_dir().join(format!("daoyi-log-{}", xid::new()));
+        let retention = Retention {
+            max_files: Some(3),
+            max_total_size: None,
+        };
+        let mut writer =
+            RollingFileWriter::new(&dir, "app.log", Rotation::Never, Some(10), retention).unwrap();
+        for _ in 0..5 {
+            writer.write_all(b"0123456789").unwrap();
+        }
+        assert_eq!(file_names(&dir), ["app.log.2", "app.log.3", "app.log.4"]);
+
+        // 重启后续写最后一个文件
+        drop(writer);
+        let mut writer =
+            RollingFileWriter::new(&dir, "app.log", Rotation::Never, None, retention).unwrap();
+        writer.write_all(b"abc").unwrap();
+        assert_eq!(fs::read(dir.join("app.log.4")).unwrap(), b"0123456789abc");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
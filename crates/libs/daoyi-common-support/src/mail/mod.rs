@@ -0,0 +1,135 @@
+//! SMTP 邮件发送
+
+use lettre::message::Mailbox;
+use lettre::message::header::ContentType;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use std::time::Duration;
+
+const SMTP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// SMTP 账号配置
+#[derive(Debug, Clone)]
+pub struct SmtpAccount {
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    /// 是否使用 SSL/TLS 直连（通常为 465 端口）
+    pub ssl_enable: bool,
+    /// 是否使用 STARTTLS 升级加密（通常为 587 端口）
+    pub starttls_enable: bool,
+}
+
+/// 待发送的邮件，内容为 HTML
+#[derive(Debug, Clone)]
+pub struct MailContent {
+    pub from: String,
+    pub nickname: Option<String>,
+    pub to: String,
+    pub subject: String,
+    pub html: String,
+}
+
+/// 发送邮件，返回邮件的 Message-ID
+pub async fn send_mail(account: &SmtpAccount, mail: &MailContent) -> anyhow::Result<String> {
+    let from = Mailbox::new(mail.nickname.clone(), mail.from.parse()?);
+    let domain = mail.from.rsplit_once('@').map_or("localhost", |(_, d)| d);
+    let message_id = format!("<{}@{}>", xid::new(), domain);
+    let message = Message::builder()
+        .from(from)
+        .to(mail.to.parse()?)
+        .subject(&mail.subject)
+        .message_id(Some(message_id.clone()))
+        .header(ContentType::TEXT_HTML)
+        .body(mail.html.clone())?;
+    let mut builder = if account.ssl_enable {
+        AsyncSmtpTransport::<Tokio1Executor>::relay(&account.host)?
+    } else if account.starttls_enable {
+        AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&account.host)?
+    } else {
+        AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&account.host)
+    };
+    builder = builder.port(account.port).timeout(Some(SMTP_TIMEOUT));
+    if let Some(username) = account.username.as_ref().filter(|u| !u.is_empty()) {
+        let password = account.password.clone().unwrap_or_default();
+        builder = builder.credentials(Credentials::new(username.clone(), password));
+    }
+    builder.build().send(message).await?;
+    Ok(message_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+    use tokio::sync::oneshot;
+
+    /// 本地 SMTP 收信桩，接收一封邮件并返回 DATA 内容
+    async fn start_smtp_sink() -> (u16, oneshot::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let (tx, rx) = oneshot::channel();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (reader, mut writer) = stream.into_split();
+            let mut lines = BufReader::new(reader).lines();
+            writer.write_all(b"220 localhost ESMTP\r\n").await.unwrap();
+            let mut data = String::new();
+            let mut in_data = false;
+            while let Some(line) = lines.next_line().await.unwrap() {
+                if in_data {
+                    if line == "." {
+                        in_data = false;
+                        writer.write_all(b"250 OK\r\n").await.unwrap();
+                    } else {
+                        data.push_str(&line);
+                        data.push('\n');
+                    }
+                    continue;
+                }
+                let command = line.to_ascii_uppercase();
+                if command.starts_with("DATA") {
+                    in_data = true;
+                    writer
+                        .write_all(b"354 End data with <CR><LF>.<CR><LF>\r\n")
+                        .await
+                        .unwrap();
+                } else if command.starts_with("QUIT") {
+                    writer.write_all(b"221 Bye\r\n").await.unwrap();
+                    break;
+                } else {
+                    writer.write_all(b"250 OK\r\n").await.unwrap();
+                }
+            }
+            let _ = tx.send(data);
+        });
+        (port, rx)
+    }
+
+    #[tokio::test]
+    async fn test_send_mail_to_local_sink() {
+        let (port, rx) = start_smtp_sink().await;
+        let account = SmtpAccount {
+            host: String::from("127.0.0.1"),
+            port,
+            username: None,
+            password: None,
+            ssl_enable: false,
+            starttls_enable: false,
+        };
+        let mail = MailContent {
+            from: String::from("noreply@daoyi.local"),
+            nickname: Some(String::from("Daoyi")),
+            to: String::from("user@daoyi.local"),
+            subject: String::from("Welcome"),
+            html: String::from("<p>hello</p>"),
+        };
+        let message_id = send_mail(&account, &mail).await.unwrap();
+        let data = rx.await.unwrap();
+        assert!(data.contains("Subject: Welcome"));
+        assert!(data.contains("<p>hello</p>"));
+        assert!(data.contains(&message_id));
+    }
+}
@@ -0,0 +1,255 @@
+//! Prometheus 监控指标
+//!
+//! - HTTP：按匹配的路由、请求方法、状态码与租户统计请求数与耗时
+//! - 数据库：连接池使用情况、获取连接的等待时间与 SQL 执行耗时
+//! - Redis：连接池使用情况与获取连接的等待时间，`cache_get_json` 的命中与未命中次数
+//! - 进程：CPU、内存、文件句柄等（仅 Linux）
+//!
+//! `/metrics` 默认与业务接口共用服务端口，配置 `metrics.port` 后改为在单独的管理端口提供
+
+use crate::app::AppState;
+use crate::context::HttpRequestContext;
+use axum::extract::{MatchedPath, Request, State};
+use axum::http::header;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum::{Router, routing};
+use deadpool_redis::Pool;
+use prometheus::{
+    Encoder, Gauge, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, Opts,
+    Registry, TextEncoder,
+};
+use sea_orm::DatabaseConnection;
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
+
+pub const METRICS_PATH: &str = "/metrics";
+
+/// 采集时探测获取数据库连接的超时时间
+const DB_ACQUIRE_PROBE_TIMEOUT: Duration = Duration::from_secs(1);
+
+static REGISTRY: LazyLock<Registry> = LazyLock::new(|| {
+    let registry = Registry::new();
+    #[cfg(target_os = "linux")]
+    registry
+        .register(Box::new(
+            prometheus::process_collector::ProcessCollector::for_self(),
+        ))
+        .expect("register process collector");
+    registry
+});
+
+static HTTP_REQUESTS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register(IntCounterVec::new(
+        Opts::new("http_requests_total", "HTTP 请求数"),
+        &["route", "method", "status", "tenant"],
+    ))
+});
+
+static HTTP_REQUEST_DURATION: LazyLock<HistogramVec> = LazyLock::new(|| {
+    register(HistogramVec::new(
+        HistogramOpts::new("http_request_duration_seconds", "HTTP 请求耗时"),
+        &["route", "method", "status", "tenant"],
+    ))
+});
+
+static DB_POOL_CONNECTIONS: LazyLock<IntGaugeVec> = LazyLock::new(|| {
+    register(IntGaugeVec::new(
+        Opts::new("db_pool_connections", "数据库连接池连接数"),
+        &["state"],
+    ))
+});
+
+static DB_POOL_ACQUIRE: LazyLock<Gauge> = LazyLock::new(|| {
+    register(Gauge::new(
+        "db_pool_acquire_seconds",
+        "采集时获取数据库连接的等待时间",
+    ))
+});
+
+static DB_QUERY_DURATION: LazyLock<HistogramVec> = LazyLock::new(|| {
+    register(HistogramVec::new(
+        HistogramOpts::new("db_query_duration_seconds", "SQL 执行耗时"),
+        &["result"],
+    ))
+});
+
+static REDIS_POOL_CONNECTIONS: LazyLock<IntGaugeVec> = LazyLock::new(|| {
+    register(IntGaugeVec::new(
+        Opts::new("redis_pool_connections", "Redis 连接池连接数"),
+        &["state"],
+    ))
+});
+
+static REDIS_POOL_WAITING: LazyLock<IntGauge> = LazyLock::new(|| {
+    register(IntGauge::new(
+        "redis_pool_waiting",
+        "等待获取 Redis 连接的任务数",
+    ))
+});
+
+static REDIS_POOL_WAIT: LazyLock<HistogramVec> = LazyLock::new(|| {
+    register(HistogramVec::new(
+        HistogramOpts::new("redis_pool_wait_seconds", "获取 Redis 连接的等待时间"),
+        &["result"],
+    ))
+});
+
+static CACHE_REQUESTS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register(IntCounterVec::new(
+        Opts::new("cache_requests_total", "缓存读取次数"),
+        &["result"],
+    ))
+});
+
+fn register<M>(metric: prometheus::Result<M>) -> M
+where
+    M: prometheus::core::Collector + Clone + 'static,
+{
+    let metric = metric.expect("invalid metric");
+    REGISTRY
+        .register(Box::new(metric.clone()))
+        .expect("register metric");
+    metric
+}
+
+/// 统计 HTTP 请求，需要作为 `Router::layer` 添加才能取得匹配的路由
+pub async fn track_http(request: Request, next: Next) -> Response {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_default();
+    let method = request.method().clone();
+    let start = Instant::now();
+    let response = next.run(request).await;
+    // 租户在认证层中解析，由 `request_context_middleware` 带回到响应中
+    let tenant = response
+        .extensions()
+        .get::<HttpRequestContext>()
+        .and_then(|context| context.tenant_id.as_deref())
+        .unwrap_or_default();
+    let status = response.status();
+    let labels = [route.as_str(), method.as_str(), status.as_str(), tenant];
+    HTTP_REQUESTS.with_label_values(&labels).inc();
+    HTTP_REQUEST_DURATION
+        .with_label_values(&labels)
+        .observe(start.elapsed().as_secs_f64());
+    response
+}
+
+/// 记录 SQL 执行耗时，通过 `DatabaseConnection::set_metric_callback` 注册
+pub fn observe_db_query(info: &sea_orm::metric::Info<'_>) {
+    DB_QUERY_DURATION
+        .with_label_values(&[result_label(!info.failed)])
+        .observe(info.elapsed.as_secs_f64());
+}
+
+/// 记录获取 Redis 连接的等待时间
+pub fn observe_redis_wait(elapsed: Duration, success: bool) {
+    REDIS_POOL_WAIT
+        .with_label_values(&[result_label(success)])
+        .observe(elapsed.as_secs_f64());
+}
+
+/// 记录缓存是否命中
+pub fn record_cache(hit: bool) {
+    CACHE_REQUESTS
+        .with_label_values(&[if hit { "hit" } else { "miss" }])
+        .inc();
+}
+
+fn result_label(success: bool) -> &'static str {
+    if success { "ok" } else { "error" }
+}
+
+pub fn create_router() -> Router<AppState> {
+    Router::new().route(METRICS_PATH, routing::get(metrics))
+}
+
+async fn metrics(State(state): State<AppState>) -> Response {
+    collect_db_pool(state.db()).await;
+    collect_redis_pool(state.redis());
+    let mut buffer = Vec::new();
+    let encoder = TextEncoder::new();
+    if let Err(e) = encoder.encode(&REGISTRY.gather(), &mut buffer) {
+        tracing::error!("Failed to encode metrics: {}", e);
+    }
+    (
+        [(header::CONTENT_TYPE, encoder.format_type().to_string())],
+        buffer,
+    )
+        .into_response()
+}
+
+macro_rules! collect_sqlx_pool {
+    ($pool:expr) => {{
+        let pool = $pool;
+        let size = pool.size() as i64;
+        let idle = pool.num_idle() as i64;
+        DB_POOL_CONNECTIONS
+            .with_label_values(&["max"])
+            .set(pool.options().get_max_connections() as i64);
+        DB_POOL_CONNECTIONS.with_label_values(&["size"]).set(size);
+        DB_POOL_CONNECTIONS.with_label_values(&["idle"]).set(idle);
+        DB_POOL_CONNECTIONS
+            .with_label_values(&["active"])
+            .set(size - idle);
+        // sqlx 不提供等待时间统计，采集时获取一次连接，耗时反映当前的等待情况
+        let start = Instant::now();
+        let _ = tokio::time::timeout(DB_ACQUIRE_PROBE_TIMEOUT, pool.acquire()).await;
+        DB_POOL_ACQUIRE.set(start.elapsed().as_secs_f64());
+    }};
+}
+
+async fn collect_db_pool(db: &DatabaseConnection) {
+    match db {
+        #[cfg(feature = "postgres")]
+        DatabaseConnection::SqlxPostgresPoolConnection(_) => {
+            collect_sqlx_pool!(db.get_postgres_connection_pool())
+        }
+        #[cfg(feature = "mysql")]
+        DatabaseConnection::SqlxMySqlPoolConnection(_) => {
+            collect_sqlx_pool!(db.get_mysql_connection_pool())
+        }
+        #[cfg(feature = "sqlite")]
+        DatabaseConnection::SqlxSqlitePoolConnection(_) => {
+            collect_sqlx_pool!(db.get_sqlite_connection_pool())
+        }
+        _ => {}
+    }
+}
+
+fn collect_redis_pool(pool: &Pool) {
+    let status = pool.status();
+    REDIS_POOL_CONNECTIONS
+        .with_label_values(&["max"])
+        .set(status.max_size as i64);
+    REDIS_POOL_CONNECTIONS
+        .with_label_values(&["size"])
+        .set(status.size as i64);
+    REDIS_POOL_CONNECTIONS
+        .with_label_values(&["idle"])
+        .set(status.available as i64);
+    REDIS_POOL_WAITING.set(status.waiting as i64);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_registered_metrics() {
+        record_cache(true);
+        record_cache(false);
+        observe_redis_wait(Duration::from_millis(2), true);
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&REGISTRY.gather(), &mut buffer)
+            .unwrap();
+        let text = String::from_utf8(buffer).unwrap();
+        assert!(text.contains("cache_requests_total{result=\"hit\"} 1"));
+        assert!(text.contains("cache_requests_total{result=\"miss\"} 1"));
+        assert!(text.contains("redis_pool_wait_seconds_count{result=\"ok\"} 1"));
+    }
+}
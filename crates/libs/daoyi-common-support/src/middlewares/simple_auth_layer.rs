@@ -1,22 +1,23 @@
 use crate::auth;
-use crate::configs::AppConfig;
+use crate::configs::{AppConfig, AuthConfig, TenantResolver};
 use crate::context::HttpRequestContext;
 use crate::error::ApiError;
 use axum::body::Body;
-use axum::http::{Request, Response};
+use axum::http::{HeaderMap, Request, Response, header};
 use axum::middleware::Next;
 use axum::response::IntoResponse;
 use std::pin::Pin;
 use tokio::sync::OnceCell;
 use tower_http::auth::{AsyncAuthorizeRequest, AsyncRequireAuthorizationLayer};
 
-static THREAD_LOCAL_LAYER: OnceCell<AsyncRequireAuthorizationLayer<ThreadLocalLayer>> =
+static AUTH_LAYER: OnceCell<AsyncRequireAuthorizationLayer<RequestContextLayer>> =
     OnceCell::const_new();
 
+/// 校验令牌与租户，并将解析出的上下文放入请求扩展
 #[derive(Clone)]
-pub struct ThreadLocalLayer;
+pub struct RequestContextLayer;
 
-impl AsyncAuthorizeRequest<Body> for ThreadLocalLayer {
+impl AsyncAuthorizeRequest<Body> for RequestContextLayer {
     type RequestBody = Body;
     type ResponseBody = Body;
     type Future = Pin<
@@ -61,17 +62,9 @@ impl AsyncAuthorizeRequest<Body> for ThreadLocalLayer {
                 let token_info = auth::check_token(token).await?;
                 token_tenant_id = Some(token_info.tenant_id);
                 context.token = Some(String::from(token));
-                context.login_id = Some(String::from(token_info.user_id));
+                context.login_id = Some(token_info.user_id);
             };
-            let tenant_id = headers
-                .get(auth_config.header_key_tenant())
-                .map(|value| -> Result<_, ApiError> {
-                    let tenant_id = value.to_str().map_err(|_| {
-                        ApiError::unauthenticated("Tenant header value is not a string")
-                    })?;
-                    Ok(tenant_id)
-                })
-                .transpose()?;
+            let tenant_id = resolve_tenant_id(headers, auth_config, is_ignored_tenant).await?;
             if tenant_id.is_none() && !is_ignored_tenant {
                 // Tenant 为空，返回错误信息
                 return Err(ApiError::unauthenticated("No Tenant header").into_response());
@@ -84,9 +77,9 @@ impl AsyncAuthorizeRequest<Body> for ThreadLocalLayer {
                         );
                     }
                 } else {
-                    auth::check_tenant_id(tenant_id).await?;
+                    auth::check_tenant_id(&tenant_id).await?;
                 }
-                context.tenant_id = Some(String::from(tenant_id));
+                context.tenant_id = Some(tenant_id);
             };
             request.extensions_mut().insert(context);
             Ok(request)
@@ -94,19 +87,71 @@ impl AsyncAuthorizeRequest<Body> for ThreadLocalLayer {
     }
 }
 
-pub async fn get_auth_layer() -> &'static AsyncRequireAuthorizationLayer<ThreadLocalLayer> {
-    THREAD_LOCAL_LAYER
-        .get_or_init(async || AsyncRequireAuthorizationLayer::new(ThreadLocalLayer))
+/// 按 `AuthConfig::tenant_resolve_order` 依次解析租户编号
+///
+/// 忽略租户的地址不按域名解析，避免域名解析接口本身被递归解析
+async fn resolve_tenant_id(
+    headers: &HeaderMap,
+    auth_config: &AuthConfig,
+    is_ignored_tenant: bool,
+) -> Result<Option<String>, ApiError> {
+    for resolver in auth_config.tenant_resolve_order() {
+        let tenant_id = match resolver {
+            TenantResolver::Header => headers
+                .get(auth_config.header_key_tenant())
+                .map(|value| -> Result<_, ApiError> {
+                    let tenant_id = value.to_str().map_err(|_| {
+                        ApiError::unauthenticated("Tenant header value is not a string")
+                    })?;
+                    Ok(String::from(tenant_id))
+                })
+                .transpose()?,
+            TenantResolver::Host if is_ignored_tenant => None,
+            TenantResolver::Host => match request_host(headers) {
+                Some(host) => auth::get_tenant_id_by_website(&host).await?,
+                None => None,
+            },
+        };
+        if tenant_id.is_some() {
+            return Ok(tenant_id);
+        }
+    }
+    Ok(None)
+}
+
+/// 获取请求的域名，优先使用代理转发的 X-Forwarded-Host，去除端口并转为小写
+fn request_host(headers: &HeaderMap) -> Option<String> {
+    let host = headers
+        .get("x-forwarded-host")
+        .or_else(|| headers.get(header::HOST))?
+        .to_str()
+        .ok()?
+        .split(',')
+        .next()?
+        .trim()
+        .to_ascii_lowercase();
+    let host = match host.rsplit_once(':') {
+        Some((name, port)) if port.chars().all(|c| c.is_ascii_digit()) => name.to_string(),
+        _ => host,
+    };
+    (!host.is_empty()).then_some(host)
+}
+
+pub async fn get_auth_layer() -> &'static AsyncRequireAuthorizationLayer<RequestContextLayer> {
+    AUTH_LAYER
+        .get_or_init(async || AsyncRequireAuthorizationLayer::new(RequestContextLayer))
         .await
 }
 
-pub async fn thread_local_middleware(request: Request<Body>, next: Next) -> Response<Body> {
-    if let Some(context) = request.extensions().get::<HttpRequestContext>() {
-        HttpRequestContext::set_current(context.clone());
-    } else {
-        HttpRequestContext::set_current(HttpRequestContext::new());
-    }
-    let response = next.run(request).await;
-    HttpRequestContext::clear();
+/// 在请求上下文中执行后续处理，上下文只对本次请求的异步任务可见
+pub async fn request_context_middleware(request: Request<Body>, next: Next) -> Response<Body> {
+    let context = request
+        .extensions()
+        .get::<HttpRequestContext>()
+        .cloned()
+        .unwrap_or_default();
+    let mut response = context.clone().scope(next.run(request)).await;
+    // 带回到响应中，供外层的监控指标读取租户
+    response.extensions_mut().insert(context);
     response
 }
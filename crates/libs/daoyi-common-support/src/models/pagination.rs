@@ -1,15 +1,18 @@
 use crate::serde::deserialize_numer;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
 const DEFAULT_PAGE: u64 = 1;
 const DEFAULT_SIZE: u64 = 10;
 
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Validate)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Validate, JsonSchema)]
 pub struct PaginationParams {
+    /// 页码，从 1 开始
     #[validate(range(min = 1, message = "页码必须大于0"))]
     #[serde(default = "default_page", deserialize_with = "deserialize_numer")]
     pub page: u64,
+    /// 每页条数
     #[validate(range(min = 1, max = 100, message = "分页大小必须在1~100之间"))]
     #[serde(default = "default_size", deserialize_with = "deserialize_numer")]
     pub size: u64,
@@ -23,7 +26,7 @@ fn default_size() -> u64 {
     DEFAULT_SIZE
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Page<T> {
     page: u64,
@@ -40,11 +43,7 @@ impl<T> Page<T> {
             size,
             total,
             items,
-            total_page: if size == 0 {
-                0
-            } else {
-                total / size + if total % size == 0 { 0 } else { 1 }
-            },
+            total_page: if size == 0 { 0 } else { total.div_ceil(size) },
         }
     }
     pub fn from_pagination(pagination: PaginationParams, total: u64, items: Vec<T>) -> Self {
@@ -0,0 +1,216 @@
+//! 接口文档：由 `aide` 从路由、请求提取器与 `ApiResponse<T>` 响应生成 OpenAPI 3.1 文档
+//!
+//! 接口模块使用 `aide::axum::ApiRouter` 声明路由，服务启动时按 `openapi` 配置
+//! 在 `/v3/api-docs` 提供文档，并可选地在 `/swagger-ui` 提供 Swagger UI 页面
+
+use crate::app::AppState;
+use crate::configs::{AppConfig, AuthConfig, OpenApiConfig};
+use crate::error::ApiError;
+use crate::request::json::Json;
+use crate::request::path::Path;
+use crate::request::query::Query;
+use crate::request::valid::{ValidJson, ValidPath, ValidQuery};
+use crate::response::ApiResponse;
+use aide::generate::GenContext;
+use aide::openapi::{
+    ApiKeyLocation, Operation, PathItem, ReferenceOr, Response, SecurityRequirement, SecurityScheme,
+};
+use aide::operation::{OperationInput, OperationOutput};
+use aide::swagger::Swagger;
+use axum::Router;
+use axum::body::Bytes;
+use axum::http::header;
+use axum::response::Html;
+use schemars::JsonSchema;
+
+pub use aide::openapi::OpenApi;
+
+pub const API_DOCS_PATH: &str = "/v3/api-docs";
+pub const SWAGGER_UI_PATH: &str = "/swagger-ui";
+
+const TOKEN_SCHEME: &str = "token";
+const TENANT_SCHEME: &str = "tenant";
+
+/// 请求提取器按内部使用的 axum 提取器生成参数或请求体文档
+macro_rules! impl_operation_input {
+    ($name:ident, $via:ty) => {
+        impl<T: JsonSchema> OperationInput for $name<T> {
+            fn operation_input(ctx: &mut GenContext, operation: &mut Operation) {
+                <$via>::operation_input(ctx, operation);
+            }
+        }
+    };
+}
+
+impl_operation_input!(Json, axum::Json<T>);
+impl_operation_input!(Query, axum::extract::Query<T>);
+impl_operation_input!(Path, axum::extract::Path<T>);
+impl_operation_input!(ValidJson, axum::Json<T>);
+impl_operation_input!(ValidQuery, axum::extract::Query<T>);
+impl_operation_input!(ValidPath, axum::extract::Path<T>);
+
+impl<T: JsonSchema> OperationOutput for ApiResponse<T> {
+    type Inner = T;
+
+    fn operation_response(ctx: &mut GenContext, operation: &mut Operation) -> Option<Response> {
+        axum::Json::<Self>::operation_response(ctx, operation)
+    }
+
+    fn inferred_responses(
+        ctx: &mut GenContext,
+        operation: &mut Operation,
+    ) -> Vec<(Option<u16>, Response)> {
+        Self::operation_response(ctx, operation)
+            .map(|response| vec![(Some(200), response)])
+            .unwrap_or_default()
+    }
+}
+
+/// 业务异常的响应体为不带数据的 `ApiResponse`，状态码随异常类型变化，记为默认响应
+impl OperationOutput for ApiError {
+    type Inner = ApiResponse<()>;
+
+    fn operation_response(ctx: &mut GenContext, operation: &mut Operation) -> Option<Response> {
+        let mut response = axum::Json::<ApiResponse<()>>::operation_response(ctx, operation)?;
+        response.description = "业务异常".to_string();
+        Some(response)
+    }
+
+    fn inferred_responses(
+        ctx: &mut GenContext,
+        operation: &mut Operation,
+    ) -> Vec<(Option<u16>, Response)> {
+        Self::operation_response(ctx, operation)
+            .map(|response| vec![(None, response)])
+            .unwrap_or_default()
+    }
+}
+
+/// 生成接口文档，并按路径补充分组标签与认证要求
+pub fn finish_api(
+    router: aide::axum::ApiRouter<AppState>,
+    config: &AppConfig,
+) -> (Router<AppState>, OpenApi) {
+    let mut api = OpenApi::default();
+    let title = config.openapi().title().unwrap_or(config.app_name());
+    let router = router.finish_api_with(&mut api, |t| {
+        t.title(title)
+            .version(env!("CARGO_PKG_VERSION"))
+            .security_scheme(TOKEN_SCHEME, token_scheme(config.auth()))
+            .security_scheme(
+                TENANT_SCHEME,
+                SecurityScheme::ApiKey {
+                    location: ApiKeyLocation::Header,
+                    name: config.auth().header_key_tenant().to_string(),
+                    description: Some("租户编号".to_string()),
+                    extensions: Default::default(),
+                },
+            )
+    });
+    if let Some(paths) = api.paths.as_mut() {
+        for (path, item) in paths.paths.iter_mut() {
+            if let ReferenceOr::Item(item) = item {
+                decorate_path_item(path, item, config.auth());
+            }
+        }
+    }
+    (router, api)
+}
+
+/// 文档与 Swagger UI 的路由，不经过认证与租户校验
+pub fn docs_router(api: &OpenApi, config: &OpenApiConfig) -> Router<AppState> {
+    let json = Bytes::from(serde_json::to_vec(api).unwrap_or_default());
+    let mut router = Router::new().route(
+        API_DOCS_PATH,
+        axum::routing::get(async move || ([(header::CONTENT_TYPE, "application/json")], json)),
+    );
+    if config.swagger_ui() {
+        let title = api.info.title.clone();
+        let html = Swagger::new(API_DOCS_PATH).with_title(&title).html();
+        router = router.route(
+            SWAGGER_UI_PATH,
+            axum::routing::get(async move || Html(html)),
+        );
+    }
+    router
+}
+
+fn token_scheme(auth: &AuthConfig) -> SecurityScheme {
+    // 令牌请求头的值为 `Bearer <token>`，使用标准请求头时 Swagger UI 可自动补全前缀
+    if auth
+        .header_key_token()
+        .eq_ignore_ascii_case(header::AUTHORIZATION.as_str())
+    {
+        SecurityScheme::Http {
+            scheme: "bearer".to_string(),
+            bearer_format: None,
+            description: Some("访问令牌".to_string()),
+            extensions: Default::default(),
+        }
+    } else {
+        SecurityScheme::ApiKey {
+            location: ApiKeyLocation::Header,
+            name: auth.header_key_token().to_string(),
+            description: Some("访问令牌，格式为 Bearer <token>".to_string()),
+            extensions: Default::default(),
+        }
+    }
+}
+
+fn decorate_path_item(path: &str, item: &mut PathItem, auth: &AuthConfig) {
+    let tag = path_tag(path);
+    let mut requirement = SecurityRequirement::new();
+    if !auth.is_ignored_auth(path) {
+        requirement.insert(TOKEN_SCHEME.to_string(), vec![]);
+    }
+    if !auth.is_ignored_tenant(path) {
+        requirement.insert(TENANT_SCHEME.to_string(), vec![]);
+    }
+    let operations = [
+        &mut item.get,
+        &mut item.put,
+        &mut item.post,
+        &mut item.delete,
+        &mut item.patch,
+    ];
+    for operation in operations.into_iter().flatten() {
+        if let Some(tag) = &tag
+            && !operation.tags.contains(tag)
+        {
+            operation.tags.push(tag.clone());
+        }
+        if operation.security.is_empty() && !requirement.is_empty() {
+            operation.security.push(requirement.clone());
+        }
+    }
+}
+
+/// 按模块与业务分组，如 `/admin-api/system/dict-data/page` 的标签为 `system/dict-data`
+fn path_tag(path: &str) -> Option<String> {
+    let mut segments = path.split('/').filter(|s| !s.is_empty()).peekable();
+    segments.next_if(|s| s.ends_with("-api"));
+    let module = segments.next()?;
+    match segments.next() {
+        Some(business) if !business.starts_with('{') => Some(format!("{module}/{business}")),
+        _ => Some(module.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::path_tag;
+
+    #[test]
+    fn tag_by_module_and_business() {
+        assert_eq!(
+            path_tag("/admin-api/system/dict-data/page").as_deref(),
+            Some("system/dict-data")
+        );
+        assert_eq!(
+            path_tag("/app-api/demo/user/{id}").as_deref(),
+            Some("demo/user")
+        );
+        assert_eq!(path_tag("/admin-api/demo").as_deref(), Some("demo"));
+        assert_eq!(path_tag("/"), None);
+    }
+}
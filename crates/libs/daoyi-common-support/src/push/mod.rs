@@ -0,0 +1,72 @@
+//! 在线用户消息推送
+//!
+//! 基于进程内广播通道实现，用户通过 SSE 订阅后即视为在线；
+//! 消息按租户和用户过滤后下发，仅推送到当前实例上的在线用户。
+
+use axum::response::sse::Event;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::sync::{Arc, LazyLock};
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::{Stream, StreamExt};
+
+const CHANNEL_CAPACITY: usize = 1024;
+
+static PUSH_CHANNEL: LazyLock<broadcast::Sender<Arc<PushMessage>>> =
+    LazyLock::new(|| broadcast::channel(CHANNEL_CAPACITY).0);
+
+/// 推送消息，`user_ids` 为空时推送给租户下所有在线用户
+#[derive(Debug, Clone)]
+pub struct PushMessage {
+    pub tenant_id: String,
+    pub user_ids: Option<HashSet<String>>,
+    pub r#type: String,
+    pub content: String,
+}
+
+impl PushMessage {
+    pub fn new<T: Serialize>(
+        tenant_id: &str,
+        user_ids: Option<HashSet<String>>,
+        r#type: &str,
+        content: &T,
+    ) -> serde_json::Result<Self> {
+        Ok(Self {
+            tenant_id: String::from(tenant_id),
+            user_ids,
+            r#type: String::from(r#type),
+            content: serde_json::to_string(content)?,
+        })
+    }
+
+    fn is_target(&self, tenant_id: &str, user_id: &str) -> bool {
+        self.tenant_id == tenant_id
+            && self
+                .user_ids
+                .as_ref()
+                .is_none_or(|user_ids| user_ids.contains(user_id))
+    }
+}
+
+/// 推送消息，返回接收到消息的在线连接数
+pub fn publish(message: PushMessage) -> usize {
+    PUSH_CHANNEL.send(Arc::new(message)).unwrap_or(0)
+}
+
+/// 订阅指定租户用户的推送消息，转换为 SSE 事件流，事件名为消息类型
+pub fn subscribe(
+    tenant_id: String,
+    user_id: String,
+) -> impl Stream<Item = Result<Event, Infallible>> + Send + 'static {
+    BroadcastStream::new(PUSH_CHANNEL.subscribe()).filter_map(move |message| {
+        // 消费过慢导致的消息丢失直接忽略
+        let message = message.ok()?;
+        message.is_target(&tenant_id, &user_id).then(|| {
+            Ok(Event::default()
+                .event(&message.r#type)
+                .data(&message.content))
+        })
+    })
+}
@@ -1,30 +1,41 @@
 use crate::configs::AppConfig;
+use crate::configs::redis_config::RedisConfig;
 use crate::error::ApiResult;
 use crate::id;
-use deadpool_redis::redis::{AsyncCommands, FromRedisValue, ToRedisArgs};
+use crate::{metrics, telemetry};
+use deadpool_redis::redis::aio::PubSub;
+use deadpool_redis::redis::{AsyncCommands, Client, FromRedisValue, ToRedisArgs};
 use deadpool_redis::{Config, Connection, Pool, Runtime};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
+use std::time::Instant;
 use tokio::sync::OnceCell;
+use tracing::Instrument;
 
 static REDIS: OnceCell<Pool> = OnceCell::const_new();
 
 const CONNECTION_TEST_KEY: &str = "connection_test_key";
 
-async fn init() -> anyhow::Result<Pool> {
-    let redis_config = AppConfig::get().await.redis();
+async fn redis_url() -> String {
+    redis_url_of(AppConfig::get().await.redis())
+}
+
+fn redis_url_of(redis_config: &RedisConfig) -> String {
     let host = redis_config.host();
     let port = redis_config.port();
     let db = redis_config.database();
     let passwd = redis_config.password();
 
-    let url = if passwd.is_empty() {
+    if passwd.is_empty() {
         format!("redis://{host}:{port}/{db}")
     } else {
         format!("redis://:{passwd}@{host}:{port}/{db}")
-    };
+    }
+}
 
-    let cfg = Config::from_url(url);
+/// 按配置创建 Redis 连接池
+pub async fn connect(redis_config: &RedisConfig) -> anyhow::Result<Pool> {
+    let cfg = Config::from_url(redis_url_of(redis_config));
     let pool = cfg.create_pool(Some(Runtime::Tokio1))?;
 
     // 测试连接
@@ -38,10 +49,17 @@ async fn init() -> anyhow::Result<Pool> {
 
 /// 初始化Redis客户端
 pub async fn init_redis() -> anyhow::Result<()> {
-    REDIS.get_or_try_init(|| init()).await?;
+    REDIS
+        .get_or_try_init(async || connect(AppConfig::get().await.redis()).await)
+        .await?;
     Ok(())
 }
 
+/// 安装全局默认的 Redis 连接池，只有首次安装生效
+pub fn install(pool: Pool) {
+    let _ = REDIS.set(pool);
+}
+
 /// 获取Redis连接池实例
 fn get_pool() -> anyhow::Result<&'static Pool> {
     REDIS
@@ -74,6 +92,7 @@ where
     V: DeserializeOwned,
 {
     let json_str = get::<Option<String>>(key_generator(key).await.as_str()).await?;
+    metrics::record_cache(json_str.is_some());
     if json_str.is_none() {
         return Ok(None);
     }
@@ -96,6 +115,10 @@ where
     cache_set_ex(key, json_str, expire_seconds).await
 }
 
+pub async fn cache_del(key: &str) -> ApiResult<()> {
+    del(key_generator(key).await.as_ref()).await
+}
+
 pub async fn cache_get<V>(key: &str) -> ApiResult<Option<V>>
 where
     V: FromRedisValue + Send + Sync + 'static,
@@ -128,9 +151,11 @@ where
 /// 返回键对应的值，如果键不存在则返回错误
 #[allow(dead_code)]
 pub async fn get<T: FromRedisValue>(key: &str) -> ApiResult<T> {
-    let pool = get_pool()?;
-    let mut conn = pool.get().await?;
-    let result = conn.get(key).await?;
+    let mut conn = raw_connection().await?;
+    let result = conn
+        .get(key)
+        .instrument(telemetry::redis_span("GET", key))
+        .await?;
     Ok(result)
 }
 
@@ -145,9 +170,11 @@ pub async fn set_ex<V>(key: &str, value: V, seconds: u64) -> ApiResult<()>
 where
     V: ToRedisArgs + Send + Sync + 'static,
 {
-    let pool = get_pool()?;
-    let mut conn = pool.get().await?;
-    let _: () = conn.set_ex(key, value, seconds).await?;
+    let mut conn = raw_connection().await?;
+    let _: () = conn
+        .set_ex(key, value, seconds)
+        .instrument(telemetry::redis_span("SETEX", key))
+        .await?;
     Ok(())
 }
 
@@ -161,9 +188,11 @@ pub async fn set<V>(key: &str, value: V) -> ApiResult<()>
 where
     V: ToRedisArgs + Send + Sync + 'static,
 {
-    let pool = get_pool()?;
-    let mut conn = pool.get().await?;
-    let _: () = conn.set(key, value).await?;
+    let mut conn = raw_connection().await?;
+    let _: () = conn
+        .set(key, value)
+        .instrument(telemetry::redis_span("SET", key))
+        .await?;
     Ok(())
 }
 
@@ -173,9 +202,11 @@ where
 /// * `key` - 要删除的键
 #[allow(dead_code)]
 pub async fn del(key: &str) -> ApiResult<()> {
-    let pool = get_pool()?;
-    let mut conn = pool.get().await?;
-    let _: () = conn.del(key).await?;
+    let mut conn = raw_connection().await?;
+    let _: () = conn
+        .del(key)
+        .instrument(telemetry::redis_span("DEL", key))
+        .await?;
     Ok(())
 }
 
@@ -184,9 +215,11 @@ pub async fn del(key: &str) -> ApiResult<()> {
 /// # 参数
 /// * `key` - 要检查的键
 pub async fn exists(key: &str) -> ApiResult<bool> {
-    let pool = get_pool()?;
-    let mut conn = pool.get().await?;
-    let result = conn.exists(key).await?;
+    let mut conn = raw_connection().await?;
+    let result = conn
+        .exists(key)
+        .instrument(telemetry::redis_span("EXISTS", key))
+        .await?;
     Ok(result)
 }
 
@@ -200,18 +233,61 @@ pub async fn set_with_expire<V>(key: &str, value: V, seconds: u64) -> ApiResult<
 where
     V: ToRedisArgs + Send + Sync + 'static,
 {
-    let pool = get_pool()?;
-    let mut conn = pool.get().await?;
-    let _: () = conn.set_ex(key, value, seconds).await?;
+    let mut conn = raw_connection().await?;
+    let _: () = conn
+        .set_ex(key, value, seconds)
+        .instrument(telemetry::redis_span("SETEX", key))
+        .await?;
     Ok(())
 }
 
+/// 键不存在时设置键值对并指定过期时间，常用作分布式锁
+///
+/// # 返回值
+/// 设置成功返回 `true`，键已存在返回 `false`
+pub async fn set_nx_ex<V>(key: &str, value: V, seconds: u64) -> ApiResult<bool>
+where
+    V: ToRedisArgs + Send + Sync + 'static,
+{
+    let mut conn = raw_connection().await?;
+    let result: Option<String> = deadpool_redis::redis::cmd("SET")
+        .arg(key)
+        .arg(value)
+        .arg("NX")
+        .arg("EX")
+        .arg(seconds)
+        .query_async(&mut conn)
+        .instrument(telemetry::redis_span("SET", key))
+        .await?;
+    Ok(result.is_some())
+}
+
 /// 获取Redis的原始连接
 ///
 /// # 返回值
 /// 返回一个Redis连接
 pub async fn raw_connection() -> ApiResult<Connection> {
     let pool = get_pool()?;
-    let conn = pool.get().await?;
-    Ok(conn)
+    let start = Instant::now();
+    let conn = pool.get().await;
+    metrics::observe_redis_wait(start.elapsed(), conn.is_ok());
+    Ok(conn?)
+}
+
+/// 发布消息到频道
+pub async fn publish(channel: &str, message: &str) -> ApiResult<()> {
+    let mut conn = raw_connection().await?;
+    let _: () = conn
+        .publish(channel, message)
+        .instrument(telemetry::redis_span("PUBLISH", channel))
+        .await?;
+    Ok(())
+}
+
+/// 订阅频道，发布订阅需要独占连接，因此不使用连接池
+pub async fn subscribe(channel: &str) -> anyhow::Result<PubSub> {
+    let client = Client::open(redis_url().await)?;
+    let mut pubsub = client.get_async_pubsub().await?;
+    pubsub.subscribe(channel).await?;
+    Ok(pubsub)
 }
@@ -2,6 +2,7 @@ use crate::error::ApiError;
 use crate::request::json::Json;
 use crate::request::path::Path;
 use crate::request::query::Query;
+use crate::request::validation::ValidateDict;
 use axum::extract::{FromRequest, FromRequestParts, Request};
 use axum::http::request::Parts;
 
@@ -16,6 +17,44 @@ pub struct ValidPath<T>(pub T);
 #[derive(Debug, Clone, Default)]
 pub struct ValidJson<T>(pub T);
 
+/// `ValidJson`/`ValidQuery` 在常规校验通过后，额外执行基于字典数据的异步校验
+macro_rules! impl_from_request_with_dict {
+    ($name:ident, $wrapper: ident, FromRequestParts) => {
+        impl<S, T> FromRequestParts<S> for $name<T>
+        where
+            S: Send + Sync,
+            T: ValidateDict + Send + Sync,
+            Valid<$wrapper<T>>: FromRequestParts<S, Rejection = ApiError>,
+        {
+            type Rejection = ApiError;
+            async fn from_request_parts(
+                parts: &mut Parts,
+                state: &S,
+            ) -> Result<Self, Self::Rejection> {
+                let value = Valid::from_request_parts(parts, state).await?.0.0;
+                value.validate_dict().await?;
+                Ok($name(value))
+            }
+        }
+    };
+    ($name:ident, $wrapper: ident, FromRequest) => {
+        impl<S, T> FromRequest<S> for $name<T>
+        where
+            S: Send + Sync,
+            T: ValidateDict + Send + Sync,
+            Valid<$wrapper<T>>: FromRequest<S, Rejection = ApiError>,
+        {
+            type Rejection = ApiError;
+
+            async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+                let value = Valid::from_request(request, state).await?.0.0;
+                value.validate_dict().await?;
+                Ok($name(value))
+            }
+        }
+    };
+}
+
 macro_rules! impl_from_request {
     ($name:ident, $wrapper: ident, FromRequestParts) => {
         impl<S, T> FromRequestParts<S> for $name<T>
@@ -46,9 +85,9 @@ macro_rules! impl_from_request {
         }
     };
 }
-impl_from_request!(ValidQuery, Query, FromRequestParts);
+impl_from_request_with_dict!(ValidQuery, Query, FromRequestParts);
 impl_from_request!(ValidPath, Path, FromRequestParts);
-impl_from_request!(ValidJson, Json, FromRequest);
+impl_from_request_with_dict!(ValidJson, Json, FromRequest);
 
 // impl<S, T> FromRequestParts<S> for ValidQuery<T>
 // where
@@ -1,9 +1,12 @@
+use crate::dict;
+use crate::error::ApiResult;
+pub use daoyi_macros::ValidateDict;
 use regex::Regex;
 use std::borrow::Cow;
-use std::cell::LazyCell;
-use validator::ValidationError;
+use std::sync::LazyLock;
+pub use validator::{ValidationError, ValidationErrors};
 
-const MOBILE_PHONE_REGEX: LazyCell<Regex> = LazyCell::new(|| {
+static MOBILE_PHONE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"^(?:\+?86)?1[3456789]\d{9}$|^(?:\+?852)?[5-9]\d{3,7}$|^(?:\+?853)?6\d{7}$|^(?:\+?886)?9\d{8}$|^(?:\+?855)?[1-9]\d{7,9}$|^(?:\+?850)?[1-9]\d{7,9}$|^(?:\+?82)?1[0-9]{8,9}$|^(?:\+?81)?[789]0[0-9]{7}$|^(?:\+?65)?[89]\d{7}$|^(?:\+?60)?1[0-9]{8,9}$|^(?:\+?66)?[6-9]\d{7,8}$|^(?:\+?62)?8[1-9]\d{6,9}$|^(?:\+?63)?9[0-9]{9}$|^(?:\+?64)?[2-9]\d{7,9}$|^(?:\+?61)?4[0-9]{8}$|^(?:\+?33)?6[0-9]{8}$|^(?:\+?49)?1[57][0-9]{8}$|^(?:\+?34)?[67]\d{8}$|^(?:\+?39)?3[13457-9]\d{8}$|^(?:\+?44)?7[1-9]\d{8}$|^(?:\+?1)?[2-9][0-9]{2}[2-9][0-9]{2}[0-9]{4}$").expect("Failed to compile mobile phone regex")
 });
 pub fn is_mobile_phone(value: &str) -> Result<(), ValidationError> {
@@ -14,6 +17,74 @@ pub fn is_mobile_phone(value: &str) -> Result<(), ValidationError> {
     }
 }
 
+/// 基于字典数据的异步校验，由 `#[derive(ValidateDict)]` 根据字段上的
+/// `#[validate_dict("字典类型")]` 生成，在 `ValidJson`/`ValidQuery` 中于常规校验之后执行
+pub trait ValidateDict {
+    fn validate_dict(&self) -> impl Future<Output = ApiResult<()>> + Send;
+}
+
+/// 可参与字典校验的字段值，空值不参与校验
+pub trait DictValue {
+    fn dict_values(&self) -> Vec<String>;
+}
+
+impl DictValue for String {
+    fn dict_values(&self) -> Vec<String> {
+        vec![self.clone()]
+    }
+}
+
+impl DictValue for str {
+    fn dict_values(&self) -> Vec<String> {
+        vec![self.to_string()]
+    }
+}
+
+impl<T: DictValue> DictValue for Option<T> {
+    fn dict_values(&self) -> Vec<String> {
+        self.as_ref().map(|x| x.dict_values()).unwrap_or_default()
+    }
+}
+
+impl<T: DictValue> DictValue for Vec<T> {
+    fn dict_values(&self) -> Vec<String> {
+        self.iter().flat_map(|x| x.dict_values()).collect()
+    }
+}
+
+macro_rules! impl_dict_value_for_number {
+    ($($ty:ty),*) => {
+        $(
+            impl DictValue for $ty {
+                fn dict_values(&self) -> Vec<String> {
+                    vec![self.to_string()]
+                }
+            }
+        )*
+    };
+}
+impl_dict_value_for_number!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// 校验字段值是否为指定字典类型下开启的字典键值，不合法时返回校验错误
+pub async fn validate_dict_value<V: DictValue + ?Sized>(
+    value: &V,
+    dict_type: &str,
+) -> ApiResult<Option<ValidationError>> {
+    let values = value.dict_values();
+    if values.is_empty() {
+        return Ok(None);
+    }
+    let dict_values = dict::get_dict_values(dict_type).await?;
+    Ok(values
+        .into_iter()
+        .find(|x| !dict_values.contains(x))
+        .map(|x| ValidationError {
+            code: Cow::from("dict"),
+            message: Some(Cow::from(format!("值({x})不是字典({dict_type})的有效值"))),
+            params: Default::default(),
+        }))
+}
+
 fn build_validation_error(error: &'static str) -> ValidationError {
     ValidationError {
         code: Cow::from("invalid"),
@@ -1,10 +1,12 @@
 use crate::error::ApiError;
 use axum::response::IntoResponse;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 pub type RestApiResult<T> = Result<ApiResponse<T>, ApiError>;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// 统一响应结构，`code` 为 0 时表示成功
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
 pub struct ApiResponse<T> {
     pub code: i32,
     pub message: String,
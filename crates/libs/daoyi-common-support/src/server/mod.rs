@@ -1,12 +1,17 @@
+use crate::actuator;
 use crate::app::AppState;
 use crate::auth::Principal;
 use crate::configs::ServerConfig;
 use crate::error::ApiError;
+use crate::metrics;
 use crate::middlewares::simple_auth_layer;
 use crate::middlewares::trace_layer::LatencyOnResponse;
+use crate::openapi;
 use crate::response::RestApiResult;
+use crate::telemetry;
+use aide::axum::ApiRouter;
 use axum::extract::{DefaultBodyLimit, Request};
-use axum::http::StatusCode;
+use axum::http::{StatusCode, header};
 use axum::{Router, debug_handler, middleware, routing};
 use std::net::SocketAddr;
 use tokio::net::TcpListener;
@@ -24,7 +29,20 @@ impl Server {
         Server { config }
     }
 
-    pub async fn start(&self, state: AppState, router: Router<AppState>) -> anyhow::Result<()> {
+    pub async fn start(&self, state: AppState, router: ApiRouter<AppState>) -> anyhow::Result<()> {
+        let metrics_config = state.config().metrics();
+        if metrics_config.enabled()
+            && let Some(port) = metrics_config.port()
+        {
+            let listener = TcpListener::bind(format!("0.0.0.0:{port}")).await?;
+            tracing::info!("Metrics is listening on: http://127.0.0.1:{port}/metrics");
+            let router = metrics::create_router().with_state(state.clone());
+            tokio::spawn(async move {
+                if let Err(e) = axum::serve(listener, router).await {
+                    tracing::error!("Metrics server error: {}", e);
+                }
+            });
+        }
         let router = self.build_router(state, router).await;
         let port = self.config.port();
         let listener = TcpListener::bind(format!("0.0.0.0:{port}",)).await?;
@@ -58,10 +76,13 @@ impl Server {
             tracing::error!("Failed to close Redis connection pool: {}", e);
         }
 
+        // 导出尚未发送的链路
+        telemetry::shutdown().await;
+
         Ok(())
     }
 
-    async fn build_router(&self, state: AppState, router: Router<AppState>) -> Router {
+    async fn build_router(&self, state: AppState, router: ApiRouter<AppState>) -> Router {
         let timeout =
             TimeoutLayer::with_status_code(StatusCode::REQUEST_TIMEOUT, self.config.timeout());
         let body_limit = DefaultBodyLimit::max(self.config.max_body_size());
@@ -69,34 +90,61 @@ impl Server {
             .allow_origin(cors::Any)
             .allow_methods(cors::Any)
             .allow_headers(cors::Any)
+            .expose_headers([header::ETAG, telemetry::TRACE_ID_HEADER])
             .allow_credentials(false)
             .max_age(self.config.max_age());
         let tracing = TraceLayer::new_for_http()
             .make_span_with(|request: &Request| {
                 let method = request.method();
                 let path = request.uri().path();
-                let id = xid::new();
-                if let Some(principal) = request.extensions().get::<Principal>() {
-                    tracing::info_span!("Api request ", id = %id, method = %method, path = %path, user_id = %principal.id, user_name = %principal.name)
+                let span = if let Some(principal) = request.extensions().get::<Principal>() {
+                    tracing::info_span!("Api request ", trace_id = tracing::field::Empty, method = %method, path = %path, user_id = %principal.id, user_name = %principal.name)
                 } else {
-                    tracing::info_span!("Api request ", id = %id, method = %method, path = %path)
+                    tracing::info_span!("Api request ", trace_id = tracing::field::Empty, method = %method, path = %path)
+                };
+                // 沿用上游传入的链路，日志中的 trace_id 与导出的链路一致
+                telemetry::set_parent(&span, request.headers());
+                if let Some(trace_id) = telemetry::trace_id(&span) {
+                    span.record("trace_id", trace_id);
                 }
+                span
             })
             .on_request(())
             .on_failure(())
             .on_response(LatencyOnResponse);
         let normalize_path = NormalizePathLayer::trim_trailing_slash();
-        Router::new()
+        let openapi_config = state.config().openapi();
+        let (router, docs) = if openapi_config.enabled() {
+            let (router, api) = openapi::finish_api(router, state.config());
+            (router, openapi::docs_router(&api, openapi_config))
+        } else {
+            (router.into(), Router::new())
+        };
+        let metrics_config = state.config().metrics();
+        let mut router = Router::new()
             .route("/", routing::get(index))
             .merge(router)
             .layer(timeout)
             .layer(body_limit)
             .layer(normalize_path)
-            .layer(tracing)
             .layer(middleware::from_fn(
-                simple_auth_layer::thread_local_middleware,
+                simple_auth_layer::request_context_middleware,
             ))
             .route_layer(simple_auth_layer::get_auth_layer().await)
+            // 请求 span 包含认证过程，令牌与租户校验的远程调用也在同一链路中
+            .layer(middleware::from_fn(telemetry::trace_id_header))
+            .layer(tracing);
+        if metrics_config.enabled() {
+            // 在认证之外统计，被拒绝的请求同样计入
+            router = router.layer(middleware::from_fn(metrics::track_http));
+            if metrics_config.port().is_none() {
+                router = router.merge(metrics::create_router());
+            }
+        }
+        router
+            // 接口文档与健康检查在认证之后合并，无需令牌与租户即可访问
+            .merge(docs)
+            .merge(actuator::create_router())
             .fallback(async || -> RestApiResult<()> {
                 tracing::warn!("Not found");
                 Err(ApiError::NotFound)
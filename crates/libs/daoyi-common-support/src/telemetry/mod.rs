@@ -0,0 +1,174 @@
+//! 分布式链路追踪
+//!
+//! - 按 W3C Trace Context 解析入站请求的 `traceparent`，并在出站的 reqwest 请求中传递
+//! - 配置 `log.otlp_endpoint` 后以 OTLP（HTTP/protobuf）导出链路，未配置时仍生成链路编号用于日志关联
+//! - 请求、SeaORM 查询与 Redis 命令都会生成 span，响应头 `X-Trace-Id` 返回链路编号
+
+use crate::configs::AppConfig;
+use axum::extract::Request;
+use axum::http::{HeaderMap, HeaderName, HeaderValue};
+use axum::middleware::Next;
+use axum::response::Response;
+use opentelemetry::trace::{
+    Span as _, SpanKind, Status, TraceContextExt, Tracer as _, TracerProvider as _,
+};
+use opentelemetry::{KeyValue, global};
+use opentelemetry_http::{HeaderExtractor, HeaderInjector};
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{Sampler, SdkTracer, SdkTracerProvider};
+use std::sync::OnceLock;
+use std::time::SystemTime;
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+pub const TRACE_ID_HEADER: HeaderName = HeaderName::from_static("x-trace-id");
+
+static PROVIDER: OnceLock<SdkTracerProvider> = OnceLock::new();
+
+/// 创建链路追踪器并注册为全局实例，返回值用于创建 `tracing` 的 OpenTelemetry 层
+pub fn init(app_config: &AppConfig) -> anyhow::Result<SdkTracer> {
+    let log_config = app_config.log();
+    global::set_text_map_propagator(TraceContextPropagator::new());
+    let sampler = Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+        log_config.otlp_sample_ratio(),
+    )));
+    let mut builder = SdkTracerProvider::builder()
+        .with_sampler(sampler)
+        .with_resource(
+            Resource::builder()
+                .with_service_name(app_config.app_name().to_string())
+                .build(),
+        );
+    if let Some(endpoint) = log_config.otlp_endpoint() {
+        let exporter = SpanExporter::builder()
+            .with_http()
+            .with_endpoint(endpoint)
+            .build()?;
+        builder = builder.with_batch_exporter(exporter);
+    }
+    let provider = builder.build();
+    let tracer = provider.tracer(app_config.app_name().to_string());
+    global::set_tracer_provider(provider.clone());
+    let _ = PROVIDER.set(provider);
+    Ok(tracer)
+}
+
+/// 导出尚未发送的 span 并关闭链路追踪
+pub async fn shutdown() {
+    if let Some(provider) = PROVIDER.get().cloned() {
+        // 关闭时会同步等待导出完成，不占用异步运行时的工作线程
+        let result = tokio::task::spawn_blocking(move || provider.shutdown()).await;
+        if let Ok(Err(e)) = result {
+            tracing::error!("Failed to shutdown tracer provider: {}", e);
+        }
+    }
+}
+
+/// 以入站请求头中的 `traceparent` 作为 span 的上级
+pub fn set_parent(span: &Span, headers: &HeaderMap) {
+    let context =
+        global::get_text_map_propagator(|propagator| propagator.extract(&HeaderExtractor(headers)));
+    if context.span().span_context().is_valid() {
+        let _ = span.set_parent(context);
+    }
+}
+
+/// span 所属链路的编号，未启用链路追踪时为 `None`
+pub fn trace_id(span: &Span) -> Option<String> {
+    let context = span.context();
+    let span_context = context.span().span_context().clone();
+    span_context
+        .is_valid()
+        .then(|| span_context.trace_id().to_string())
+}
+
+/// 将当前 span 的链路信息写入出站请求头
+pub fn inject(headers: &mut HeaderMap) {
+    let context = Span::current().context();
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut HeaderInjector(headers))
+    });
+}
+
+/// 为出站的 reqwest 请求附加 `traceparent`
+pub fn traced(request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+    let mut headers = HeaderMap::new();
+    inject(&mut headers);
+    request.headers(headers)
+}
+
+/// 在响应头中返回链路编号，需要添加在请求 span 之内
+pub async fn trace_id_header(request: Request, next: Next) -> Response {
+    let trace_id = trace_id(&Span::current());
+    let mut response = next.run(request).await;
+    if let Some(value) = trace_id.and_then(|id| HeaderValue::from_str(&id).ok()) {
+        response.headers_mut().insert(TRACE_ID_HEADER, value);
+    }
+    response
+}
+
+/// 记录 SQL 执行的 span，通过 `DatabaseConnection::set_metric_callback` 在执行完成后回调，
+/// 按耗时回推开始时间；不在请求链路中的查询（如启动任务）不记录
+pub fn record_db_query(info: &sea_orm::metric::Info<'_>) {
+    let parent = Span::current().context();
+    if !parent.span().span_context().is_valid() {
+        return;
+    }
+    let end = SystemTime::now();
+    let tracer = global::tracer("sea-orm");
+    let mut span = tracer
+        .span_builder("db.query")
+        .with_kind(SpanKind::Client)
+        .with_start_time(end - info.elapsed)
+        .with_attributes([KeyValue::new("db.statement", info.statement.sql.clone())])
+        .start_with_context(&tracer, &parent);
+    if info.failed {
+        span.set_status(Status::error("query failed"));
+    }
+    span.end_with_timestamp(end);
+}
+
+/// Redis 命令的 span
+pub fn redis_span(command: &'static str, key: &str) -> Span {
+    tracing::info_span!(
+        "redis",
+        otel.name = command,
+        otel.kind = "client",
+        db.system = "redis",
+        key = key
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn propagate_traceparent() {
+        global::set_text_map_propagator(TraceContextPropagator::new());
+        let provider = SdkTracerProvider::builder().build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+        tracing::subscriber::with_default(subscriber, || {
+            let mut incoming = HeaderMap::new();
+            incoming.insert(
+                "traceparent",
+                HeaderValue::from_static("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"),
+            );
+            let span = tracing::info_span!("request");
+            set_parent(&span, &incoming);
+            assert_eq!(
+                trace_id(&span).as_deref(),
+                Some("4bf92f3577b34da6a3ce929d0e0e4736")
+            );
+            let mut outgoing = HeaderMap::new();
+            span.in_scope(|| inject(&mut outgoing));
+            let traceparent = outgoing["traceparent"].to_str().unwrap();
+            assert!(traceparent.starts_with("00-4bf92f3577b34da6a3ce929d0e0e4736-"));
+            assert!(!traceparent.contains("00f067aa0ba902b7"));
+        });
+    }
+}
@@ -0,0 +1,185 @@
+//! 消息模板引擎，供邮件、短信、站内信等模板共用
+//!
+//! 模板使用 `{name}` 形式的占位符，参数名由字母、数字和下划线组成，其它花括号原样保留
+
+use crate::error::{ApiError, ApiResult};
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, RwLock};
+
+/// 模版参数占位符，如 `{name}`
+static TEMPLATE_PARAM_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{(\w+)}").expect("Failed to compile template param regex"));
+
+/// 模板标识 -> (模板版本, 解析后的模板)
+type TemplateCache = HashMap<String, (String, Arc<Template>)>;
+
+/// 已解析的模板，按模板标识缓存，版本变化时重新解析
+static TEMPLATE_CACHE: LazyLock<RwLock<TemplateCache>> = LazyLock::new(Default::default);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Text(String),
+    Param(String),
+}
+
+/// 解析后的模板
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    segments: Vec<Segment>,
+    params: Vec<String>,
+}
+
+impl Template {
+    pub fn parse(source: &str) -> Self {
+        let mut segments = vec![];
+        let mut params: Vec<String> = vec![];
+        let mut last = 0;
+        for captures in TEMPLATE_PARAM_REGEX.captures_iter(source) {
+            let matched = captures.get(0).expect("capture group 0 always exists");
+            if matched.start() > last {
+                segments.push(Segment::Text(source[last..matched.start()].to_string()));
+            }
+            let param = captures[1].to_string();
+            if !params.contains(&param) {
+                params.push(param.clone());
+            }
+            segments.push(Segment::Param(param));
+            last = matched.end();
+        }
+        if last < source.len() {
+            segments.push(Segment::Text(source[last..].to_string()));
+        }
+        Self { segments, params }
+    }
+
+    /// 模板需要的参数名，按首次出现的顺序去重
+    pub fn params(&self) -> &[String] {
+        &self.params
+    }
+
+    /// 校验参数是否齐全，缺失时返回业务错误
+    pub fn validate_params(&self, params: &HashMap<String, String>) -> ApiResult<()> {
+        let missing = self
+            .params
+            .iter()
+            .filter(|x| !params.contains_key(*x))
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(ApiError::biz(format!(
+                "模板参数({})缺失",
+                missing.join(",")
+            )))
+        }
+    }
+
+    /// 使用参数渲染模板，参数缺失时报错，参数值原样输出
+    pub fn render(&self, params: &HashMap<String, String>) -> ApiResult<String> {
+        self.render_with(params, |x| x.to_string())
+    }
+
+    /// 使用参数渲染 HTML 模板，参数值会做 HTML 转义，模板本身的标签保持不变
+    pub fn render_html(&self, params: &HashMap<String, String>) -> ApiResult<String> {
+        self.render_with(params, escape_html)
+    }
+
+    fn render_with(
+        &self,
+        params: &HashMap<String, String>,
+        escape: impl Fn(&str) -> String,
+    ) -> ApiResult<String> {
+        self.validate_params(params)?;
+        let mut result = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Text(text) => result.push_str(text),
+                Segment::Param(param) => result.push_str(&escape(&params[param])),
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// 获取解析后的模板，`key` 标识模板，`version` 变化（如模板更新时间）时重新解析
+pub fn get_or_parse(key: &str, version: &str, source: &str) -> Arc<Template> {
+    if let Some((cached_version, template)) = TEMPLATE_CACHE
+        .read()
+        .expect("template cache poisoned")
+        .get(key)
+        && cached_version == version
+    {
+        return template.clone();
+    }
+    let template = Arc::new(Template::parse(source));
+    TEMPLATE_CACHE
+        .write()
+        .expect("template cache poisoned")
+        .insert(key.to_string(), (version.to_string(), template.clone()));
+    template
+}
+
+/// 解析多段模板（如标题与内容）需要的参数名，按出现顺序去重
+pub fn parse_params(sources: &[&str]) -> Vec<String> {
+    let mut params: Vec<String> = vec![];
+    for source in sources {
+        for param in Template::parse(source).params {
+            if !params.contains(&param) {
+                params.push(param);
+            }
+        }
+    }
+    params
+}
+
+/// 转义 HTML 特殊字符
+pub fn escape_html(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#39;"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_template() {
+        let template = Template::parse("{name}你好，你的订单{orderNo}已发货，{name}请注意查收{}");
+        assert_eq!(template.params(), ["name", "orderNo"]);
+        let mut params = HashMap::from([(String::from("name"), String::from("张三"))]);
+        assert_eq!(
+            template.render(&params).unwrap_err().to_string(),
+            ApiError::biz("模板参数(orderNo)缺失").to_string()
+        );
+        params.insert(String::from("orderNo"), String::from("<b>1</b>"));
+        assert_eq!(
+            template.render(&params).unwrap(),
+            "张三你好，你的订单<b>1</b>已发货，张三请注意查收{}"
+        );
+        assert_eq!(
+            template.render_html(&params).unwrap(),
+            "张三你好，你的订单&lt;b&gt;1&lt;/b&gt;已发货，张三请注意查收{}"
+        );
+        assert_eq!(parse_params(&["{a}{b}", "{b}{c}"]), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_template_cache_by_version() {
+        let v1 = get_or_parse("test", "1", "{a}");
+        assert!(Arc::ptr_eq(&v1, &get_or_parse("test", "1", "{ignored}")));
+        let v2 = get_or_parse("test", "2", "{b}");
+        assert_eq!(v2.params(), ["b"]);
+    }
+}
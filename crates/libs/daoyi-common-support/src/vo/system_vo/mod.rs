@@ -1,10 +1,17 @@
-use crate::enumeration::CommonStatusEnum;
-use crate::serde::datetime_format;
+use crate::enumeration::{
+    CommonStatusEnum, ConfigTypeEnum, FileStorageEnum, JobLogStatusEnum, JobStatusEnum,
+    LoginResultEnum, MailSendStatusEnum, NoticeTypeEnum, NotifyTemplateTypeEnum,
+};
+use crate::models::pagination::PaginationParams;
+use crate::request::validation::ValidateDict;
+use crate::serde::{datetime_format, deserialize_option_numer, option_datetime_format};
+use schemars::JsonSchema;
 use sea_orm::prelude::DateTime;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use validator::Validate;
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
 pub struct AuthLoginReqVO {
     #[validate(length(min = 4, max = 16, message = "账号长度为4-16"))]
     pub username: String,
@@ -12,16 +19,17 @@ pub struct AuthLoginReqVO {
     pub password: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AuthLoginRespVO {
     pub tenant_id: String,
     pub user_id: String,
     pub access_token: String,
     #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
     pub expires_time: DateTime,
 }
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TenantRespVO {
     pub id: String,
@@ -30,13 +38,13 @@ pub struct TenantRespVO {
     pub contact_name: String,
     pub contact_mobile: Option<String>,
     pub status: CommonStatusEnum,
-    pub websites: Option<String>,
+    pub websites: Vec<String>,
     pub package_id: String,
     pub expire_time: DateTime,
     pub account_count: i32,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DictDataSimpleRespVO {
     pub dict_type: String,
@@ -46,7 +54,7 @@ pub struct DictDataSimpleRespVO {
     pub css_class: Option<String>,
 }
 
-#[derive(Debug, Serialize, Default)]
+#[derive(Debug, Serialize, Default, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AuthPermissionInfoRespVO {
     pub user: UserVO,
@@ -55,7 +63,7 @@ pub struct AuthPermissionInfoRespVO {
     pub menus: Vec<MenuVO>,
 }
 
-#[derive(Debug, Serialize, Default)]
+#[derive(Debug, Serialize, Default, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UserVO {
     pub id: String,
@@ -64,9 +72,10 @@ pub struct UserVO {
     pub dept_id: Option<String>,
     pub username: String,
     pub email: Option<String>,
+    pub post_names: Vec<String>,
 }
 
-#[derive(Debug, Serialize, Default)]
+#[derive(Debug, Serialize, Default, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MenuVO {
     pub id: String,
@@ -82,3 +91,875 @@ pub struct MenuVO {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub children: Vec<MenuVO>,
 }
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PostSaveReqVO {
+    pub id: Option<String>,
+    #[validate(length(min = 1, max = 64, message = "岗位编码长度为1-64"))]
+    pub code: String,
+    #[validate(length(min = 1, max = 50, message = "岗位名称长度为1-50"))]
+    pub name: String,
+    pub sort: i32,
+    pub status: CommonStatusEnum,
+    #[validate(length(max = 500, message = "备注长度不能超过500"))]
+    pub remark: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PostPageReqVO {
+    pub code: Option<String>,
+    pub name: Option<String>,
+    pub status: Option<CommonStatusEnum>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub pagination: PaginationParams,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PostRespVO {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub sort: i32,
+    pub status: CommonStatusEnum,
+    pub remark: Option<String>,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub create_time: DateTime,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PostSimpleRespVO {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSaveReqVO {
+    pub id: Option<String>,
+    #[validate(length(min = 4, max = 30, message = "账号长度为4-30"))]
+    pub username: String,
+    #[validate(length(min = 4, max = 16, message = "密码长度为4-16"))]
+    pub password: Option<String>,
+    #[validate(length(max = 30, message = "用户昵称长度不能超过30"))]
+    pub nickname: String,
+    #[validate(length(max = 500, message = "备注长度不能超过500"))]
+    pub remark: Option<String>,
+    pub dept_id: Option<String>,
+    pub post_ids: Option<Vec<String>>,
+    #[validate(email(message = "邮箱格式不正确"))]
+    pub email: Option<String>,
+    #[validate(custom(function = "crate::request::validation::is_mobile_phone"))]
+    pub mobile: Option<String>,
+    #[validate_dict("system_user_sex")]
+    pub sex: Option<String>,
+    pub avatar: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserPageReqVO {
+    pub username: Option<String>,
+    pub mobile: Option<String>,
+    pub status: Option<CommonStatusEnum>,
+    pub dept_id: Option<String>,
+    pub post_id: Option<String>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub pagination: PaginationParams,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserRespVO {
+    pub id: String,
+    pub username: String,
+    pub nickname: String,
+    pub remark: Option<String>,
+    pub dept_id: Option<String>,
+    pub post_ids: Vec<String>,
+    pub post_names: Vec<String>,
+    pub email: Option<String>,
+    pub mobile: Option<String>,
+    pub sex: Option<String>,
+    pub avatar: Option<String>,
+    pub status: CommonStatusEnum,
+    pub login_ip: Option<String>,
+    pub login_date: Option<DateTime>,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub create_time: DateTime,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IdReqVO {
+    #[validate(length(min = 1, message = "编号不能为空"))]
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantPackageSaveReqVO {
+    pub id: Option<String>,
+    #[validate(length(min = 1, max = 30, message = "套餐名长度为1-30"))]
+    pub name: String,
+    pub status: CommonStatusEnum,
+    #[validate(length(max = 256, message = "备注长度不能超过256"))]
+    pub remark: Option<String>,
+    pub menu_ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantPackagePageReqVO {
+    pub name: Option<String>,
+    pub status: Option<CommonStatusEnum>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub pagination: PaginationParams,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantPackageRespVO {
+    pub id: String,
+    pub name: String,
+    pub status: CommonStatusEnum,
+    pub remark: Option<String>,
+    pub menu_ids: Vec<String>,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub create_time: DateTime,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantPackageSimpleRespVO {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionAssignRoleMenuReqVO {
+    #[validate(length(min = 1, message = "角色编号不能为空"))]
+    pub role_id: String,
+    pub menu_ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantSaveReqVO {
+    pub id: Option<String>,
+    #[validate(length(min = 1, max = 30, message = "租户名长度为1-30"))]
+    pub name: String,
+    #[validate(length(min = 1, max = 30, message = "联系人长度为1-30"))]
+    pub contact_name: String,
+    #[validate(custom(function = "crate::request::validation::is_mobile_phone"))]
+    pub contact_mobile: Option<String>,
+    pub status: CommonStatusEnum,
+    /// 绑定域名，支持 `*.example.com` 形式的通配子域名
+    #[serde(default)]
+    pub websites: Vec<String>,
+    #[validate(length(min = 1, message = "租户套餐编号不能为空"))]
+    pub package_id: String,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub expire_time: DateTime,
+    #[validate(range(min = 1, message = "账号数量必须大于0"))]
+    pub account_count: i32,
+    /// 租户管理员账号，仅创建时使用
+    #[validate(length(min = 4, max = 30, message = "账号长度为4-30"))]
+    pub username: Option<String>,
+    /// 租户管理员密码，仅创建时使用
+    #[validate(length(min = 4, max = 16, message = "密码长度为4-16"))]
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantPageReqVO {
+    pub name: Option<String>,
+    pub contact_name: Option<String>,
+    pub contact_mobile: Option<String>,
+    pub status: Option<CommonStatusEnum>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub pagination: PaginationParams,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantUpdateStatusReqVO {
+    #[validate(length(min = 1, message = "租户编号不能为空"))]
+    pub id: String,
+    pub status: CommonStatusEnum,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantUpdateExpireTimeReqVO {
+    #[validate(length(min = 1, message = "租户编号不能为空"))]
+    pub id: String,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub expire_time: DateTime,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DictTypeSaveReqVO {
+    pub id: Option<String>,
+    #[validate(length(min = 1, max = 100, message = "字典名称长度为1-100"))]
+    pub name: String,
+    #[validate(length(min = 1, max = 100, message = "字典类型长度为1-100"))]
+    pub r#type: String,
+    pub status: CommonStatusEnum,
+    #[validate(length(max = 500, message = "备注长度不能超过500"))]
+    pub remark: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DictTypePageReqVO {
+    pub name: Option<String>,
+    pub r#type: Option<String>,
+    pub status: Option<CommonStatusEnum>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub pagination: PaginationParams,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DictTypeRespVO {
+    pub id: String,
+    pub name: String,
+    pub r#type: String,
+    pub status: CommonStatusEnum,
+    pub remark: Option<String>,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub create_time: DateTime,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DictTypeSimpleRespVO {
+    pub id: String,
+    pub name: String,
+    pub r#type: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DictDataSaveReqVO {
+    pub id: Option<String>,
+    pub sort: i32,
+    #[validate(length(min = 1, max = 100, message = "字典标签长度为1-100"))]
+    pub label: String,
+    #[validate(length(min = 1, max = 100, message = "字典键值长度为1-100"))]
+    pub value: String,
+    #[validate(length(min = 1, max = 100, message = "字典类型长度为1-100"))]
+    pub dict_type: String,
+    pub status: CommonStatusEnum,
+    pub color_type: Option<String>,
+    pub css_class: Option<String>,
+    #[validate(length(max = 500, message = "备注长度不能超过500"))]
+    pub remark: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DictDataPageReqVO {
+    pub label: Option<String>,
+    pub dict_type: Option<String>,
+    pub status: Option<CommonStatusEnum>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub pagination: PaginationParams,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DictDataRespVO {
+    pub id: String,
+    pub sort: i32,
+    pub label: String,
+    pub value: String,
+    pub dict_type: String,
+    pub status: CommonStatusEnum,
+    pub color_type: Option<String>,
+    pub css_class: Option<String>,
+    pub remark: Option<String>,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub create_time: DateTime,
+}
+
+/// 字典数据快照，缓存在 Redis 中，`etag` 由快照内容计算，用于前端协商缓存
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DictDataSnapshotVO {
+    pub etag: String,
+    pub list: Vec<DictDataSimpleRespVO>,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NoticeSaveReqVO {
+    pub id: Option<String>,
+    #[validate(length(min = 1, max = 50, message = "公告标题长度为1-50"))]
+    pub title: String,
+    pub content: String,
+    pub r#type: NoticeTypeEnum,
+    pub status: CommonStatusEnum,
+    /// 通知的角色，与部门均为空时通知所有用户
+    #[serde(default)]
+    pub target_role_ids: Vec<String>,
+    /// 通知的部门
+    #[serde(default)]
+    pub target_dept_ids: Vec<String>,
+    /// 发布时间，为空时仅保存不发布，晚于当前时间时定时发布
+    #[serde(default, with = "option_datetime_format")]
+    #[schemars(with = "Option<String>")]
+    pub publish_time: Option<DateTime>,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NoticePageReqVO {
+    pub title: Option<String>,
+    pub r#type: Option<NoticeTypeEnum>,
+    pub status: Option<CommonStatusEnum>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub pagination: PaginationParams,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NoticeRespVO {
+    pub id: String,
+    pub title: String,
+    pub content: String,
+    pub r#type: NoticeTypeEnum,
+    pub status: CommonStatusEnum,
+    pub target_role_ids: Vec<String>,
+    pub target_dept_ids: Vec<String>,
+    #[serde(with = "option_datetime_format")]
+    #[schemars(with = "Option<String>")]
+    pub publish_time: Option<DateTime>,
+    pub pushed: bool,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub create_time: DateTime,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NoticeReadReqVO {
+    #[validate(length(min = 1, message = "公告编号不能为空"))]
+    pub ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyTemplateSaveReqVO {
+    pub id: Option<String>,
+    #[validate(length(min = 1, max = 63, message = "模版名称长度为1-63"))]
+    pub name: String,
+    #[validate(length(min = 1, max = 64, message = "模版编码长度为1-64"))]
+    pub code: String,
+    #[validate(length(min = 1, max = 255, message = "发送人名称长度为1-255"))]
+    pub nickname: String,
+    #[validate(length(min = 1, max = 1024, message = "模版内容长度为1-1024"))]
+    pub content: String,
+    pub r#type: NotifyTemplateTypeEnum,
+    pub status: CommonStatusEnum,
+    #[validate(length(max = 255, message = "备注长度不能超过255"))]
+    pub remark: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyTemplatePageReqVO {
+    pub name: Option<String>,
+    pub code: Option<String>,
+    pub status: Option<CommonStatusEnum>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub pagination: PaginationParams,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyTemplateRespVO {
+    pub id: String,
+    pub name: String,
+    pub code: String,
+    pub nickname: String,
+    pub content: String,
+    pub r#type: NotifyTemplateTypeEnum,
+    pub params: Vec<String>,
+    pub status: CommonStatusEnum,
+    pub remark: Option<String>,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub create_time: DateTime,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyTemplateSendReqVO {
+    #[validate(length(min = 1, message = "用户编号不能为空"))]
+    pub user_id: String,
+    #[validate(length(min = 1, message = "模板编码不能为空"))]
+    pub template_code: String,
+    #[serde(default)]
+    pub template_params: HashMap<String, String>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyMessageRespVO {
+    pub id: String,
+    pub user_id: String,
+    pub template_id: String,
+    pub template_code: String,
+    pub template_nickname: String,
+    pub template_content: String,
+    pub template_type: NotifyTemplateTypeEnum,
+    pub template_params: HashMap<String, String>,
+    pub read_status: bool,
+    #[serde(with = "option_datetime_format")]
+    #[schemars(with = "Option<String>")]
+    pub read_time: Option<DateTime>,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub create_time: DateTime,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyMessageMyPageReqVO {
+    #[serde(default, deserialize_with = "deserialize_option_numer")]
+    pub read_status: Option<bool>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub pagination: PaginationParams,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyMessageReadReqVO {
+    #[validate(length(min = 1, message = "站内信编号不能为空"))]
+    pub ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MailAccountSaveReqVO {
+    pub id: Option<String>,
+    #[validate(email(message = "邮箱格式不正确"))]
+    pub mail: String,
+    #[validate(length(max = 255, message = "用户名长度不能超过255"))]
+    pub username: Option<String>,
+    /// 密码，更新时为空表示不修改
+    pub password: Option<String>,
+    #[validate(length(min = 1, max = 255, message = "SMTP 服务器域名长度为1-255"))]
+    pub host: String,
+    #[validate(range(min = 1, max = 65535, message = "SMTP 服务器端口必须在1~65535之间"))]
+    pub port: i32,
+    pub ssl_enable: bool,
+    pub starttls_enable: bool,
+    #[validate(length(max = 255, message = "备注长度不能超过255"))]
+    pub remark: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MailAccountPageReqVO {
+    pub mail: Option<String>,
+    pub username: Option<String>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub pagination: PaginationParams,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MailAccountRespVO {
+    pub id: String,
+    pub mail: String,
+    pub username: Option<String>,
+    pub host: String,
+    pub port: i32,
+    pub ssl_enable: bool,
+    pub starttls_enable: bool,
+    pub remark: Option<String>,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub create_time: DateTime,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MailAccountSimpleRespVO {
+    pub id: String,
+    pub mail: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MailTemplateSaveReqVO {
+    pub id: Option<String>,
+    #[validate(length(min = 1, max = 63, message = "模版名称长度为1-63"))]
+    pub name: String,
+    #[validate(length(min = 1, max = 63, message = "模版编码长度为1-63"))]
+    pub code: String,
+    #[validate(length(min = 1, message = "邮箱账号不能为空"))]
+    pub account_id: String,
+    #[validate(length(max = 255, message = "发送人名称长度不能超过255"))]
+    pub nickname: Option<String>,
+    #[validate(length(min = 1, max = 255, message = "模版标题长度为1-255"))]
+    pub title: String,
+    #[validate(length(min = 1, message = "模版内容不能为空"))]
+    pub content: String,
+    pub status: CommonStatusEnum,
+    #[validate(length(max = 255, message = "备注长度不能超过255"))]
+    pub remark: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MailTemplatePageReqVO {
+    pub name: Option<String>,
+    pub code: Option<String>,
+    pub account_id: Option<String>,
+    pub status: Option<CommonStatusEnum>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub pagination: PaginationParams,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MailTemplateRespVO {
+    pub id: String,
+    pub name: String,
+    pub code: String,
+    pub account_id: String,
+    pub nickname: Option<String>,
+    pub title: String,
+    pub content: String,
+    pub params: Vec<String>,
+    pub status: CommonStatusEnum,
+    pub remark: Option<String>,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub create_time: DateTime,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MailTemplateSendReqVO {
+    #[validate(email(message = "接收邮箱格式不正确"))]
+    pub mail: String,
+    pub user_id: Option<String>,
+    #[validate(length(min = 1, message = "模板编码不能为空"))]
+    pub template_code: String,
+    #[serde(default)]
+    pub template_params: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MailLogPageReqVO {
+    pub user_id: Option<String>,
+    pub to_mail: Option<String>,
+    pub account_id: Option<String>,
+    pub template_id: Option<String>,
+    pub send_status: Option<MailSendStatusEnum>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub pagination: PaginationParams,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MailLogRespVO {
+    pub id: String,
+    pub user_id: Option<String>,
+    pub to_mail: String,
+    pub account_id: String,
+    pub from_mail: String,
+    pub template_id: String,
+    pub template_code: String,
+    pub template_nickname: Option<String>,
+    pub template_title: String,
+    pub template_content: String,
+    pub template_params: HashMap<String, String>,
+    pub send_status: MailSendStatusEnum,
+    #[serde(with = "option_datetime_format")]
+    #[schemars(with = "Option<String>")]
+    pub send_time: Option<DateTime>,
+    pub send_message_id: Option<String>,
+    pub send_exception: Option<String>,
+    pub retry_count: i32,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub create_time: DateTime,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FileConfigSaveReqVO {
+    pub id: Option<String>,
+    #[validate(length(min = 1, max = 63, message = "配置名长度为1-63"))]
+    pub name: String,
+    pub storage: FileStorageEnum,
+    /// 存储配置，结构取决于存储器类型
+    pub config: serde_json::Value,
+    #[validate(length(max = 255, message = "备注长度不能超过255"))]
+    pub remark: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FileConfigPageReqVO {
+    pub name: Option<String>,
+    pub storage: Option<FileStorageEnum>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub pagination: PaginationParams,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FileConfigRespVO {
+    pub id: String,
+    pub name: String,
+    pub storage: FileStorageEnum,
+    pub config: serde_json::Value,
+    pub master: bool,
+    pub remark: Option<String>,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub create_time: DateTime,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FilePageReqVO {
+    pub name: Option<String>,
+    pub path: Option<String>,
+    pub r#type: Option<String>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub pagination: PaginationParams,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FileRespVO {
+    pub id: String,
+    pub config_id: String,
+    pub name: String,
+    pub path: String,
+    pub r#type: String,
+    pub size: i64,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub create_time: DateTime,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FilePresignedUrlReqVO {
+    #[validate(length(min = 1, message = "文件编号不能为空"))]
+    pub id: String,
+    /// 有效期（秒），默认 10 分钟
+    #[validate(range(min = 1, max = 604800, message = "有效期必须在1~604800秒之间"))]
+    pub expires: Option<u64>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FilePresignedUrlRespVO {
+    pub url: String,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub expire_time: DateTime,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigSaveReqVO {
+    pub id: Option<String>,
+    #[validate(length(min = 1, max = 50, message = "参数分类长度为1-50"))]
+    pub category: String,
+    #[validate(length(min = 1, max = 100, message = "参数名称长度为1-100"))]
+    pub name: String,
+    #[validate(length(min = 1, max = 100, message = "参数键名长度为1-100"))]
+    pub key: String,
+    #[validate(length(max = 500, message = "参数键值长度不能超过500"))]
+    pub value: String,
+    pub visible: bool,
+    #[validate(length(max = 255, message = "备注长度不能超过255"))]
+    pub remark: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigPageReqVO {
+    pub name: Option<String>,
+    pub key: Option<String>,
+    pub r#type: Option<ConfigTypeEnum>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub pagination: PaginationParams,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigRespVO {
+    pub id: String,
+    pub category: String,
+    pub name: String,
+    pub key: String,
+    pub value: String,
+    pub r#type: ConfigTypeEnum,
+    pub visible: bool,
+    pub remark: Option<String>,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub create_time: DateTime,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigKeyReqVO {
+    #[validate(length(min = 1, message = "参数键名不能为空"))]
+    pub key: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JobSaveReqVO {
+    pub id: Option<String>,
+    #[validate(length(min = 1, max = 63, message = "任务名称长度为1-63"))]
+    pub name: String,
+    #[validate(length(min = 1, max = 63, message = "处理器名称长度为1-63"))]
+    pub handler_name: String,
+    #[validate(length(max = 255, message = "处理器参数长度不能超过255"))]
+    pub handler_param: Option<String>,
+    #[validate(length(min = 1, max = 63, message = "CRON 表达式长度为1-63"))]
+    pub cron_expression: String,
+    #[validate(range(min = 0, max = 10, message = "重试次数必须在0~10之间"))]
+    pub retry_count: i32,
+    /// 重试间隔（毫秒）
+    #[validate(range(min = 0, max = 3600000, message = "重试间隔必须在0~3600000毫秒之间"))]
+    pub retry_interval: i32,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JobPageReqVO {
+    pub name: Option<String>,
+    pub handler_name: Option<String>,
+    pub status: Option<JobStatusEnum>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub pagination: PaginationParams,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JobRespVO {
+    pub id: String,
+    pub name: String,
+    pub handler_name: String,
+    pub handler_param: Option<String>,
+    pub cron_expression: String,
+    pub retry_count: i32,
+    pub retry_interval: i32,
+    pub status: JobStatusEnum,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub create_time: DateTime,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JobNextTimesReqVO {
+    #[validate(length(min = 1, message = "任务编号不能为空"))]
+    pub id: String,
+    #[validate(range(min = 1, max = 100, message = "次数必须在1~100之间"))]
+    pub count: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JobLogPageReqVO {
+    pub job_id: Option<String>,
+    pub handler_name: Option<String>,
+    pub status: Option<JobLogStatusEnum>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub pagination: PaginationParams,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JobLogRespVO {
+    pub id: String,
+    pub job_id: String,
+    pub handler_name: String,
+    pub handler_param: Option<String>,
+    pub execute_index: i32,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub begin_time: DateTime,
+    #[serde(with = "option_datetime_format")]
+    #[schemars(with = "Option<String>")]
+    pub end_time: Option<DateTime>,
+    /// 执行时长（毫秒）
+    pub duration: Option<i64>,
+    pub status: JobLogStatusEnum,
+    pub result: Option<String>,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub create_time: DateTime,
+}
+
+#[derive(Debug, Deserialize, Validate, ValidateDict, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginLogPageReqVO {
+    pub username: Option<String>,
+    pub user_ip: Option<String>,
+    pub result: Option<LoginResultEnum>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub pagination: PaginationParams,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginLogRespVO {
+    pub id: String,
+    pub user_id: Option<String>,
+    pub username: String,
+    pub result: LoginResultEnum,
+    pub user_ip: String,
+    pub user_agent: Option<String>,
+    #[serde(with = "datetime_format")]
+    #[schemars(with = "String")]
+    pub create_time: DateTime,
+}
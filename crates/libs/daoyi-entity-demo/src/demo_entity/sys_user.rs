@@ -2,10 +2,13 @@
 
 use daoyi_common_support::enumeration::Gender;
 use daoyi_macros::BeforeInsert;
+use schemars::JsonSchema;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize, BeforeInsert)]
+#[derive(
+    Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize, JsonSchema, BeforeInsert,
+)]
 #[sea_orm(schema_name = "demo", table_name = "sys_user")]
 #[serde(rename_all = "camelCase")]
 pub struct Model {
@@ -3,11 +3,26 @@
 pub mod prelude;
 
 pub mod system_access_token;
+pub mod system_config;
 pub mod system_dict_data;
 pub mod system_dict_type;
+pub mod system_file;
+pub mod system_file_config;
+pub mod system_job;
+pub mod system_job_log;
+pub mod system_login_log;
+pub mod system_mail_account;
+pub mod system_mail_log;
+pub mod system_mail_template;
 pub mod system_menu;
+pub mod system_notice;
+pub mod system_notice_read;
+pub mod system_notify_message;
+pub mod system_notify_template;
+pub mod system_post;
 pub mod system_role;
 pub mod system_role_menu;
 pub mod system_tenant;
+pub mod system_tenant_package;
 pub mod system_user_role;
 pub mod system_users;
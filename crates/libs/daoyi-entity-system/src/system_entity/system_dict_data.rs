@@ -1,7 +1,7 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.19
 
 use daoyi_common_support::enumeration::CommonStatusEnum;
-use daoyi_common_support::vo::system_vo::DictDataSimpleRespVO;
+use daoyi_common_support::vo::system_vo::{DictDataRespVO, DictDataSimpleRespVO};
 use daoyi_macros::{DaoyiActiveModelBehavior, daoyi_model};
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -38,4 +38,21 @@ impl From<Model> for DictDataSimpleRespVO {
             css_class: value.css_class,
         }
     }
-}
\ No newline at end of file
+}
+
+impl From<Model> for DictDataRespVO {
+    fn from(value: Model) -> Self {
+        Self {
+            id: value.id,
+            sort: value.sort,
+            label: value.label,
+            value: value.value,
+            dict_type: value.dict_type,
+            status: value.status,
+            color_type: value.color_type,
+            css_class: value.css_class,
+            remark: value.remark,
+            create_time: value.create_time,
+        }
+    }
+}
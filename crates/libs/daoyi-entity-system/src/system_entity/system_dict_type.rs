@@ -1,6 +1,7 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.19
 
 use daoyi_common_support::enumeration::CommonStatusEnum;
+use daoyi_common_support::vo::system_vo::{DictTypeRespVO, DictTypeSimpleRespVO};
 use daoyi_macros::{DaoyiActiveModelBehavior, daoyi_model};
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -23,3 +24,26 @@ pub struct Model {
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {}
+
+impl From<Model> for DictTypeRespVO {
+    fn from(value: Model) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            r#type: value.r#type,
+            status: value.status,
+            remark: value.remark,
+            create_time: value.create_time,
+        }
+    }
+}
+
+impl From<Model> for DictTypeSimpleRespVO {
+    fn from(value: Model) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            r#type: value.r#type,
+        }
+    }
+}
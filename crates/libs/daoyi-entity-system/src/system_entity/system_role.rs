@@ -2,6 +2,7 @@
 
 use daoyi_common_support::enumeration::{CommonStatusEnum, DataScopeEnum, RoleTypeEnum};
 use daoyi_macros::{DaoyiActiveModelBehavior, daoyi_model};
+use daoyi_common_support::database::json_array::StringArray;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -18,7 +19,8 @@ pub struct Model {
     pub code: String,
     pub sort: i32,
     pub data_scope: DataScopeEnum,
-    pub data_scope_dept_ids: Vec<String>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub data_scope_dept_ids: StringArray,
     pub status: CommonStatusEnum,
     pub r#type: RoleTypeEnum,
     pub remark: Option<String>,
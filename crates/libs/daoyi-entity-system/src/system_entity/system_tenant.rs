@@ -2,6 +2,7 @@
 
 use daoyi_common_support::vo::system_vo::TenantRespVO;
 use daoyi_macros::{daoyi_model, DaoyiActiveModelBehavior};
+use daoyi_common_support::database::json_array::StringArray;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 use daoyi_common_support::enumeration::CommonStatusEnum;
@@ -20,7 +21,8 @@ pub struct Model {
     pub contact_name: String,
     pub contact_mobile: Option<String>,
     pub status: CommonStatusEnum,
-    pub websites: Option<String>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub websites: StringArray,
     pub package_id: String,
     pub expire_time: DateTime,
     pub account_count: i32,
@@ -38,7 +40,7 @@ impl From<Model> for TenantRespVO {
             contact_name: value.contact_name,
             contact_mobile: value.contact_mobile,
             status: value.status,
-            websites: value.websites,
+            websites: value.websites.into(),
             package_id: value.package_id,
             expire_time: value.expire_time,
             account_count: value.account_count,
@@ -1,8 +1,9 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.19
 
 use daoyi_common_support::enumeration::CommonStatusEnum;
-use daoyi_common_support::vo::system_vo::UserVO;
+use daoyi_common_support::vo::system_vo::{UserRespVO, UserVO};
 use daoyi_macros::{daoyi_model, DaoyiActiveModelBehavior};
+use daoyi_common_support::database::json_array::StringArray;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -20,7 +21,8 @@ pub struct Model {
     pub nickname: String,
     pub remark: Option<String>,
     pub dept_id: Option<String>,
-    pub post_ids: Option<Vec<String>>,
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub post_ids: Option<StringArray>,
     pub email: Option<String>,
     pub mobile: Option<String>,
     pub sex: Option<String>,
@@ -42,6 +44,29 @@ impl From<Model> for UserVO {
             dept_id: value.dept_id,
             username: value.username,
             email: value.email,
+            post_names: vec![],
+        }
+    }
+}
+
+impl From<Model> for UserRespVO {
+    fn from(value: Model) -> Self {
+        Self {
+            id: value.id,
+            username: value.username,
+            nickname: value.nickname,
+            remark: value.remark,
+            dept_id: value.dept_id,
+            post_ids: value.post_ids.unwrap_or_default().into(),
+            post_names: vec![],
+            email: value.email,
+            mobile: value.mobile,
+            sex: value.sex,
+            avatar: value.avatar,
+            status: value.status,
+            login_ip: value.login_ip,
+            login_date: value.login_date,
+            create_time: value.create_time,
         }
     }
 }
@@ -1,9 +1,26 @@
 pub mod system_access_token_service;
+pub mod system_auth_service;
+pub mod system_config_service;
 pub mod system_dict_data_service;
+pub mod system_dict_sync_service;
 pub mod system_dict_type_service;
+pub mod system_file_config_service;
+pub mod system_file_service;
+pub mod system_job_service;
+pub mod system_login_log_service;
+pub mod system_mail_account_service;
+pub mod system_mail_log_service;
+pub mod system_mail_send_service;
+pub mod system_mail_template_service;
 pub mod system_menu_service;
+pub mod system_notice_service;
+pub mod system_notify_message_service;
+pub mod system_notify_send_service;
+pub mod system_notify_template_service;
+pub mod system_post_service;
 pub mod system_role_menu_service;
 pub mod system_role_service;
+pub mod system_tenant_package_service;
 pub mod system_tenant_service;
 pub mod system_user_role_service;
 pub mod system_users_service;
@@ -7,8 +7,8 @@ use daoyi_common_support::error::{ApiError, ApiResult};
 use daoyi_common_support::vo::system_vo::AuthLoginRespVO;
 use daoyi_common_support::{database, redis_utils};
 use sea_orm::entity::prelude::*;
-use sea_orm::sqlx::types::chrono::Local;
-use sea_orm::Set;
+use chrono::Local;
+use sea_orm::{IntoActiveModel, Set};
 
 pub async fn get_access_token(token: &str) -> ApiResult<system_access_token::Model> {
     let db = database::get().await;
@@ -45,7 +45,7 @@ pub async fn create_token_after_login_success(
 ) -> ApiResult<AuthLoginRespVO> {
     let access_token = loop {
         let token = xid::new().to_string();
-        if let Err(_) = get_access_token(&token).await {
+        if get_access_token(&token).await.is_err() {
             break token;
         }
     };
@@ -53,13 +53,39 @@ pub async fn create_token_after_login_success(
     context.token = Some(access_token.clone());
     context.login_id = Some(String::from(login_id));
     context.tenant_id = Some(String::from(tenant_id));
-    HttpRequestContext::set_current(context);
     let token_expiration = AppConfig::get().await.auth().token_expiration();
     let db = database::get().await;
     let mut active_model = system_access_token::ActiveModel::new();
     active_model.user_id = Set(String::from(login_id));
     active_model.access_token = Set(access_token);
     active_model.expires_time = Set(Local::now().naive_local() + token_expiration);
-    let model = active_model.insert(db).await?;
+    // 以登录用户的身份写入令牌
+    let model = context.scope(active_model.insert(db)).await?;
     Ok(model.into())
 }
+
+/// 吊销租户下的全部访问令牌，并清除令牌缓存
+pub async fn revoke_tenant_tokens(tenant_id: &str) -> ApiResult<()> {
+    let db = database::get().await;
+    let tokens = SystemAccessToken::find_ignore_tenant()
+        .filter(system_access_token::Column::TenantId.eq(tenant_id))
+        .all(db)
+        .await?;
+    for token in tokens {
+        redis_utils::cache_del(&RedisKey::CheckToken.key(&token.access_token)).await?;
+        let mut active_model = token.into_active_model();
+        active_model.deleted = Set(true);
+        active_model.update(db).await?;
+    }
+    Ok(())
+}
+
+/// 清理已过期的访问令牌，返回清理数量，供定时任务调用
+pub async fn purge_expired_access_tokens() -> ApiResult<u64> {
+    let db = database::get().await;
+    let result = SystemAccessToken::delete_many()
+        .filter(system_access_token::Column::ExpiresTime.lt(Local::now().naive_local()))
+        .exec(db)
+        .await?;
+    Ok(result.rows_affected)
+}
@@ -0,0 +1,92 @@
+use crate::system_entity::prelude::*;
+use crate::system_entity::{system_tenant, system_users};
+use crate::system_service::system_login_log_service::{self, LoginClient};
+use crate::system_service::{
+    system_access_token_service, system_tenant_service, system_users_service,
+};
+use daoyi_common_support::context::HttpRequestContext;
+use daoyi_common_support::database;
+use daoyi_common_support::enumeration::{CommonStatusEnum, LoginResultEnum};
+use daoyi_common_support::error::{ApiError, ApiResult};
+use daoyi_common_support::password::verify_password;
+use daoyi_common_support::vo::system_vo::{AuthLoginReqVO, AuthLoginRespVO};
+use sea_orm::entity::prelude::*;
+use chrono::Local;
+
+/// 登录失败时统一返回给客户端的提示，具体原因只记录在登录日志中
+const LOGIN_FAILED_MESSAGE: &str = "账号或密码不正确";
+
+/// 登录被拒绝的原因及已识别出的用户编号
+type LoginRejection = (LoginResultEnum, Option<String>);
+
+/// 账号密码登录：依次校验租户、用户状态与密码，成功后更新登录信息，并记录登录日志
+pub async fn login(req: AuthLoginReqVO, client: LoginClient) -> ApiResult<AuthLoginRespVO> {
+    match authenticate(&req).await? {
+        Ok(user) => {
+            let (tenant_id, user_id) = (user.tenant_id.clone(), user.id.clone());
+            system_users_service::update_user_login(user, &client.user_ip).await?;
+            system_login_log_service::create_login_log(
+                &req.username,
+                Some(user_id.clone()),
+                LoginResultEnum::Success,
+                &client,
+            )
+            .await?;
+            system_access_token_service::create_token_after_login_success(&tenant_id, &user_id)
+                .await
+        }
+        Err((result, user_id)) => {
+            tracing::warn!("账号({})登录失败: {result:?}", req.username);
+            system_login_log_service::create_login_log(&req.username, user_id, result, &client)
+                .await?;
+            Err(ApiError::biz(LOGIN_FAILED_MESSAGE))
+        }
+    }
+}
+
+async fn authenticate(
+    req: &AuthLoginReqVO,
+) -> ApiResult<Result<system_users::Model, LoginRejection>> {
+    // 1. 校验租户
+    let Some(tenant_id) = HttpRequestContext::get_tenant_id().await else {
+        return Ok(Err((LoginResultEnum::TenantNotFound, None)));
+    };
+    if let Some(result) = check_login_tenant(&tenant_id).await? {
+        return Ok(Err((result, None)));
+    }
+    // 2. 校验用户状态
+    let Some(user) = system_users_service::get_by_username(&req.username).await? else {
+        return Ok(Err((LoginResultEnum::BadCredentials, None)));
+    };
+    if user.status == CommonStatusEnum::Disable {
+        return Ok(Err((LoginResultEnum::UserDisabled, Some(user.id))));
+    }
+    // 3. 校验密码
+    if !verify_password(&req.password, &user.password).await? {
+        return Ok(Err((LoginResultEnum::BadCredentials, Some(user.id))));
+    }
+    Ok(Ok(user))
+}
+
+/// 校验登录租户，租户不可用时返回对应的登录结果
+async fn check_login_tenant(tenant_id: &str) -> ApiResult<Option<LoginResultEnum>> {
+    let Err(e) = system_tenant_service::check_tenant_id(tenant_id).await else {
+        return Ok(None);
+    };
+    // check_tenant_id 的错误不区分原因，按租户当前的状态归类
+    let db = database::get().await;
+    let tenant = SystemTenant::find_ignore_tenant()
+        .filter(system_tenant::Column::Id.eq(tenant_id))
+        .one(db)
+        .await?;
+    match tenant {
+        None => Ok(Some(LoginResultEnum::TenantNotFound)),
+        Some(tenant) if tenant.status == CommonStatusEnum::Disable => {
+            Ok(Some(LoginResultEnum::TenantDisabled))
+        }
+        Some(tenant) if tenant.expire_time <= Local::now().naive_local() => {
+            Ok(Some(LoginResultEnum::TenantExpired))
+        }
+        Some(_) => Err(e),
+    }
+}
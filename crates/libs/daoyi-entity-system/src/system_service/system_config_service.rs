@@ -0,0 +1,140 @@
+use crate::system_entity::prelude::*;
+use crate::system_entity::system_config;
+use daoyi_common_support::config;
+use daoyi_common_support::database;
+use daoyi_common_support::enumeration::ConfigTypeEnum;
+use daoyi_common_support::error::{ApiError, ApiResult};
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::vo::system_vo::{ConfigPageReqVO, ConfigRespVO, ConfigSaveReqVO};
+use sea_orm::entity::prelude::*;
+use sea_orm::{IntoActiveModel, QueryOrder, QueryTrait, Set};
+use std::collections::HashMap;
+
+pub async fn create_config(req: ConfigSaveReqVO) -> ApiResult<String> {
+    validate_config_key_unique(None, &req.key).await?;
+    let db = database::get().await;
+    let mut active_model = system_config::ActiveModel::new();
+    active_model.category = Set(req.category);
+    active_model.name = Set(req.name);
+    active_model.config_key = Set(req.key);
+    active_model.value = Set(req.value);
+    active_model.r#type = Set(ConfigTypeEnum::Custom);
+    active_model.visible = Set(req.visible);
+    active_model.remark = Set(req.remark);
+    let model = active_model.insert(db).await?;
+    config::notify_change(&model.tenant_id, &model.config_key).await?;
+    Ok(model.id)
+}
+
+pub async fn update_config(req: ConfigSaveReqVO) -> ApiResult<()> {
+    let id = req
+        .id
+        .as_deref()
+        .ok_or_else(|| ApiError::biz("参数配置编号不能为空"))?;
+    let existed = get_config_by_id(id).await?;
+    validate_config_key_unique(Some(id), &req.key).await?;
+    if existed.r#type == ConfigTypeEnum::System && existed.config_key != req.key {
+        return Err(ApiError::biz("不能修改类型为系统内置的参数键名"));
+    }
+    let old_key = existed.config_key.clone();
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.category = Set(req.category);
+    active_model.name = Set(req.name);
+    active_model.config_key = Set(req.key);
+    active_model.value = Set(req.value);
+    active_model.visible = Set(req.visible);
+    active_model.remark = Set(req.remark);
+    let model = active_model.update(db).await?;
+    if old_key != model.config_key {
+        config::notify_change(&model.tenant_id, &old_key).await?;
+    }
+    config::notify_change(&model.tenant_id, &model.config_key).await?;
+    Ok(())
+}
+
+pub async fn delete_config(id: &str) -> ApiResult<()> {
+    let existed = get_config_by_id(id).await?;
+    if existed.r#type == ConfigTypeEnum::System {
+        return Err(ApiError::biz("不能删除类型为系统内置的参数配置"));
+    }
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.deleted = Set(true);
+    let model = active_model.update(db).await?;
+    config::notify_change(&model.tenant_id, &model.config_key).await?;
+    Ok(())
+}
+
+pub async fn get_config_by_id(id: &str) -> ApiResult<system_config::Model> {
+    let db = database::get().await;
+    SystemConfig::find_perm()
+        .await
+        .filter(system_config::Column::Id.eq(id))
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::biz("参数配置不存在"))
+}
+
+pub async fn get_config_by_key(key: &str) -> ApiResult<Option<system_config::Model>> {
+    let db = database::get().await;
+    let model = SystemConfig::find_perm()
+        .await
+        .filter(system_config::Column::ConfigKey.eq(key))
+        .one(db)
+        .await?;
+    Ok(model)
+}
+
+/// 按键名查询参数值，作为 `config::get_param` 的加载器
+pub async fn load_param(key: String) -> ApiResult<Option<String>> {
+    Ok(get_config_by_key(&key).await?.map(|x| x.value))
+}
+
+pub async fn get_config_page(req: ConfigPageReqVO) -> ApiResult<Page<ConfigRespVO>> {
+    let db = database::get().await;
+    let paginator = SystemConfig::find_perm()
+        .await
+        .apply_if(req.name.as_ref(), |query, name| {
+            query.filter(system_config::Column::Name.contains(name))
+        })
+        .apply_if(req.key.as_ref(), |query, key| {
+            query.filter(system_config::Column::ConfigKey.contains(key))
+        })
+        .apply_if(req.r#type, |query, r#type| {
+            query.filter(system_config::Column::Type.eq(r#type))
+        })
+        .order_by_desc(system_config::Column::Id)
+        .paginate(db, req.pagination.size);
+    let total = paginator.num_items().await?;
+    let items = paginator
+        .fetch_page(req.pagination.page - 1)
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+    Ok(Page::from_pagination(req.pagination, total, items))
+}
+
+/// 获取当前租户对前端可见的参数，键名 -> 键值
+pub async fn get_visible_config_map() -> ApiResult<HashMap<String, String>> {
+    let db = database::get().await;
+    let map = SystemConfig::find_perm()
+        .await
+        .filter(system_config::Column::Visible.eq(true))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|x| (x.config_key, x.value))
+        .collect();
+    Ok(map)
+}
+
+async fn validate_config_key_unique(id: Option<&str>, key: &str) -> ApiResult<()> {
+    if let Some(existed) = get_config_by_key(key).await?
+        && id != Some(existed.id.as_str())
+    {
+        return Err(ApiError::biz(format!("参数配置 key({key})已存在")));
+    }
+    Ok(())
+}
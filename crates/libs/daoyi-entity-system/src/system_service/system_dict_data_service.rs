@@ -1,10 +1,77 @@
 use crate::system_entity::prelude::*;
 use crate::system_entity::system_dict_data;
+use crate::system_service::system_dict_type_service;
 use daoyi_common_support::database;
+use daoyi_common_support::dict;
 use daoyi_common_support::enumeration::CommonStatusEnum;
-use daoyi_common_support::error::ApiResult;
+use daoyi_common_support::error::{ApiError, ApiResult};
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::redis_utils;
+use daoyi_common_support::vo::system_vo::{
+    DictDataPageReqVO, DictDataRespVO, DictDataSaveReqVO, DictDataSimpleRespVO, DictDataSnapshotVO,
+};
 use sea_orm::entity::prelude::*;
-use sea_orm::{QueryOrder, QueryTrait};
+use sea_orm::{IntoActiveModel, PaginatorTrait, QueryOrder, QueryTrait, Set};
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+pub async fn create_dict_data(req: DictDataSaveReqVO) -> ApiResult<String> {
+    validate_dict_data_for_create_or_update(None, &req.dict_type, &req.value).await?;
+    let db = database::get().await;
+    let mut active_model = system_dict_data::ActiveModel::new();
+    active_model.sort = Set(req.sort);
+    active_model.label = Set(req.label);
+    active_model.value = Set(req.value);
+    active_model.dict_type = Set(req.dict_type);
+    active_model.status = Set(req.status);
+    active_model.color_type = Set(req.color_type);
+    active_model.css_class = Set(req.css_class);
+    active_model.remark = Set(req.remark);
+    let model = active_model.insert(db).await?;
+    evict_dict_data_snapshot().await?;
+    Ok(model.id)
+}
+
+pub async fn update_dict_data(req: DictDataSaveReqVO) -> ApiResult<()> {
+    let id = req
+        .id
+        .as_deref()
+        .ok_or_else(|| ApiError::biz("字典数据编号不能为空"))?;
+    let existed = get_dict_data_by_id(id).await?;
+    validate_dict_data_for_create_or_update(Some(id), &req.dict_type, &req.value).await?;
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.sort = Set(req.sort);
+    active_model.label = Set(req.label);
+    active_model.value = Set(req.value);
+    active_model.dict_type = Set(req.dict_type);
+    active_model.status = Set(req.status);
+    active_model.color_type = Set(req.color_type);
+    active_model.css_class = Set(req.css_class);
+    active_model.remark = Set(req.remark);
+    active_model.update(db).await?;
+    evict_dict_data_snapshot().await?;
+    Ok(())
+}
+
+pub async fn delete_dict_data(id: &str) -> ApiResult<()> {
+    let existed = get_dict_data_by_id(id).await?;
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.deleted = Set(true);
+    active_model.update(db).await?;
+    evict_dict_data_snapshot().await?;
+    Ok(())
+}
+
+pub async fn get_dict_data_by_id(id: &str) -> ApiResult<system_dict_data::Model> {
+    let db = database::get().await;
+    SystemDictData::find_perm()
+        .await
+        .filter(system_dict_data::Column::Id.eq(id))
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::biz("当前字典数据不存在"))
+}
 
 pub async fn get_dict_data_list(
     status: CommonStatusEnum,
@@ -23,3 +90,98 @@ pub async fn get_dict_data_list(
         .await?;
     Ok(list)
 }
+
+pub async fn get_dict_data_page(req: DictDataPageReqVO) -> ApiResult<Page<DictDataRespVO>> {
+    let db = database::get().await;
+    let paginator = SystemDictData::find_perm()
+        .await
+        .apply_if(req.label.as_ref(), |query, label| {
+            query.filter(system_dict_data::Column::Label.contains(label))
+        })
+        .apply_if(req.dict_type.as_ref(), |query, dict_type| {
+            query.filter(system_dict_data::Column::DictType.eq(dict_type))
+        })
+        .apply_if(req.status, |query, status| {
+            query.filter(system_dict_data::Column::Status.eq(status))
+        })
+        .order_by_asc(system_dict_data::Column::DictType)
+        .order_by_asc(system_dict_data::Column::Sort)
+        .paginate(db, req.pagination.size);
+    let total = paginator.num_items().await?;
+    let items = paginator
+        .fetch_page(req.pagination.page - 1)
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+    Ok(Page::from_pagination(req.pagination, total, items))
+}
+
+pub async fn count_dict_data_by_type(dict_type: &str) -> ApiResult<u64> {
+    let db = database::get().await;
+    let count = SystemDictData::find_perm()
+        .await
+        .filter(system_dict_data::Column::DictType.eq(dict_type))
+        .count(db)
+        .await?;
+    Ok(count)
+}
+
+/// 获取当前租户已开启字典数据的快照，优先从 Redis 读取，未命中时查库并回写
+pub async fn get_dict_data_snapshot() -> ApiResult<DictDataSnapshotVO> {
+    let redis_key = dict::snapshot_key().await;
+    if let Some(snapshot) = redis_utils::cache_get_json::<DictDataSnapshotVO>(&redis_key).await? {
+        return Ok(snapshot);
+    }
+    let list: Vec<DictDataSimpleRespVO> = get_dict_data_list(CommonStatusEnum::Enable, None)
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+    let snapshot = DictDataSnapshotVO {
+        etag: compute_etag(&list)?,
+        list,
+    };
+    redis_utils::cache_set_json(&redis_key, &snapshot).await?;
+    Ok(snapshot)
+}
+
+/// 字典类型或数据发生变更后，清除当前租户的字典数据快照
+pub async fn evict_dict_data_snapshot() -> ApiResult<()> {
+    redis_utils::cache_del(&dict::snapshot_key().await).await
+}
+
+/// 以快照内容的哈希值作为强 ETag，内容不变时 ETag 保持不变
+fn compute_etag(list: &[DictDataSimpleRespVO]) -> ApiResult<String> {
+    let content = serde_json::to_string(list)?;
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    Ok(format!("\"{:016x}\"", hasher.finish()))
+}
+
+/// 校验字典数据：字典类型存在且开启，同一字典类型下键值唯一
+async fn validate_dict_data_for_create_or_update(
+    id: Option<&str>,
+    dict_type: &str,
+    value: &str,
+) -> ApiResult<()> {
+    let dict_type_model = system_dict_type_service::get_dict_type_by_type(dict_type)
+        .await?
+        .ok_or_else(|| ApiError::biz("当前字典类型不存在"))?;
+    if dict_type_model.status != CommonStatusEnum::Enable {
+        return Err(ApiError::biz("字典类型不处于开启状态，不允许选择"));
+    }
+    let db = database::get().await;
+    let existed = SystemDictData::find_perm()
+        .await
+        .filter(system_dict_data::Column::DictType.eq(dict_type))
+        .filter(system_dict_data::Column::Value.eq(value))
+        .one(db)
+        .await?;
+    if let Some(existed) = existed
+        && id != Some(existed.id.as_str())
+    {
+        return Err(ApiError::biz("已经存在该值的字典数据"));
+    }
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use crate::system_entity::prelude::*;
+use crate::system_entity::{system_dict_data, system_dict_type, system_tenant};
+use daoyi_common_support::database;
+use daoyi_common_support::dict::{self, DictTypeDefinition};
+use daoyi_common_support::enumeration::{CommonStatusEnum, ENUM_DICTS};
+use daoyi_common_support::error::ApiResult;
+use daoyi_common_support::redis_utils;
+use sea_orm::entity::prelude::*;
+use sea_orm::{IntoActiveModel, Set, TransactionTrait};
+
+/// 将 `DaoyiDict` 枚举派生的字典同步到所有租户，启动时执行
+pub async fn sync_enum_dicts() -> ApiResult<()> {
+    let db = database::get().await;
+    let tenant_ids = SystemTenant::find_ignore_tenant()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|x: system_tenant::Model| x.id);
+    for tenant_id in tenant_ids {
+        let txn = db.begin().await?;
+        sync_enum_dicts_for_tenant(&txn, &tenant_id).await?;
+        txn.commit().await?;
+        redis_utils::cache_del(&dict::tenant_snapshot_key(Some(&tenant_id))).await?;
+    }
+    redis_utils::cache_del(&dict::tenant_snapshot_key(None)).await?;
+    Ok(())
+}
+
+/// 为指定租户同步枚举字典：字典类型与字典数据不存在则新增，存在则以枚举定义为准更新，
+/// 枚举中已移除的字典数据会被删除
+pub async fn sync_enum_dicts_for_tenant<C: ConnectionTrait>(
+    db: &C,
+    tenant_id: &str,
+) -> ApiResult<()> {
+    for definition in ENUM_DICTS {
+        upsert_dict_type(db, tenant_id, definition).await?;
+        upsert_dict_data(db, tenant_id, definition).await?;
+    }
+    Ok(())
+}
+
+async fn upsert_dict_type<C: ConnectionTrait>(
+    db: &C,
+    tenant_id: &str,
+    definition: &DictTypeDefinition,
+) -> ApiResult<()> {
+    let existed = SystemDictType::find_ignore_tenant()
+        .filter(system_dict_type::Column::TenantId.eq(tenant_id))
+        .filter(system_dict_type::Column::Type.eq(definition.dict_type))
+        .one(db)
+        .await?;
+    match existed {
+        Some(existed) if existed.name == definition.name => {}
+        Some(existed) => {
+            let mut active_model = existed.into_active_model();
+            active_model.name = Set(definition.name.to_string());
+            active_model.update(db).await?;
+        }
+        None => {
+            let mut active_model = system_dict_type::ActiveModel::new();
+            active_model.name = Set(definition.name.to_string());
+            active_model.r#type = Set(definition.dict_type.to_string());
+            active_model.status = Set(CommonStatusEnum::Enable);
+            active_model.tenant_id = Set(tenant_id.to_string());
+            active_model.insert(db).await?;
+        }
+    }
+    Ok(())
+}
+
+async fn upsert_dict_data<C: ConnectionTrait>(
+    db: &C,
+    tenant_id: &str,
+    definition: &DictTypeDefinition,
+) -> ApiResult<()> {
+    let existed_list = SystemDictData::find_ignore_tenant()
+        .filter(system_dict_data::Column::TenantId.eq(tenant_id))
+        .filter(system_dict_data::Column::DictType.eq(definition.dict_type))
+        .all(db)
+        .await?;
+    for existed in &existed_list {
+        if definition.data.iter().any(|x| x.value == existed.value) {
+            continue;
+        }
+        let mut active_model = existed.clone().into_active_model();
+        active_model.deleted = Set(true);
+        active_model.update(db).await?;
+    }
+    for data in definition.data {
+        let existed = existed_list.iter().find(|x| x.value == data.value);
+        let color_type = data.color_type.map(String::from);
+        let css_class = data.css_class.map(String::from);
+        match existed {
+            Some(existed)
+                if existed.label == data.label
+                    && existed.sort == data.sort
+                    && existed.color_type == color_type
+                    && existed.css_class == css_class => {}
+            Some(existed) => {
+                let mut active_model = existed.clone().into_active_model();
+                active_model.label = Set(data.label.to_string());
+                active_model.sort = Set(data.sort);
+                active_model.color_type = Set(color_type);
+                active_model.css_class = Set(css_class);
+                active_model.update(db).await?;
+            }
+            None => {
+                let mut active_model = system_dict_data::ActiveModel::new();
+                active_model.sort = Set(data.sort);
+                active_model.label = Set(data.label.to_string());
+                active_model.value = Set(data.value.to_string());
+                active_model.dict_type = Set(definition.dict_type.to_string());
+                active_model.status = Set(CommonStatusEnum::Enable);
+                active_model.color_type = Set(color_type);
+                active_model.css_class = Set(css_class);
+                active_model.tenant_id = Set(tenant_id.to_string());
+                active_model.insert(db).await?;
+            }
+        }
+    }
+    Ok(())
+}
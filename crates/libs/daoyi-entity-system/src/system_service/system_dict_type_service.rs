@@ -0,0 +1,144 @@
+use crate::system_entity::prelude::*;
+use crate::system_entity::{system_dict_data, system_dict_type};
+use crate::system_service::system_dict_data_service;
+use daoyi_common_support::database;
+use daoyi_common_support::error::{ApiError, ApiResult};
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::vo::system_vo::{DictTypePageReqVO, DictTypeRespVO, DictTypeSaveReqVO};
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::Expr;
+use chrono::Local;
+use sea_orm::{IntoActiveModel, QueryOrder, QueryTrait, Set, TransactionTrait};
+
+pub async fn create_dict_type(req: DictTypeSaveReqVO) -> ApiResult<String> {
+    validate_dict_type_unique(None, &req.name, &req.r#type).await?;
+    let db = database::get().await;
+    let mut active_model = system_dict_type::ActiveModel::new();
+    active_model.name = Set(req.name);
+    active_model.r#type = Set(req.r#type);
+    active_model.status = Set(req.status);
+    active_model.remark = Set(req.remark);
+    let model = active_model.insert(db).await?;
+    system_dict_data_service::evict_dict_data_snapshot().await?;
+    Ok(model.id)
+}
+
+/// 更新字典类型，类型标识变更时同步更新其下字典数据的类型
+pub async fn update_dict_type(req: DictTypeSaveReqVO) -> ApiResult<()> {
+    let id = req
+        .id
+        .as_deref()
+        .ok_or_else(|| ApiError::biz("字典类型编号不能为空"))?;
+    let existed = get_dict_type_by_id(id).await?;
+    validate_dict_type_unique(Some(id), &req.name, &req.r#type).await?;
+    let db = database::get().await;
+    let txn = db.begin().await?;
+    if existed.r#type != req.r#type {
+        SystemDictData::update_many()
+            .col_expr(system_dict_data::Column::DictType, Expr::value(&req.r#type))
+            .filter(system_dict_data::Column::DictType.eq(&existed.r#type))
+            .filter(system_dict_data::Column::TenantId.eq(&existed.tenant_id))
+            .filter(system_dict_data::Column::Deleted.eq(false))
+            .exec(&txn)
+            .await?;
+    }
+    let mut active_model = existed.into_active_model();
+    active_model.name = Set(req.name);
+    active_model.r#type = Set(req.r#type);
+    active_model.status = Set(req.status);
+    active_model.remark = Set(req.remark);
+    active_model.update(&txn).await?;
+    txn.commit().await?;
+    system_dict_data_service::evict_dict_data_snapshot().await?;
+    Ok(())
+}
+
+/// 删除字典类型，类型下仍有字典数据时不允许删除
+pub async fn delete_dict_type(id: &str) -> ApiResult<()> {
+    let existed = get_dict_type_by_id(id).await?;
+    if system_dict_data_service::count_dict_data_by_type(&existed.r#type).await? > 0 {
+        return Err(ApiError::biz("无法删除，该字典类型还有字典数据"));
+    }
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.deleted = Set(true);
+    active_model.deleted_time = Set(Some(Local::now().naive_local()));
+    active_model.update(db).await?;
+    system_dict_data_service::evict_dict_data_snapshot().await?;
+    Ok(())
+}
+
+pub async fn get_dict_type_by_id(id: &str) -> ApiResult<system_dict_type::Model> {
+    let db = database::get().await;
+    SystemDictType::find_perm()
+        .await
+        .filter(system_dict_type::Column::Id.eq(id))
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::biz("当前字典类型不存在"))
+}
+
+pub async fn get_dict_type_by_type(r#type: &str) -> ApiResult<Option<system_dict_type::Model>> {
+    let db = database::get().await;
+    let model = SystemDictType::find_perm()
+        .await
+        .filter(system_dict_type::Column::Type.eq(r#type))
+        .one(db)
+        .await?;
+    Ok(model)
+}
+
+pub async fn get_dict_type_list() -> ApiResult<Vec<system_dict_type::Model>> {
+    let db = database::get().await;
+    let list = SystemDictType::find_perm()
+        .await
+        .order_by_asc(system_dict_type::Column::Type)
+        .all(db)
+        .await?;
+    Ok(list)
+}
+
+pub async fn get_dict_type_page(req: DictTypePageReqVO) -> ApiResult<Page<DictTypeRespVO>> {
+    let db = database::get().await;
+    let paginator = SystemDictType::find_perm()
+        .await
+        .apply_if(req.name.as_ref(), |query, name| {
+            query.filter(system_dict_type::Column::Name.contains(name))
+        })
+        .apply_if(req.r#type.as_ref(), |query, r#type| {
+            query.filter(system_dict_type::Column::Type.contains(r#type))
+        })
+        .apply_if(req.status, |query, status| {
+            query.filter(system_dict_type::Column::Status.eq(status))
+        })
+        .order_by_desc(system_dict_type::Column::Id)
+        .paginate(db, req.pagination.size);
+    let total = paginator.num_items().await?;
+    let items = paginator
+        .fetch_page(req.pagination.page - 1)
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+    Ok(Page::from_pagination(req.pagination, total, items))
+}
+
+async fn validate_dict_type_unique(id: Option<&str>, name: &str, r#type: &str) -> ApiResult<()> {
+    let db = database::get().await;
+    let existed = SystemDictType::find_perm()
+        .await
+        .filter(system_dict_type::Column::Name.eq(name))
+        .one(db)
+        .await?;
+    if let Some(existed) = existed
+        && id != Some(existed.id.as_str())
+    {
+        return Err(ApiError::biz("已经存在该名字的字典类型"));
+    }
+    if let Some(existed) = get_dict_type_by_type(r#type).await?
+        && id != Some(existed.id.as_str())
+    {
+        return Err(ApiError::biz("已经存在该类型的字典类型"));
+    }
+    Ok(())
+}
@@ -0,0 +1,150 @@
+use crate::system_entity::prelude::*;
+use crate::system_entity::system_file_config;
+use daoyi_common_support::database;
+use daoyi_common_support::enumeration::FileStorageEnum;
+use daoyi_common_support::error::{ApiError, ApiResult};
+use daoyi_common_support::file::{self, FileStorage};
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::vo::system_vo::{
+    FileConfigPageReqVO, FileConfigRespVO, FileConfigSaveReqVO,
+};
+use sea_orm::entity::prelude::*;
+use sea_orm::{IntoActiveModel, QueryOrder, QueryTrait, Set, TransactionTrait};
+use std::sync::Arc;
+
+pub async fn create_file_config(req: FileConfigSaveReqVO) -> ApiResult<String> {
+    validate_storage_config(req.storage, &req.config)?;
+    let db = database::get().await;
+    let mut active_model = system_file_config::ActiveModel::new();
+    active_model.name = Set(req.name);
+    active_model.storage = Set(req.storage);
+    active_model.config = Set(req.config);
+    active_model.master = Set(false);
+    active_model.remark = Set(req.remark);
+    let model = active_model.insert(db).await?;
+    Ok(model.id)
+}
+
+pub async fn update_file_config(req: FileConfigSaveReqVO) -> ApiResult<()> {
+    let id = req
+        .id
+        .as_deref()
+        .ok_or_else(|| ApiError::biz("文件配置编号不能为空"))?;
+    let existed = get_file_config_by_id(id).await?;
+    validate_storage_config(req.storage, &req.config)?;
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.name = Set(req.name);
+    active_model.storage = Set(req.storage);
+    active_model.config = Set(req.config);
+    active_model.remark = Set(req.remark);
+    active_model.update(db).await?;
+    Ok(())
+}
+
+/// 设置主配置，上传文件时使用主配置，同一租户只有一个主配置
+pub async fn update_file_config_master(id: &str) -> ApiResult<()> {
+    let existed = get_file_config_by_id(id).await?;
+    let db = database::get().await;
+    let txn = db.begin().await?;
+    SystemFileConfig::update_many()
+        .col_expr(system_file_config::Column::Master, Expr::value(false))
+        .filter(system_file_config::Column::TenantId.eq(&existed.tenant_id))
+        .filter(system_file_config::Column::Master.eq(true))
+        .exec(&txn)
+        .await?;
+    let mut active_model = existed.into_active_model();
+    active_model.master = Set(true);
+    active_model.update(&txn).await?;
+    txn.commit().await?;
+    Ok(())
+}
+
+pub async fn delete_file_config(id: &str) -> ApiResult<()> {
+    let existed = get_file_config_by_id(id).await?;
+    if existed.master {
+        return Err(ApiError::biz("该文件配置为主配置，不允许删除"));
+    }
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.deleted = Set(true);
+    active_model.update(db).await?;
+    Ok(())
+}
+
+pub async fn get_file_config_by_id(id: &str) -> ApiResult<system_file_config::Model> {
+    let db = database::get().await;
+    SystemFileConfig::find_perm()
+        .await
+        .filter(system_file_config::Column::Id.eq(id))
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::biz("文件配置不存在"))
+}
+
+pub async fn get_master_file_config() -> ApiResult<system_file_config::Model> {
+    let db = database::get().await;
+    SystemFileConfig::find_perm()
+        .await
+        .filter(system_file_config::Column::Master.eq(true))
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::biz("未设置主文件配置，无法上传文件"))
+}
+
+pub async fn get_file_config_page(req: FileConfigPageReqVO) -> ApiResult<Page<FileConfigRespVO>> {
+    let db = database::get().await;
+    let paginator = SystemFileConfig::find_perm()
+        .await
+        .apply_if(req.name.as_ref(), |query, name| {
+            query.filter(system_file_config::Column::Name.contains(name))
+        })
+        .apply_if(req.storage, |query, storage| {
+            query.filter(system_file_config::Column::Storage.eq(storage))
+        })
+        .order_by_desc(system_file_config::Column::Id)
+        .paginate(db, req.pagination.size);
+    let total = paginator.num_items().await?;
+    let items = paginator
+        .fetch_page(req.pagination.page - 1)
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+    Ok(Page::from_pagination(req.pagination, total, items))
+}
+
+/// 测试文件配置：上传、下载并删除一个测试文件
+pub async fn test_file_config(id: &str) -> ApiResult<()> {
+    let config = get_file_config_by_id(id).await?;
+    let storage = get_file_storage(&config)?;
+    let path = file::generate_path("test.txt");
+    let content = b"daoyi file config test";
+    let result = async {
+        storage.upload(&path, content, "text/plain").await?;
+        anyhow::ensure!(
+            storage.download(&path).await? == content,
+            "下载的文件内容不一致"
+        );
+        storage.delete(&path).await
+    }
+    .await;
+    result.map_err(|e| ApiError::biz(format!("测试文件配置失败: {e:#}")))
+}
+
+/// 获取文件配置对应的存储器，配置更新后重新创建
+pub fn get_file_storage(config: &system_file_config::Model) -> ApiResult<Arc<dyn FileStorage>> {
+    file::get_or_create_storage(
+        &config.id,
+        &config.update_time.to_string(),
+        config.storage,
+        &config.config,
+    )
+    .map_err(|e| ApiError::biz(format!("文件配置({})不正确: {e:#}", config.name)))
+}
+
+fn validate_storage_config(storage: FileStorageEnum, config: &Json) -> ApiResult<()> {
+    file::create_storage("", storage, config)
+        .map(|_| ())
+        .map_err(|e| ApiError::biz(format!("存储配置不正确: {e:#}")))
+}
@@ -0,0 +1,153 @@
+use crate::system_entity::prelude::*;
+use crate::system_entity::{system_file, system_file_config};
+use crate::system_service::system_file_config_service;
+use daoyi_common_support::database;
+use daoyi_common_support::enumeration::redis_keys::RedisKey;
+use daoyi_common_support::error::{ApiError, ApiResult};
+use daoyi_common_support::file;
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::redis_utils;
+use daoyi_common_support::vo::system_vo::{
+    FilePageReqVO, FilePresignedUrlReqVO, FilePresignedUrlRespVO, FileRespVO,
+};
+use sea_orm::entity::prelude::*;
+use chrono::Local;
+use sea_orm::{IntoActiveModel, QueryOrder, QueryTrait, Set};
+use std::time::Duration;
+
+/// 临时下载地址的默认有效期
+const DEFAULT_PRESIGNED_EXPIRES: Duration = Duration::from_secs(600);
+/// 不支持预签名的存储器使用的临时下载地址，需要在认证与租户忽略地址中放行
+pub const FILE_TEMP_DOWNLOAD_URL: &str = "/admin-api/system/file/temp-download";
+
+/// 使用主文件配置上传文件，文件类型按内容识别
+pub async fn upload_file(name: &str, content: Vec<u8>) -> ApiResult<system_file::Model> {
+    file::check_file_size(content.len()).await?;
+    let config = system_file_config_service::get_master_file_config().await?;
+    let storage = system_file_config_service::get_file_storage(&config)?;
+    let content_type = file::detect_content_type(&content, name);
+    let path = file::generate_path(name);
+    storage.upload(&path, &content, &content_type).await?;
+    let db = database::get().await;
+    let mut active_model = system_file::ActiveModel::new();
+    active_model.config_id = Set(config.id);
+    active_model.name = Set(name.to_string());
+    active_model.path = Set(path);
+    active_model.r#type = Set(content_type);
+    active_model.size = Set(content.len() as i64);
+    let model = active_model.insert(db).await?;
+    Ok(model)
+}
+
+pub async fn delete_file(id: &str) -> ApiResult<()> {
+    let existed = get_file_by_id(id).await?;
+    let config = get_file_config(&existed.config_id).await?;
+    let storage = system_file_config_service::get_file_storage(&config)?;
+    storage.delete(&existed.path).await?;
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.deleted = Set(true);
+    active_model.update(db).await?;
+    Ok(())
+}
+
+pub async fn get_file_by_id(id: &str) -> ApiResult<system_file::Model> {
+    let db = database::get().await;
+    SystemFile::find_perm()
+        .await
+        .filter(system_file::Column::Id.eq(id))
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::biz("文件不存在"))
+}
+
+pub async fn get_file_page(req: FilePageReqVO) -> ApiResult<Page<FileRespVO>> {
+    let db = database::get().await;
+    let paginator = SystemFile::find_perm()
+        .await
+        .apply_if(req.name.as_ref(), |query, name| {
+            query.filter(system_file::Column::Name.contains(name))
+        })
+        .apply_if(req.path.as_ref(), |query, path| {
+            query.filter(system_file::Column::Path.contains(path))
+        })
+        .apply_if(req.r#type.as_ref(), |query, r#type| {
+            query.filter(system_file::Column::Type.contains(r#type))
+        })
+        .order_by_desc(system_file::Column::Id)
+        .paginate(db, req.pagination.size);
+    let total = paginator.num_items().await?;
+    let items = paginator
+        .fetch_page(req.pagination.page - 1)
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+    Ok(Page::from_pagination(req.pagination, total, items))
+}
+
+/// 下载文件，返回文件信息与内容
+pub async fn download_file(id: &str) -> ApiResult<(system_file::Model, Vec<u8>)> {
+    let file = get_file_by_id(id).await?;
+    let content = download_content(&file).await?;
+    Ok((file, content))
+}
+
+/// 获取文件的临时下载地址
+///
+/// 存储器支持预签名（如 S3）时直接返回预签名地址，否则生成有效期内的临时下载令牌
+pub async fn get_presigned_url(req: FilePresignedUrlReqVO) -> ApiResult<FilePresignedUrlRespVO> {
+    let file = get_file_by_id(&req.id).await?;
+    let config = get_file_config(&file.config_id).await?;
+    let storage = system_file_config_service::get_file_storage(&config)?;
+    let expires = req
+        .expires
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_PRESIGNED_EXPIRES);
+    let expire_time = Local::now().naive_local() + expires;
+    let url = match storage.presigned_url(&file.path, expires).await? {
+        Some(url) => url,
+        None => {
+            let token = xid::new().to_string();
+            redis_utils::set_ex(
+                &RedisKey::FileTempDownload.key(&token),
+                file.id,
+                expires.as_secs(),
+            )
+            .await?;
+            format!("{FILE_TEMP_DOWNLOAD_URL}/{token}")
+        }
+    };
+    Ok(FilePresignedUrlRespVO { url, expire_time })
+}
+
+/// 使用临时令牌下载文件，令牌在有效期内可重复使用
+pub async fn download_temp_file(token: &str) -> ApiResult<(system_file::Model, Vec<u8>)> {
+    let file_id: Option<String> = redis_utils::get(&RedisKey::FileTempDownload.key(token)).await?;
+    let file_id = file_id.ok_or_else(|| ApiError::biz("下载地址已失效"))?;
+    let db = database::get().await;
+    // 临时下载不携带租户信息，按文件编号查找
+    let file = SystemFile::find_ignore_tenant()
+        .filter(system_file::Column::Id.eq(file_id))
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::biz("文件不存在"))?;
+    let content = download_content(&file).await?;
+    Ok((file, content))
+}
+
+async fn download_content(file: &system_file::Model) -> ApiResult<Vec<u8>> {
+    let config = get_file_config(&file.config_id).await?;
+    let storage = system_file_config_service::get_file_storage(&config)?;
+    Ok(storage.download(&file.path).await?)
+}
+
+/// 按编号获取文件所属的配置，临时下载时没有租户上下文
+async fn get_file_config(config_id: &str) -> ApiResult<system_file_config::Model> {
+    let db = database::get().await;
+    SystemFileConfig::find_ignore_tenant()
+        .filter(system_file_config::Column::Id.eq(config_id))
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::biz("文件配置不存在"))
+}
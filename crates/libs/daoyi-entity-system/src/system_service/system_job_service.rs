@@ -0,0 +1,361 @@
+use crate::system_entity::prelude::*;
+use crate::system_entity::{system_job, system_job_log};
+use daoyi_common_support::context::HttpRequestContext;
+use daoyi_common_support::database;
+use daoyi_common_support::enumeration::{JobLogStatusEnum, JobStatusEnum};
+use daoyi_common_support::error::{ApiError, ApiResult};
+use daoyi_common_support::job;
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::vo::system_vo::{
+    JobLogPageReqVO, JobLogRespVO, JobNextTimesReqVO, JobPageReqVO, JobRespVO, JobSaveReqVO,
+};
+use sea_orm::entity::prelude::*;
+use chrono::Local;
+use sea_orm::{IntoActiveModel, QueryOrder, QueryTrait, Set};
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+
+/// 调度器检查到期任务的间隔
+const JOB_SCHEDULE_TICK: Duration = Duration::from_secs(1);
+/// 调度器重新加载任务的间隔，用于同步其它实例对任务的修改
+const JOB_RELOAD_INTERVAL: Duration = Duration::from_secs(30);
+/// 默认计算的后续触发次数
+const DEFAULT_NEXT_TIMES_COUNT: usize = 5;
+/// 任务日志中执行结果的最大长度
+const JOB_RESULT_MAX_LENGTH: usize = 4000;
+
+/// 本实例修改任务后通知调度器立即重新加载
+static JOB_RELOAD_NOTIFY: LazyLock<Notify> = LazyLock::new(Notify::new);
+
+pub async fn create_job(req: JobSaveReqVO) -> ApiResult<String> {
+    validate_job(&req)?;
+    let db = database::get().await;
+    let mut active_model = system_job::ActiveModel::new();
+    active_model.name = Set(req.name);
+    active_model.handler_name = Set(req.handler_name);
+    active_model.handler_param = Set(req.handler_param);
+    active_model.cron_expression = Set(req.cron_expression);
+    active_model.retry_count = Set(req.retry_count);
+    active_model.retry_interval = Set(req.retry_interval);
+    active_model.status = Set(JobStatusEnum::Normal);
+    let model = active_model.insert(db).await?;
+    JOB_RELOAD_NOTIFY.notify_one();
+    Ok(model.id)
+}
+
+pub async fn update_job(req: JobSaveReqVO) -> ApiResult<()> {
+    let id = req
+        .id
+        .as_deref()
+        .ok_or_else(|| ApiError::biz("任务编号不能为空"))?;
+    let existed = get_job_by_id(id).await?;
+    validate_job(&req)?;
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.name = Set(req.name);
+    active_model.handler_name = Set(req.handler_name);
+    active_model.handler_param = Set(req.handler_param);
+    active_model.cron_expression = Set(req.cron_expression);
+    active_model.retry_count = Set(req.retry_count);
+    active_model.retry_interval = Set(req.retry_interval);
+    active_model.update(db).await?;
+    JOB_RELOAD_NOTIFY.notify_one();
+    Ok(())
+}
+
+pub async fn delete_job(id: &str) -> ApiResult<()> {
+    let existed = get_job_by_id(id).await?;
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.deleted = Set(true);
+    active_model.update(db).await?;
+    JOB_RELOAD_NOTIFY.notify_one();
+    Ok(())
+}
+
+/// 暂停任务，暂停后调度器不再触发，仍可手动执行
+pub async fn pause_job(id: &str) -> ApiResult<()> {
+    update_job_status(id, JobStatusEnum::Normal, JobStatusEnum::Stop).await
+}
+
+/// 恢复已暂停的任务
+pub async fn resume_job(id: &str) -> ApiResult<()> {
+    update_job_status(id, JobStatusEnum::Stop, JobStatusEnum::Normal).await
+}
+
+async fn update_job_status(id: &str, from: JobStatusEnum, to: JobStatusEnum) -> ApiResult<()> {
+    let existed = get_job_by_id(id).await?;
+    if existed.status != from {
+        return Err(ApiError::biz(match to {
+            JobStatusEnum::Normal => "任务已开启，无需恢复",
+            JobStatusEnum::Stop => "任务已暂停，无需重复暂停",
+        }));
+    }
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.status = Set(to);
+    active_model.update(db).await?;
+    JOB_RELOAD_NOTIFY.notify_one();
+    Ok(())
+}
+
+/// 立即在后台执行一次任务，不受任务状态与调度时间影响，执行时沿用当前请求的上下文
+pub async fn trigger_job(id: &str) -> ApiResult<()> {
+    let existed = get_job_by_id(id).await?;
+    if job::get_handler(&existed.handler_name).is_none() {
+        return Err(handler_not_found(&existed.handler_name));
+    }
+    HttpRequestContext::spawn(async move {
+        if let Err(e) = execute_job(existed).await {
+            tracing::error!("手动执行任务失败: {e}");
+        }
+    });
+    Ok(())
+}
+
+pub async fn get_job_by_id(id: &str) -> ApiResult<system_job::Model> {
+    let db = database::get().await;
+    SystemJob::find_perm()
+        .await
+        .filter(system_job::Column::Id.eq(id))
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::biz("定时任务不存在"))
+}
+
+pub async fn get_job_page(req: JobPageReqVO) -> ApiResult<Page<JobRespVO>> {
+    let db = database::get().await;
+    let paginator = SystemJob::find_perm()
+        .await
+        .apply_if(req.name.as_ref(), |query, name| {
+            query.filter(system_job::Column::Name.contains(name))
+        })
+        .apply_if(req.handler_name.as_ref(), |query, handler_name| {
+            query.filter(system_job::Column::HandlerName.contains(handler_name))
+        })
+        .apply_if(req.status, |query, status| {
+            query.filter(system_job::Column::Status.eq(status))
+        })
+        .order_by_desc(system_job::Column::Id)
+        .paginate(db, req.pagination.size);
+    let total = paginator.num_items().await?;
+    let items = paginator
+        .fetch_page(req.pagination.page - 1)
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+    Ok(Page::from_pagination(req.pagination, total, items))
+}
+
+/// 计算任务从当前时间开始的后续触发时间
+pub async fn get_job_next_times(req: JobNextTimesReqVO) -> ApiResult<Vec<DateTime>> {
+    let existed = get_job_by_id(&req.id).await?;
+    job::next_fire_times(
+        &existed.cron_expression,
+        Local::now().naive_local(),
+        req.count.unwrap_or(DEFAULT_NEXT_TIMES_COUNT),
+    )
+}
+
+pub async fn get_job_log_by_id(id: &str) -> ApiResult<system_job_log::Model> {
+    let db = database::get().await;
+    SystemJobLog::find_perm()
+        .await
+        .filter(system_job_log::Column::Id.eq(id))
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::biz("任务日志不存在"))
+}
+
+pub async fn get_job_log_page(req: JobLogPageReqVO) -> ApiResult<Page<JobLogRespVO>> {
+    let db = database::get().await;
+    let paginator = SystemJobLog::find_perm()
+        .await
+        .apply_if(req.job_id.as_ref(), |query, job_id| {
+            query.filter(system_job_log::Column::JobId.eq(job_id))
+        })
+        .apply_if(req.handler_name.as_ref(), |query, handler_name| {
+            query.filter(system_job_log::Column::HandlerName.contains(handler_name))
+        })
+        .apply_if(req.status, |query, status| {
+            query.filter(system_job_log::Column::Status.eq(status))
+        })
+        .order_by_desc(system_job_log::Column::Id)
+        .paginate(db, req.pagination.size);
+    let total = paginator.num_items().await?;
+    let items = paginator
+        .fetch_page(req.pagination.page - 1)
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+    Ok(Page::from_pagination(req.pagination, total, items))
+}
+
+/// 调度中的任务及其下次触发时间
+struct ScheduledJob {
+    job: system_job::Model,
+    next_fire_time: DateTime,
+}
+
+/// 启动任务调度器，按 cron 表达式触发所有租户中开启的任务
+///
+/// 每个实例都运行调度器，同一次触发通过 Redis 锁保证只有一个实例执行
+pub fn start_job_scheduler() {
+    tokio::spawn(async {
+        let mut scheduled = HashMap::new();
+        let mut interval = tokio::time::interval(JOB_SCHEDULE_TICK);
+        let mut reload_at = Instant::now();
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {}
+                _ = JOB_RELOAD_NOTIFY.notified() => reload_at = Instant::now(),
+            }
+            if Instant::now() >= reload_at {
+                if let Err(e) = reload_scheduled_jobs(&mut scheduled).await {
+                    tracing::error!("加载定时任务失败: {e}");
+                }
+                reload_at = Instant::now() + JOB_RELOAD_INTERVAL;
+            }
+            fire_due_jobs(&mut scheduled);
+        }
+    });
+}
+
+/// 重新加载开启的任务，未修改的任务保留原有的下次触发时间
+async fn reload_scheduled_jobs(scheduled: &mut HashMap<String, ScheduledJob>) -> ApiResult<()> {
+    let db = database::get().await;
+    let jobs = SystemJob::find_ignore_tenant()
+        .filter(system_job::Column::Status.eq(JobStatusEnum::Normal))
+        .all(db)
+        .await?;
+    let now = Local::now().naive_local();
+    let mut reloaded = HashMap::with_capacity(jobs.len());
+    for job in jobs {
+        let next_fire_time = match scheduled.remove(&job.id) {
+            Some(existed) if existed.job.update_time == job.update_time => existed.next_fire_time,
+            _ => match next_fire_time(&job, now) {
+                Some(next_fire_time) => next_fire_time,
+                None => continue,
+            },
+        };
+        reloaded.insert(
+            job.id.clone(),
+            ScheduledJob {
+                job,
+                next_fire_time,
+            },
+        );
+    }
+    *scheduled = reloaded;
+    Ok(())
+}
+
+/// 触发已到期的任务，并计算其下次触发时间，错过的多次触发只执行一次
+fn fire_due_jobs(scheduled: &mut HashMap<String, ScheduledJob>) {
+    let now = Local::now().naive_local();
+    let mut finished = Vec::new();
+    for (id, item) in scheduled.iter_mut() {
+        if item.next_fire_time > now {
+            continue;
+        }
+        let fire_time = item.next_fire_time;
+        let job = item.job.clone();
+        tokio::spawn(async move {
+            if let Err(e) = fire_job(job, fire_time).await {
+                tracing::error!("执行定时任务失败: {e}");
+            }
+        });
+        match next_fire_time(&item.job, now) {
+            Some(next_fire_time) => item.next_fire_time = next_fire_time,
+            None => finished.push(id.clone()),
+        }
+    }
+    for id in finished {
+        scheduled.remove(&id);
+    }
+}
+
+fn next_fire_time(job: &system_job::Model, after: DateTime) -> Option<DateTime> {
+    match job::next_fire_times(&job.cron_expression, after, 1) {
+        Ok(times) => times.into_iter().next(),
+        Err(e) => {
+            tracing::error!("定时任务({})无法计算触发时间: {e}", job.id);
+            None
+        }
+    }
+}
+
+/// 抢到本次触发的执行权后，确认任务仍处于开启状态，再在任务所属租户的上下文中执行
+async fn fire_job(job: system_job::Model, fire_time: DateTime) -> ApiResult<()> {
+    if !job::try_acquire_trigger(&job.id, fire_time).await? {
+        return Ok(());
+    }
+    let db = database::get().await;
+    let latest = SystemJob::find_ignore_tenant()
+        .filter(system_job::Column::Id.eq(&job.id))
+        .one(db)
+        .await?;
+    match latest {
+        Some(latest) if latest.status == JobStatusEnum::Normal => {
+            let context = HttpRequestContext::tenant(latest.tenant_id.clone());
+            context.scope(execute_job(latest)).await
+        }
+        _ => Ok(()),
+    }
+}
+
+/// 执行任务并记录任务日志，日志归属任务所在的租户
+async fn execute_job(job: system_job::Model) -> ApiResult<()> {
+    let handler =
+        job::get_handler(&job.handler_name).ok_or_else(|| handler_not_found(&job.handler_name))?;
+    let db = database::get().await;
+    let mut active_model = system_job_log::ActiveModel::new();
+    active_model.tenant_id = Set(job.tenant_id.clone());
+    active_model.job_id = Set(job.id.clone());
+    active_model.handler_name = Set(job.handler_name.clone());
+    active_model.handler_param = Set(job.handler_param.clone());
+    active_model.execute_index = Set(1);
+    active_model.begin_time = Set(Local::now().naive_local());
+    active_model.status = Set(JobLogStatusEnum::Running);
+    let log = active_model.insert(db).await?;
+
+    let started = Instant::now();
+    let execution = job::execute(
+        handler,
+        job.handler_param,
+        job.retry_count.max(0) as u32,
+        Duration::from_millis(job.retry_interval.max(0) as u64),
+    )
+    .await;
+    let (status, result) = match execution.result {
+        Ok(result) => (JobLogStatusEnum::Success, result),
+        Err(e) => {
+            tracing::error!("定时任务({})执行失败: {e:#}", job.id);
+            (JobLogStatusEnum::Failure, format!("{e:#}"))
+        }
+    };
+    let mut active_model = log.into_active_model();
+    active_model.execute_index = Set(execution.attempts);
+    active_model.end_time = Set(Some(Local::now().naive_local()));
+    active_model.duration = Set(Some(started.elapsed().as_millis() as i64));
+    active_model.status = Set(status);
+    active_model.result = Set(Some(result.chars().take(JOB_RESULT_MAX_LENGTH).collect()));
+    active_model.update(db).await?;
+    Ok(())
+}
+
+fn validate_job(req: &JobSaveReqVO) -> ApiResult<()> {
+    if job::get_handler(&req.handler_name).is_none() {
+        return Err(handler_not_found(&req.handler_name));
+    }
+    job::parse_cron(&req.cron_expression)?;
+    Ok(())
+}
+
+fn handler_not_found(handler_name: &str) -> ApiError {
+    ApiError::biz(format!("任务处理器({handler_name})不存在"))
+}
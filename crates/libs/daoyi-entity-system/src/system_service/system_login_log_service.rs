@@ -0,0 +1,69 @@
+use crate::system_entity::prelude::*;
+use crate::system_entity::system_login_log;
+use daoyi_common_support::database;
+use daoyi_common_support::enumeration::LoginResultEnum;
+use daoyi_common_support::error::{ApiError, ApiResult};
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::vo::system_vo::{LoginLogPageReqVO, LoginLogRespVO};
+use sea_orm::entity::prelude::*;
+use sea_orm::{QueryOrder, QueryTrait, Set};
+
+/// 登录日志中记录的客户端信息
+#[derive(Debug, Clone)]
+pub struct LoginClient {
+    pub user_ip: String,
+    pub user_agent: Option<String>,
+}
+
+/// 记录一次登录结果，日志归属登录请求的租户
+pub async fn create_login_log(
+    username: &str,
+    user_id: Option<String>,
+    result: LoginResultEnum,
+    client: &LoginClient,
+) -> ApiResult<()> {
+    let db = database::get().await;
+    let mut active_model = system_login_log::ActiveModel::new();
+    active_model.user_id = Set(user_id);
+    active_model.username = Set(username.to_string());
+    active_model.result = Set(result);
+    active_model.user_ip = Set(client.user_ip.clone());
+    active_model.user_agent = Set(client.user_agent.clone());
+    active_model.insert(db).await?;
+    Ok(())
+}
+
+pub async fn get_login_log_by_id(id: &str) -> ApiResult<system_login_log::Model> {
+    let db = database::get().await;
+    SystemLoginLog::find_perm()
+        .await
+        .filter(system_login_log::Column::Id.eq(id))
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::biz("登录日志不存在"))
+}
+
+pub async fn get_login_log_page(req: LoginLogPageReqVO) -> ApiResult<Page<LoginLogRespVO>> {
+    let db = database::get().await;
+    let paginator = SystemLoginLog::find_perm()
+        .await
+        .apply_if(req.username.as_ref(), |query, username| {
+            query.filter(system_login_log::Column::Username.contains(username))
+        })
+        .apply_if(req.user_ip.as_ref(), |query, user_ip| {
+            query.filter(system_login_log::Column::UserIp.contains(user_ip))
+        })
+        .apply_if(req.result, |query, result| {
+            query.filter(system_login_log::Column::Result.eq(result))
+        })
+        .order_by_desc(system_login_log::Column::Id)
+        .paginate(db, req.pagination.size);
+    let total = paginator.num_items().await?;
+    let items = paginator
+        .fetch_page(req.pagination.page - 1)
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+    Ok(Page::from_pagination(req.pagination, total, items))
+}
@@ -0,0 +1,111 @@
+use crate::system_entity::prelude::*;
+use crate::system_entity::{system_mail_account, system_mail_template};
+use daoyi_common_support::database;
+use daoyi_common_support::error::{ApiError, ApiResult};
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::vo::system_vo::{
+    MailAccountPageReqVO, MailAccountRespVO, MailAccountSaveReqVO, MailAccountSimpleRespVO,
+};
+use sea_orm::entity::prelude::*;
+use sea_orm::{IntoActiveModel, QueryOrder, QueryTrait, Set};
+
+pub async fn create_mail_account(req: MailAccountSaveReqVO) -> ApiResult<String> {
+    let db = database::get().await;
+    let mut active_model = system_mail_account::ActiveModel::new();
+    active_model.mail = Set(req.mail);
+    active_model.username = Set(req.username);
+    active_model.smtp_password = Set(req.password.filter(|x| !x.is_empty()));
+    active_model.host = Set(req.host);
+    active_model.port = Set(req.port);
+    active_model.ssl_enable = Set(req.ssl_enable);
+    active_model.starttls_enable = Set(req.starttls_enable);
+    active_model.remark = Set(req.remark);
+    let model = active_model.insert(db).await?;
+    Ok(model.id)
+}
+
+pub async fn update_mail_account(req: MailAccountSaveReqVO) -> ApiResult<()> {
+    let id = req
+        .id
+        .as_deref()
+        .ok_or_else(|| ApiError::biz("邮箱账号编号不能为空"))?;
+    let existed = get_mail_account_by_id(id).await?;
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.mail = Set(req.mail);
+    active_model.username = Set(req.username);
+    if let Some(password) = req.password.filter(|x| !x.is_empty()) {
+        active_model.smtp_password = Set(Some(password));
+    }
+    active_model.host = Set(req.host);
+    active_model.port = Set(req.port);
+    active_model.ssl_enable = Set(req.ssl_enable);
+    active_model.starttls_enable = Set(req.starttls_enable);
+    active_model.remark = Set(req.remark);
+    active_model.update(db).await?;
+    Ok(())
+}
+
+pub async fn delete_mail_account(id: &str) -> ApiResult<()> {
+    let existed = get_mail_account_by_id(id).await?;
+    let db = database::get().await;
+    let template_count = SystemMailTemplate::find_perm()
+        .await
+        .filter(system_mail_template::Column::AccountId.eq(id))
+        .count(db)
+        .await?;
+    if template_count > 0 {
+        return Err(ApiError::biz("无法删除，该邮箱账号还有邮件模板"));
+    }
+    let mut active_model = existed.into_active_model();
+    active_model.deleted = Set(true);
+    active_model.update(db).await?;
+    Ok(())
+}
+
+pub async fn get_mail_account_by_id(id: &str) -> ApiResult<system_mail_account::Model> {
+    let db = database::get().await;
+    SystemMailAccount::find_perm()
+        .await
+        .filter(system_mail_account::Column::Id.eq(id))
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::biz("邮箱账号不存在"))
+}
+
+pub async fn get_mail_account_page(
+    req: MailAccountPageReqVO,
+) -> ApiResult<Page<MailAccountRespVO>> {
+    let db = database::get().await;
+    let paginator = SystemMailAccount::find_perm()
+        .await
+        .apply_if(req.mail.as_ref(), |query, mail| {
+            query.filter(system_mail_account::Column::Mail.contains(mail))
+        })
+        .apply_if(req.username.as_ref(), |query, username| {
+            query.filter(system_mail_account::Column::Username.contains(username))
+        })
+        .order_by_desc(system_mail_account::Column::Id)
+        .paginate(db, req.pagination.size);
+    let total = paginator.num_items().await?;
+    let items = paginator
+        .fetch_page(req.pagination.page - 1)
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+    Ok(Page::from_pagination(req.pagination, total, items))
+}
+
+pub async fn get_simple_mail_account_list() -> ApiResult<Vec<MailAccountSimpleRespVO>> {
+    let db = database::get().await;
+    let list = SystemMailAccount::find_perm()
+        .await
+        .order_by_asc(system_mail_account::Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+    Ok(list)
+}
@@ -0,0 +1,49 @@
+use crate::system_entity::prelude::*;
+use crate::system_entity::system_mail_log;
+use daoyi_common_support::database;
+use daoyi_common_support::error::{ApiError, ApiResult};
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::vo::system_vo::{MailLogPageReqVO, MailLogRespVO};
+use sea_orm::entity::prelude::*;
+use sea_orm::{QueryOrder, QueryTrait};
+
+pub async fn get_mail_log_by_id(id: &str) -> ApiResult<system_mail_log::Model> {
+    let db = database::get().await;
+    SystemMailLog::find_perm()
+        .await
+        .filter(system_mail_log::Column::Id.eq(id))
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::biz("邮件日志不存在"))
+}
+
+pub async fn get_mail_log_page(req: MailLogPageReqVO) -> ApiResult<Page<MailLogRespVO>> {
+    let db = database::get().await;
+    let paginator = SystemMailLog::find_perm()
+        .await
+        .apply_if(req.user_id.as_ref(), |query, user_id| {
+            query.filter(system_mail_log::Column::UserId.eq(user_id))
+        })
+        .apply_if(req.to_mail.as_ref(), |query, to_mail| {
+            query.filter(system_mail_log::Column::ToMail.contains(to_mail))
+        })
+        .apply_if(req.account_id.as_ref(), |query, account_id| {
+            query.filter(system_mail_log::Column::AccountId.eq(account_id))
+        })
+        .apply_if(req.template_id.as_ref(), |query, template_id| {
+            query.filter(system_mail_log::Column::TemplateId.eq(template_id))
+        })
+        .apply_if(req.send_status, |query, send_status| {
+            query.filter(system_mail_log::Column::SendStatus.eq(send_status))
+        })
+        .order_by_desc(system_mail_log::Column::Id)
+        .paginate(db, req.pagination.size);
+    let total = paginator.num_items().await?;
+    let items = paginator
+        .fetch_page(req.pagination.page - 1)
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+    Ok(Page::from_pagination(req.pagination, total, items))
+}
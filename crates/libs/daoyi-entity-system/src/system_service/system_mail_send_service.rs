@@ -0,0 +1,123 @@
+use crate::system_entity::prelude::*;
+use crate::system_entity::system_mail_log;
+use crate::system_service::{system_mail_account_service, system_mail_template_service};
+use daoyi_common_support::context::HttpRequestContext;
+use daoyi_common_support::database;
+use daoyi_common_support::enumeration::{CommonStatusEnum, MailSendStatusEnum};
+use daoyi_common_support::error::{ApiError, ApiResult};
+use daoyi_common_support::mail::{self, MailContent, SmtpAccount};
+use sea_orm::entity::prelude::*;
+use chrono::Local;
+use sea_orm::{IntoActiveModel, Set};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// 单封邮件的最大发送次数（含首次发送）
+const MAIL_SEND_MAX_ATTEMPTS: i32 = 3;
+/// 首次重试前的等待时间，之后每次翻倍
+const MAIL_SEND_RETRY_BACKOFF: Duration = Duration::from_secs(2);
+
+/// 使用邮件模版发送单封邮件，返回邮件日志编号
+///
+/// 先写入发送日志再异步投递，投递失败时按指数退避重试；模版已关闭时只记录日志不发送
+pub async fn send_single_mail(
+    mail: &str,
+    user_id: Option<String>,
+    template_code: &str,
+    template_params: HashMap<String, String>,
+) -> ApiResult<String> {
+    let template = system_mail_template_service::get_mail_template_by_code(template_code)
+        .await?
+        .ok_or_else(|| ApiError::biz(format!("邮件模版({template_code})不存在")))?;
+    let account = system_mail_account_service::get_mail_account_by_id(&template.account_id).await?;
+    // 标题为纯文本，内容为 HTML，参数值需要转义
+    let title =
+        system_mail_template_service::get_title_template(&template).render(&template_params)?;
+    let content = system_mail_template_service::get_content_template(&template)
+        .render_html(&template_params)?;
+    let send_status = if template.status == CommonStatusEnum::Enable {
+        MailSendStatusEnum::Init
+    } else {
+        MailSendStatusEnum::Ignore
+    };
+    let db = database::get().await;
+    let mut active_model = system_mail_log::ActiveModel::new();
+    active_model.user_id = Set(user_id);
+    active_model.to_mail = Set(mail.to_string());
+    active_model.account_id = Set(account.id.clone());
+    active_model.from_mail = Set(account.mail.clone());
+    active_model.template_id = Set(template.id);
+    active_model.template_code = Set(template.code);
+    active_model.template_nickname = Set(template.nickname.clone());
+    active_model.template_title = Set(title.clone());
+    active_model.template_content = Set(content.clone());
+    active_model.template_params = Set(serde_json::to_value(template_params)?);
+    active_model.send_status = Set(send_status);
+    active_model.retry_count = Set(0);
+    let log = active_model.insert(db).await?;
+    let log_id = log.id.clone();
+    if send_status == MailSendStatusEnum::Ignore {
+        tracing::info!("邮件模版({template_code})已关闭，无法给({mail})发送邮件");
+        return Ok(log_id);
+    }
+    let smtp_account = SmtpAccount::from(&account);
+    let content = MailContent {
+        from: account.mail,
+        nickname: template.nickname,
+        to: mail.to_string(),
+        subject: title,
+        html: content,
+    };
+    HttpRequestContext::spawn(async move {
+        if let Err(e) = deliver_mail(log, smtp_account, content).await {
+            tracing::error!("更新邮件日志失败: {e}");
+        }
+    });
+    Ok(log_id)
+}
+
+/// 投递邮件并回写发送结果，失败时按指数退避重试
+async fn deliver_mail(
+    log: system_mail_log::Model,
+    account: SmtpAccount,
+    content: MailContent,
+) -> ApiResult<()> {
+    let mut backoff = MAIL_SEND_RETRY_BACKOFF;
+    let mut attempt = 1;
+    let result = loop {
+        match mail::send_mail(&account, &content).await {
+            Ok(message_id) => break Ok(message_id),
+            Err(e) if attempt < MAIL_SEND_MAX_ATTEMPTS => {
+                tracing::warn!(
+                    "邮件({})第{attempt}次发送失败，{backoff:?}后重试: {e}",
+                    log.id
+                );
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+                attempt += 1;
+            }
+            Err(e) => break Err(e),
+        }
+    };
+    let db = database::get().await;
+    let existed = SystemMailLog::find_by_id(&log.id)
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::biz("邮件日志不存在"))?;
+    let mut active_model = existed.into_active_model();
+    active_model.retry_count = Set(attempt - 1);
+    active_model.send_time = Set(Some(Local::now().naive_local()));
+    match result {
+        Ok(message_id) => {
+            active_model.send_status = Set(MailSendStatusEnum::Success);
+            active_model.send_message_id = Set(Some(message_id));
+        }
+        Err(e) => {
+            tracing::error!("邮件({})发送失败: {e}", log.id);
+            active_model.send_status = Set(MailSendStatusEnum::Failure);
+            active_model.send_exception = Set(Some(format!("{e:#}")));
+        }
+    }
+    active_model.update(db).await?;
+    Ok(())
+}
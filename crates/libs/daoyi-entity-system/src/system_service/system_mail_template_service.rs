@@ -0,0 +1,142 @@
+use crate::system_entity::prelude::*;
+use crate::system_entity::system_mail_template;
+use crate::system_service::system_mail_account_service;
+use daoyi_common_support::database;
+use daoyi_common_support::error::{ApiError, ApiResult};
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::template::{self, Template};
+use daoyi_common_support::vo::system_vo::{
+    MailTemplatePageReqVO, MailTemplateRespVO, MailTemplateSaveReqVO,
+};
+use sea_orm::entity::prelude::*;
+use sea_orm::{IntoActiveModel, QueryOrder, QueryTrait, Set};
+use std::sync::Arc;
+
+pub async fn create_mail_template(req: MailTemplateSaveReqVO) -> ApiResult<String> {
+    validate_mail_template_code_unique(None, &req.code).await?;
+    system_mail_account_service::get_mail_account_by_id(&req.account_id).await?;
+    let db = database::get().await;
+    let mut active_model = system_mail_template::ActiveModel::new();
+    active_model.params = Set(template::parse_params(&[&req.title, &req.content]).into());
+    active_model.name = Set(req.name);
+    active_model.code = Set(req.code);
+    active_model.account_id = Set(req.account_id);
+    active_model.nickname = Set(req.nickname);
+    active_model.title = Set(req.title);
+    active_model.content = Set(req.content);
+    active_model.status = Set(req.status);
+    active_model.remark = Set(req.remark);
+    let model = active_model.insert(db).await?;
+    Ok(model.id)
+}
+
+pub async fn update_mail_template(req: MailTemplateSaveReqVO) -> ApiResult<()> {
+    let id = req
+        .id
+        .as_deref()
+        .ok_or_else(|| ApiError::biz("邮件模版编号不能为空"))?;
+    let existed = get_mail_template_by_id(id).await?;
+    validate_mail_template_code_unique(Some(id), &req.code).await?;
+    system_mail_account_service::get_mail_account_by_id(&req.account_id).await?;
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.params = Set(template::parse_params(&[&req.title, &req.content]).into());
+    active_model.name = Set(req.name);
+    active_model.code = Set(req.code);
+    active_model.account_id = Set(req.account_id);
+    active_model.nickname = Set(req.nickname);
+    active_model.title = Set(req.title);
+    active_model.content = Set(req.content);
+    active_model.status = Set(req.status);
+    active_model.remark = Set(req.remark);
+    active_model.update(db).await?;
+    Ok(())
+}
+
+pub async fn delete_mail_template(id: &str) -> ApiResult<()> {
+    let existed = get_mail_template_by_id(id).await?;
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.deleted = Set(true);
+    active_model.update(db).await?;
+    Ok(())
+}
+
+pub async fn get_mail_template_by_id(id: &str) -> ApiResult<system_mail_template::Model> {
+    let db = database::get().await;
+    SystemMailTemplate::find_perm()
+        .await
+        .filter(system_mail_template::Column::Id.eq(id))
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::biz("邮件模版不存在"))
+}
+
+pub async fn get_mail_template_by_code(
+    code: &str,
+) -> ApiResult<Option<system_mail_template::Model>> {
+    let db = database::get().await;
+    let model = SystemMailTemplate::find_perm()
+        .await
+        .filter(system_mail_template::Column::Code.eq(code))
+        .one(db)
+        .await?;
+    Ok(model)
+}
+
+pub async fn get_mail_template_page(
+    req: MailTemplatePageReqVO,
+) -> ApiResult<Page<MailTemplateRespVO>> {
+    let db = database::get().await;
+    let paginator = SystemMailTemplate::find_perm()
+        .await
+        .apply_if(req.name.as_ref(), |query, name| {
+            query.filter(system_mail_template::Column::Name.contains(name))
+        })
+        .apply_if(req.code.as_ref(), |query, code| {
+            query.filter(system_mail_template::Column::Code.contains(code))
+        })
+        .apply_if(req.account_id.as_ref(), |query, account_id| {
+            query.filter(system_mail_template::Column::AccountId.eq(account_id))
+        })
+        .apply_if(req.status, |query, status| {
+            query.filter(system_mail_template::Column::Status.eq(status))
+        })
+        .order_by_desc(system_mail_template::Column::Id)
+        .paginate(db, req.pagination.size);
+    let total = paginator.num_items().await?;
+    let items = paginator
+        .fetch_page(req.pagination.page - 1)
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+    Ok(Page::from_pagination(req.pagination, total, items))
+}
+
+/// 获取邮件模版标题解析后的模板，模版更新后自动重新解析
+pub fn get_title_template(template: &system_mail_template::Model) -> Arc<Template> {
+    template::get_or_parse(
+        &format!("mail-title:{}", template.id),
+        &template.update_time.to_string(),
+        &template.title,
+    )
+}
+
+/// 获取邮件模版内容解析后的模板，模版更新后自动重新解析
+pub fn get_content_template(template: &system_mail_template::Model) -> Arc<Template> {
+    template::get_or_parse(
+        &format!("mail-content:{}", template.id),
+        &template.update_time.to_string(),
+        &template.content,
+    )
+}
+
+async fn validate_mail_template_code_unique(id: Option<&str>, code: &str) -> ApiResult<()> {
+    if let Some(existed) = get_mail_template_by_code(code).await?
+        && id != Some(existed.id.as_str())
+    {
+        return Err(ApiError::biz(format!("已经存在编码为【{code}】的邮件模板")));
+    }
+    Ok(())
+}
@@ -13,8 +13,8 @@ pub async fn get_menu_list(ids: Option<&Vec<String>>) -> ApiResult<Vec<system_me
         return Ok(vec![]);
     }
     let db = database::get().await;
-    Ok(SystemMenu::find_perm()
-        .await
+    // 菜单为全局数据，不区分租户，租户可用范围由租户套餐控制
+    Ok(SystemMenu::find_ignore_tenant()
         .apply_if(ids, |query, ids| {
             query.filter(system_menu::Column::Id.is_in(ids))
         })
@@ -26,7 +26,7 @@ pub async fn build_menu_tree(menus: Vec<system_menu::Model>) -> ApiResult<Vec<Me
     let mut menus = menus;
     menus.retain(|m| m.r#type != MenuTypeEnum::BUTTON);
 
-    menus.sort_by(|a, b| a.sort.cmp(&b.sort));
+    menus.sort_by_key(|m| m.sort);
 
     let mut map: HashMap<String, Vec<system_menu::Model>> = HashMap::new();
     for menu in menus {
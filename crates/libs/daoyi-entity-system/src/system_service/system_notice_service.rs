@@ -0,0 +1,300 @@
+use crate::system_entity::prelude::*;
+use crate::system_entity::{system_notice, system_notice_read, system_user_role, system_users};
+use crate::system_service::{system_user_role_service, system_users_service};
+use daoyi_common_support::database;
+use daoyi_common_support::database::json_array;
+use daoyi_common_support::enumeration::CommonStatusEnum;
+use daoyi_common_support::error::{ApiError, ApiResult};
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::push::{self, PushMessage};
+use daoyi_common_support::vo::system_vo::{NoticePageReqVO, NoticeRespVO, NoticeSaveReqVO};
+use sea_orm::entity::prelude::*;
+use chrono::Local;
+use sea_orm::{Condition, DbBackend, IntoActiveModel, QueryOrder, QueryTrait, Set};
+use std::collections::HashSet;
+use std::time::Duration;
+
+/// 推送给前端的公告消息类型
+pub const NOTICE_PUSH_TYPE: &str = "notice-push";
+
+/// 定时发布公告的扫描间隔
+const NOTICE_PUSH_INTERVAL: Duration = Duration::from_secs(30);
+
+pub async fn create_notice(req: NoticeSaveReqVO) -> ApiResult<String> {
+    let db = database::get().await;
+    let mut active_model = system_notice::ActiveModel::new();
+    active_model.title = Set(req.title);
+    active_model.content = Set(req.content);
+    active_model.r#type = Set(req.r#type);
+    active_model.status = Set(req.status);
+    active_model.target_role_ids = Set(req.target_role_ids.into());
+    active_model.target_dept_ids = Set(req.target_dept_ids.into());
+    active_model.publish_time = Set(req.publish_time);
+    active_model.pushed = Set(false);
+    let model = active_model.insert(db).await?;
+    let id = model.id.clone();
+    push_notice_if_due(model).await?;
+    Ok(id)
+}
+
+/// 更新公告，发布时间变更后会按新的发布时间重新推送
+pub async fn update_notice(req: NoticeSaveReqVO) -> ApiResult<()> {
+    let id = req
+        .id
+        .as_deref()
+        .ok_or_else(|| ApiError::biz("公告编号不能为空"))?;
+    let existed = get_notice_by_id(id).await?;
+    let republish = existed.publish_time != req.publish_time;
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.title = Set(req.title);
+    active_model.content = Set(req.content);
+    active_model.r#type = Set(req.r#type);
+    active_model.status = Set(req.status);
+    active_model.target_role_ids = Set(req.target_role_ids.into());
+    active_model.target_dept_ids = Set(req.target_dept_ids.into());
+    active_model.publish_time = Set(req.publish_time);
+    if republish {
+        active_model.pushed = Set(false);
+    }
+    let model = active_model.update(db).await?;
+    push_notice_if_due(model).await
+}
+
+pub async fn delete_notice(id: &str) -> ApiResult<()> {
+    let existed = get_notice_by_id(id).await?;
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.deleted = Set(true);
+    active_model.update(db).await?;
+    Ok(())
+}
+
+/// 立即发布公告，并推送给在线用户
+pub async fn publish_notice(id: &str) -> ApiResult<()> {
+    let existed = get_notice_by_id(id).await?;
+    if existed.status != CommonStatusEnum::Enable {
+        return Err(ApiError::biz("公告已关闭，无法发布"));
+    }
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.publish_time = Set(Some(Local::now().naive_local()));
+    active_model.pushed = Set(false);
+    let model = active_model.update(db).await?;
+    push_notice_if_due(model).await
+}
+
+pub async fn get_notice_by_id(id: &str) -> ApiResult<system_notice::Model> {
+    let db = database::get().await;
+    SystemNotice::find_perm()
+        .await
+        .filter(system_notice::Column::Id.eq(id))
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::biz("当前公告不存在"))
+}
+
+pub async fn get_notice_page(req: NoticePageReqVO) -> ApiResult<Page<NoticeRespVO>> {
+    let db = database::get().await;
+    let paginator = SystemNotice::find_perm()
+        .await
+        .apply_if(req.title.as_ref(), |query, title| {
+            query.filter(system_notice::Column::Title.contains(title))
+        })
+        .apply_if(req.r#type, |query, r#type| {
+            query.filter(system_notice::Column::Type.eq(r#type))
+        })
+        .apply_if(req.status, |query, status| {
+            query.filter(system_notice::Column::Status.eq(status))
+        })
+        .order_by_desc(system_notice::Column::Id)
+        .paginate(db, req.pagination.size);
+    let total = paginator.num_items().await?;
+    let items = paginator
+        .fetch_page(req.pagination.page - 1)
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+    Ok(Page::from_pagination(req.pagination, total, items))
+}
+
+/// 获得当前登录用户未读的已发布公告，按发布时间倒序
+pub async fn get_my_unread_notice_list() -> ApiResult<Vec<system_notice::Model>> {
+    let user_id = system_users_service::get_login_user_id().await?;
+    let read_ids = get_read_notice_ids(&user_id).await?;
+    let db = database::get().await;
+    let list = SystemNotice::find_perm()
+        .await
+        .filter(published_condition())
+        .filter(visible_condition(db.get_database_backend(), &user_id).await?)
+        .apply_if((!read_ids.is_empty()).then_some(read_ids), |query, ids| {
+            query.filter(system_notice::Column::Id.is_not_in(ids))
+        })
+        .order_by_desc(system_notice::Column::PublishTime)
+        .all(db)
+        .await?;
+    Ok(list)
+}
+
+/// 将公告标记为当前登录用户已读，忽略不存在或已读的公告
+pub async fn mark_notice_read(ids: &[String]) -> ApiResult<()> {
+    let user_id = system_users_service::get_login_user_id().await?;
+    let read_ids = get_read_notice_ids(&user_id).await?;
+    let db = database::get().await;
+    let notices = SystemNotice::find_perm()
+        .await
+        .filter(system_notice::Column::Id.is_in(ids))
+        .all(db)
+        .await?;
+    for notice in notices {
+        if read_ids.contains(&notice.id) {
+            continue;
+        }
+        let mut active_model = system_notice_read::ActiveModel::new();
+        active_model.notice_id = Set(notice.id);
+        active_model.user_id = Set(user_id.clone());
+        active_model.read_time = Set(Local::now().naive_local());
+        active_model.tenant_id = Set(notice.tenant_id);
+        active_model.insert(db).await?;
+    }
+    Ok(())
+}
+
+pub async fn mark_all_notice_read() -> ApiResult<()> {
+    let ids = get_my_unread_notice_list()
+        .await?
+        .into_iter()
+        .map(|x| x.id)
+        .collect::<Vec<_>>();
+    mark_notice_read(&ids).await
+}
+
+/// 启动定时发布任务，周期性推送已到发布时间的公告
+pub fn start_notice_push_scheduler() {
+    tokio::spawn(async {
+        let mut interval = tokio::time::interval(NOTICE_PUSH_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = push_due_notices().await {
+                tracing::error!("定时发布公告失败: {e}");
+            }
+        }
+    });
+}
+
+/// 推送所有租户中已到发布时间、尚未推送的公告
+pub async fn push_due_notices() -> ApiResult<()> {
+    let db = database::get().await;
+    let list = SystemNotice::find_ignore_tenant()
+        .filter(system_notice::Column::Pushed.eq(false))
+        .filter(published_condition())
+        .all(db)
+        .await?;
+    for notice in list {
+        push_notice(notice).await?;
+    }
+    Ok(())
+}
+
+async fn push_notice_if_due(notice: system_notice::Model) -> ApiResult<()> {
+    let due = notice
+        .publish_time
+        .is_some_and(|time| time <= Local::now().naive_local());
+    if notice.status == CommonStatusEnum::Enable && due && !notice.pushed {
+        push_notice(notice).await?;
+    }
+    Ok(())
+}
+
+/// 推送公告给租户下的在线目标用户，先以条件更新抢占推送标记，避免多实例重复推送
+async fn push_notice(notice: system_notice::Model) -> ApiResult<()> {
+    let db = database::get().await;
+    let result = SystemNotice::update_many()
+        .col_expr(system_notice::Column::Pushed, Expr::value(true))
+        .filter(system_notice::Column::Id.eq(&notice.id))
+        .filter(system_notice::Column::Pushed.eq(false))
+        .exec(db)
+        .await?;
+    if result.rows_affected == 0 {
+        return Ok(());
+    }
+    let user_ids = get_target_user_ids(&notice).await?;
+    let tenant_id = notice.tenant_id.clone();
+    let vo: NoticeRespVO = notice.into();
+    push::publish(PushMessage::new(
+        &tenant_id,
+        user_ids,
+        NOTICE_PUSH_TYPE,
+        &vo,
+    )?);
+    Ok(())
+}
+
+/// 公告的目标用户，未指定角色和部门时返回 None 表示租户下所有用户
+async fn get_target_user_ids(notice: &system_notice::Model) -> ApiResult<Option<HashSet<String>>> {
+    if notice.target_role_ids.is_empty() && notice.target_dept_ids.is_empty() {
+        return Ok(None);
+    }
+    let db = database::get().await;
+    let mut user_ids = HashSet::new();
+    if !notice.target_dept_ids.is_empty() {
+        let users = SystemUsers::find_ignore_tenant()
+            .filter(system_users::Column::TenantId.eq(&notice.tenant_id))
+            .filter(system_users::Column::DeptId.is_in(&notice.target_dept_ids))
+            .all(db)
+            .await?;
+        user_ids.extend(users.into_iter().map(|x| x.id));
+    }
+    if !notice.target_role_ids.is_empty() {
+        let user_roles = SystemUserRole::find_ignore_tenant()
+            .filter(system_user_role::Column::TenantId.eq(&notice.tenant_id))
+            .filter(system_user_role::Column::RoleId.is_in(&notice.target_role_ids))
+            .all(db)
+            .await?;
+        user_ids.extend(user_roles.into_iter().map(|x| x.user_id));
+    }
+    Ok(Some(user_ids))
+}
+
+/// 已发布：开启状态且发布时间不晚于当前时间
+fn published_condition() -> Condition {
+    Condition::all()
+        .add(system_notice::Column::Status.eq(CommonStatusEnum::Enable))
+        .add(system_notice::Column::PublishTime.lte(Local::now().naive_local()))
+}
+
+/// 用户可见：未指定目标，或用户的角色、部门命中公告的目标
+async fn visible_condition(backend: DbBackend, user_id: &str) -> ApiResult<Condition> {
+    let user = system_users_service::get_by_id(user_id).await?;
+    let role_ids = system_user_role_service::get_user_role_id_list_by_user_id(user_id).await?;
+    let mut condition = Condition::any().add(
+        Condition::all()
+            .add(json_array::is_empty(backend, system_notice::Column::TargetRoleIds))
+            .add(json_array::is_empty(backend, system_notice::Column::TargetDeptIds)),
+    );
+    if !role_ids.is_empty() {
+        condition = condition.add(
+            json_array::contains_any(backend, system_notice::Column::TargetRoleIds, &role_ids),
+        );
+    }
+    if let Some(dept_id) = user.dept_id {
+        condition = condition.add(
+            json_array::contains_any(backend, system_notice::Column::TargetDeptIds, &[dept_id]),
+        );
+    }
+    Ok(condition)
+}
+
+async fn get_read_notice_ids(user_id: &str) -> ApiResult<HashSet<String>> {
+    let db = database::get().await;
+    let ids = SystemNoticeRead::find_perm()
+        .await
+        .filter(system_notice_read::Column::UserId.eq(user_id))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|x| x.notice_id)
+        .collect();
+    Ok(ids)
+}
@@ -0,0 +1,78 @@
+use crate::system_entity::prelude::*;
+use crate::system_entity::system_notify_message;
+use crate::system_service::system_users_service;
+use daoyi_common_support::database;
+use daoyi_common_support::error::ApiResult;
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::vo::system_vo::{NotifyMessageMyPageReqVO, NotifyMessageRespVO};
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::Expr;
+use chrono::Local;
+use sea_orm::{PaginatorTrait, QueryOrder, QueryTrait};
+
+/// 获得当前登录用户的站内信分页
+pub async fn get_my_notify_message_page(
+    req: NotifyMessageMyPageReqVO,
+) -> ApiResult<Page<NotifyMessageRespVO>> {
+    let user_id = system_users_service::get_login_user_id().await?;
+    let db = database::get().await;
+    let paginator = SystemNotifyMessage::find_perm()
+        .await
+        .filter(system_notify_message::Column::UserId.eq(user_id))
+        .apply_if(req.read_status, |query, read_status| {
+            query.filter(system_notify_message::Column::ReadStatus.eq(read_status))
+        })
+        .order_by_desc(system_notify_message::Column::Id)
+        .paginate(db, req.pagination.size);
+    let total = paginator.num_items().await?;
+    let items = paginator
+        .fetch_page(req.pagination.page - 1)
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+    Ok(Page::from_pagination(req.pagination, total, items))
+}
+
+/// 将当前登录用户的站内信标记为已读，返回更新的条数
+pub async fn update_notify_message_read(ids: &[String]) -> ApiResult<u64> {
+    mark_my_notify_message_read(Some(ids)).await
+}
+
+/// 将当前登录用户的所有站内信标记为已读，返回更新的条数
+pub async fn update_all_notify_message_read() -> ApiResult<u64> {
+    mark_my_notify_message_read(None).await
+}
+
+/// 获得当前登录用户的未读站内信数量
+pub async fn get_unread_notify_message_count() -> ApiResult<u64> {
+    let user_id = system_users_service::get_login_user_id().await?;
+    let db = database::get().await;
+    let count = SystemNotifyMessage::find_perm()
+        .await
+        .filter(system_notify_message::Column::UserId.eq(user_id))
+        .filter(system_notify_message::Column::ReadStatus.eq(false))
+        .count(db)
+        .await?;
+    Ok(count)
+}
+
+async fn mark_my_notify_message_read(ids: Option<&[String]>) -> ApiResult<u64> {
+    let user_id = system_users_service::get_login_user_id().await?;
+    let db = database::get().await;
+    let result = SystemNotifyMessage::update_many()
+        .col_expr(system_notify_message::Column::ReadStatus, Expr::value(true))
+        .col_expr(
+            system_notify_message::Column::ReadTime,
+            Expr::value(Local::now().naive_local()),
+        )
+        .filter(system_notify_message::Column::UserId.eq(user_id))
+        .filter(system_notify_message::Column::ReadStatus.eq(false))
+        .filter(system_notify_message::Column::Deleted.eq(false))
+        .apply_if(ids, |query, ids| {
+            query.filter(system_notify_message::Column::Id.is_in(ids))
+        })
+        .exec(db)
+        .await?;
+    Ok(result.rows_affected)
+}
@@ -0,0 +1,55 @@
+use crate::system_entity::system_notify_message;
+use crate::system_service::{system_notify_template_service, system_users_service};
+use daoyi_common_support::database;
+use daoyi_common_support::enumeration::CommonStatusEnum;
+use daoyi_common_support::error::{ApiError, ApiResult};
+use daoyi_common_support::push::{self, PushMessage};
+use daoyi_common_support::vo::system_vo::NotifyMessageRespVO;
+use sea_orm::Set;
+use sea_orm::entity::prelude::*;
+use std::collections::HashMap;
+
+/// 推送给前端的站内信消息类型
+pub const NOTIFY_MESSAGE_PUSH_TYPE: &str = "notify-message";
+
+/// 使用站内信模版给单个用户发送站内信，并推送给在线的该用户
+///
+/// 供其它业务在进程内直接调用；模版已关闭时不发送，返回 `None`
+pub async fn send_single_to_user(
+    user_id: &str,
+    template_code: &str,
+    template_params: HashMap<String, String>,
+) -> ApiResult<Option<String>> {
+    let template = system_notify_template_service::get_notify_template_by_code(template_code)
+        .await?
+        .ok_or_else(|| ApiError::biz(format!("站内信模版({template_code})不存在")))?;
+    if template.status != CommonStatusEnum::Enable {
+        tracing::info!("站内信模版({template_code})已关闭，无法给用户({user_id})发送");
+        return Ok(None);
+    }
+    let content =
+        system_notify_template_service::get_content_template(&template).render(&template_params)?;
+    let user = system_users_service::get_by_id(user_id).await?;
+    let db = database::get().await;
+    let mut active_model = system_notify_message::ActiveModel::new();
+    active_model.user_id = Set(user.id);
+    active_model.template_id = Set(template.id);
+    active_model.template_code = Set(template.code);
+    active_model.template_nickname = Set(template.nickname);
+    active_model.template_content = Set(content);
+    active_model.template_type = Set(template.r#type);
+    active_model.template_params = Set(serde_json::to_value(template_params)?);
+    active_model.read_status = Set(false);
+    active_model.tenant_id = Set(user.tenant_id);
+    let message = active_model.insert(db).await?;
+    let id = message.id.clone();
+    let tenant_id = message.tenant_id.clone();
+    let vo: NotifyMessageRespVO = message.into();
+    push::publish(PushMessage::new(
+        &tenant_id,
+        Some([vo.user_id.clone()].into()),
+        NOTIFY_MESSAGE_PUSH_TYPE,
+        &vo,
+    )?);
+    Ok(Some(id))
+}
@@ -0,0 +1,127 @@
+use crate::system_entity::prelude::*;
+use crate::system_entity::system_notify_template;
+use daoyi_common_support::database;
+use daoyi_common_support::error::{ApiError, ApiResult};
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::template::{self, Template};
+use daoyi_common_support::vo::system_vo::{
+    NotifyTemplatePageReqVO, NotifyTemplateRespVO, NotifyTemplateSaveReqVO,
+};
+use sea_orm::entity::prelude::*;
+use sea_orm::{IntoActiveModel, QueryOrder, QueryTrait, Set};
+use std::sync::Arc;
+
+pub async fn create_notify_template(req: NotifyTemplateSaveReqVO) -> ApiResult<String> {
+    validate_notify_template_code_unique(None, &req.code).await?;
+    let db = database::get().await;
+    let mut active_model = system_notify_template::ActiveModel::new();
+    active_model.params = Set(Template::parse(&req.content).params().to_vec().into());
+    active_model.name = Set(req.name);
+    active_model.code = Set(req.code);
+    active_model.nickname = Set(req.nickname);
+    active_model.content = Set(req.content);
+    active_model.r#type = Set(req.r#type);
+    active_model.status = Set(req.status);
+    active_model.remark = Set(req.remark);
+    let model = active_model.insert(db).await?;
+    Ok(model.id)
+}
+
+pub async fn update_notify_template(req: NotifyTemplateSaveReqVO) -> ApiResult<()> {
+    let id = req
+        .id
+        .as_deref()
+        .ok_or_else(|| ApiError::biz("站内信模版编号不能为空"))?;
+    let existed = get_notify_template_by_id(id).await?;
+    validate_notify_template_code_unique(Some(id), &req.code).await?;
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.params = Set(Template::parse(&req.content).params().to_vec().into());
+    active_model.name = Set(req.name);
+    active_model.code = Set(req.code);
+    active_model.nickname = Set(req.nickname);
+    active_model.content = Set(req.content);
+    active_model.r#type = Set(req.r#type);
+    active_model.status = Set(req.status);
+    active_model.remark = Set(req.remark);
+    active_model.update(db).await?;
+    Ok(())
+}
+
+pub async fn delete_notify_template(id: &str) -> ApiResult<()> {
+    let existed = get_notify_template_by_id(id).await?;
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.deleted = Set(true);
+    active_model.update(db).await?;
+    Ok(())
+}
+
+pub async fn get_notify_template_by_id(id: &str) -> ApiResult<system_notify_template::Model> {
+    let db = database::get().await;
+    SystemNotifyTemplate::find_perm()
+        .await
+        .filter(system_notify_template::Column::Id.eq(id))
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::biz("站内信模版不存在"))
+}
+
+pub async fn get_notify_template_by_code(
+    code: &str,
+) -> ApiResult<Option<system_notify_template::Model>> {
+    let db = database::get().await;
+    let model = SystemNotifyTemplate::find_perm()
+        .await
+        .filter(system_notify_template::Column::Code.eq(code))
+        .one(db)
+        .await?;
+    Ok(model)
+}
+
+pub async fn get_notify_template_page(
+    req: NotifyTemplatePageReqVO,
+) -> ApiResult<Page<NotifyTemplateRespVO>> {
+    let db = database::get().await;
+    let paginator = SystemNotifyTemplate::find_perm()
+        .await
+        .apply_if(req.name.as_ref(), |query, name| {
+            query.filter(system_notify_template::Column::Name.contains(name))
+        })
+        .apply_if(req.code.as_ref(), |query, code| {
+            query.filter(system_notify_template::Column::Code.contains(code))
+        })
+        .apply_if(req.status, |query, status| {
+            query.filter(system_notify_template::Column::Status.eq(status))
+        })
+        .order_by_desc(system_notify_template::Column::Id)
+        .paginate(db, req.pagination.size);
+    let total = paginator.num_items().await?;
+    let items = paginator
+        .fetch_page(req.pagination.page - 1)
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+    Ok(Page::from_pagination(req.pagination, total, items))
+}
+
+/// 获取站内信模版内容解析后的模板，模版更新后自动重新解析
+pub fn get_content_template(template: &system_notify_template::Model) -> Arc<Template> {
+    template::get_or_parse(
+        &format!("notify:{}", template.id),
+        &template.update_time.to_string(),
+        &template.content,
+    )
+}
+
+async fn validate_notify_template_code_unique(id: Option<&str>, code: &str) -> ApiResult<()> {
+    if let Some(existed) = get_notify_template_by_code(code).await?
+        && id != Some(existed.id.as_str())
+    {
+        return Err(ApiError::biz(format!(
+            "已经存在编码为【{code}】的站内信模板"
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,171 @@
+use crate::system_entity::prelude::*;
+use crate::system_entity::system_post;
+use daoyi_common_support::enumeration::CommonStatusEnum;
+use daoyi_common_support::error::{ApiError, ApiResult};
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::vo::system_vo::{PostPageReqVO, PostRespVO, PostSaveReqVO};
+use sea_orm::entity::prelude::*;
+use sea_orm::{IntoActiveModel, QueryOrder, QueryTrait, Set};
+use std::collections::HashMap;
+
+pub async fn create_post<C: ConnectionTrait>(db: &C, req: PostSaveReqVO) -> ApiResult<String> {
+    validate_post_for_create_or_update(db, None, &req.name, &req.code).await?;
+    let mut active_model = system_post::ActiveModel::new();
+    active_model.code = Set(req.code);
+    active_model.name = Set(req.name);
+    active_model.sort = Set(req.sort);
+    active_model.status = Set(req.status);
+    active_model.remark = Set(req.remark);
+    let model = active_model.insert(db).await?;
+    Ok(model.id)
+}
+
+pub async fn update_post<C: ConnectionTrait>(db: &C, req: PostSaveReqVO) -> ApiResult<()> {
+    let id = req
+        .id
+        .as_deref()
+        .ok_or_else(|| ApiError::biz("岗位编号不能为空"))?;
+    let existed = get_post_by_id(db, id).await?;
+    validate_post_for_create_or_update(db, Some(id), &req.name, &req.code).await?;
+    let mut active_model = existed.into_active_model();
+    active_model.code = Set(req.code);
+    active_model.name = Set(req.name);
+    active_model.sort = Set(req.sort);
+    active_model.status = Set(req.status);
+    active_model.remark = Set(req.remark);
+    active_model.update(db).await?;
+    Ok(())
+}
+
+pub async fn delete_post<C: ConnectionTrait>(db: &C, id: &str) -> ApiResult<()> {
+    let existed = get_post_by_id(db, id).await?;
+    let mut active_model = existed.into_active_model();
+    active_model.deleted = Set(true);
+    active_model.update(db).await?;
+    Ok(())
+}
+
+pub async fn get_post_by_id<C: ConnectionTrait>(db: &C, id: &str) -> ApiResult<system_post::Model> {
+    SystemPost::find_perm()
+        .await
+        .filter(system_post::Column::Id.eq(id))
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::biz("岗位不存在"))
+}
+
+pub async fn get_post_list<C: ConnectionTrait>(
+    db: &C,
+    ids: Option<&Vec<String>>,
+    status: Option<CommonStatusEnum>,
+) -> ApiResult<Vec<system_post::Model>> {
+    if ids.is_some_and(|ids| ids.is_empty()) {
+        return Ok(vec![]);
+    }
+    let list = SystemPost::find_perm()
+        .await
+        .apply_if(ids, |query, ids| {
+            query.filter(system_post::Column::Id.is_in(ids))
+        })
+        .apply_if(status, |query, status| {
+            query.filter(system_post::Column::Status.eq(status))
+        })
+        .order_by_asc(system_post::Column::Sort)
+        .all(db)
+        .await?;
+    Ok(list)
+}
+
+pub async fn get_post_page<C: ConnectionTrait>(
+    db: &C,
+    req: PostPageReqVO,
+) -> ApiResult<Page<PostRespVO>> {
+    let paginator = SystemPost::find_perm()
+        .await
+        .apply_if(req.code.as_ref(), |query, code| {
+            query.filter(system_post::Column::Code.contains(code))
+        })
+        .apply_if(req.name.as_ref(), |query, name| {
+            query.filter(system_post::Column::Name.contains(name))
+        })
+        .apply_if(req.status, |query, status| {
+            query.filter(system_post::Column::Status.eq(status))
+        })
+        .order_by_asc(system_post::Column::Sort)
+        .order_by_desc(system_post::Column::Id)
+        .paginate(db, req.pagination.size);
+    let total = paginator.num_items().await?;
+    let items = paginator
+        .fetch_page(req.pagination.page - 1)
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+    Ok(Page::from_pagination(req.pagination, total, items))
+}
+
+/// 校验岗位们是否有效：存在且处于开启状态
+pub async fn validate_post_list<C: ConnectionTrait>(db: &C, ids: &Vec<String>) -> ApiResult<()> {
+    if ids.is_empty() {
+        return Ok(());
+    }
+    let post_map = get_post_list(db, Some(ids), None)
+        .await?
+        .into_iter()
+        .map(|x| (x.id.clone(), x))
+        .collect::<HashMap<_, _>>();
+    for id in ids {
+        let post = post_map
+            .get(id)
+            .ok_or_else(|| ApiError::biz(format!("岗位({id})不存在")))?;
+        if post.status != CommonStatusEnum::Enable {
+            return Err(ApiError::biz(format!("岗位({})未开启", post.name)));
+        }
+    }
+    Ok(())
+}
+
+/// 按岗位编号获取岗位名称，保持传入顺序，忽略不存在的岗位
+pub async fn get_post_names<C: ConnectionTrait>(
+    db: &C,
+    ids: &Vec<String>,
+) -> ApiResult<Vec<String>> {
+    let post_map = get_post_list(db, Some(ids), None)
+        .await?
+        .into_iter()
+        .map(|x| (x.id, x.name))
+        .collect::<HashMap<_, _>>();
+    Ok(ids
+        .iter()
+        .filter_map(|id| post_map.get(id).cloned())
+        .collect())
+}
+
+async fn validate_post_for_create_or_update<C: ConnectionTrait>(
+    db: &C,
+    id: Option<&str>,
+    name: &str,
+    code: &str,
+) -> ApiResult<()> {
+    let existed = SystemPost::find_perm()
+        .await
+        .filter(system_post::Column::Name.eq(name))
+        .one(db)
+        .await?;
+    if let Some(existed) = existed
+        && id != Some(existed.id.as_str())
+    {
+        return Err(ApiError::biz("已经存在该名字的岗位"));
+    }
+    let existed = SystemPost::find_perm()
+        .await
+        .filter(system_post::Column::Code.eq(code))
+        .one(db)
+        .await?;
+    if let Some(existed) = existed
+        && id != Some(existed.id.as_str())
+    {
+        return Err(ApiError::biz("已经存在该标识的岗位"));
+    }
+    Ok(())
+}
@@ -1,9 +1,13 @@
 use crate::system_entity::prelude::*;
 use crate::system_entity::system_role_menu;
-use crate::system_service::{system_menu_service, system_role_service};
+use crate::system_service::{
+    system_menu_service, system_role_service, system_tenant_package_service,
+};
 use daoyi_common_support::database;
+use daoyi_common_support::enumeration::RoleCodeEnum;
 use daoyi_common_support::error::ApiResult;
 use sea_orm::prelude::*;
+use sea_orm::{IntoActiveModel, Set};
 use std::collections::HashSet;
 
 pub async fn get_role_menu_list_by_role_id(role_ids: &Vec<String>) -> ApiResult<Vec<String>> {
@@ -30,3 +34,87 @@ pub async fn get_role_menu_list_by_role_id(role_ids: &Vec<String>) -> ApiResult<
         .into_iter()
         .collect())
 }
+
+/// 设置角色菜单，菜单范围受角色所属租户的套餐限制
+pub async fn assign_role_menu(role_id: &str, menu_ids: Vec<String>) -> ApiResult<()> {
+    let role = system_role_service::get_role_by_id(role_id).await?;
+    let menu_ids =
+        system_tenant_package_service::filter_menu_ids_by_tenant(&role.tenant_id, menu_ids).await?;
+    replace_role_menus(&role.tenant_id, role_id, menu_ids).await
+}
+
+/// 租户套餐变更后同步租户的角色菜单：
+/// 租户管理员拥有套餐全部菜单，其它角色移除套餐外的菜单
+pub async fn sync_tenant_role_menus(tenant_id: &str, package_menu_ids: &[String]) -> ApiResult<()> {
+    let allowed = package_menu_ids.iter().collect::<HashSet<_>>();
+    for role in system_role_service::get_role_list_by_tenant_id(tenant_id).await? {
+        if RoleCodeEnum::is_tenant_admin(&role.code) {
+            replace_role_menus(tenant_id, &role.id, package_menu_ids.to_vec()).await?;
+            continue;
+        }
+        let menu_ids = get_role_menu_models(tenant_id, &role.id)
+            .await?
+            .into_iter()
+            .map(|x| x.menu_id)
+            .filter(|id| allowed.contains(id))
+            .collect();
+        replace_role_menus(tenant_id, &role.id, menu_ids).await?;
+    }
+    Ok(())
+}
+
+async fn get_role_menu_models(
+    tenant_id: &str,
+    role_id: &str,
+) -> ApiResult<Vec<system_role_menu::Model>> {
+    let db = database::get().await;
+    let list = SystemRoleMenu::find_ignore_tenant()
+        .filter(system_role_menu::Column::TenantId.eq(tenant_id))
+        .filter(system_role_menu::Column::RoleId.eq(role_id))
+        .all(db)
+        .await?;
+    Ok(list)
+}
+
+async fn replace_role_menus(
+    tenant_id: &str,
+    role_id: &str,
+    menu_ids: Vec<String>,
+) -> ApiResult<()> {
+    let existed = get_role_menu_models(tenant_id, role_id).await?;
+    let target = menu_ids.into_iter().collect::<HashSet<_>>();
+    let existed_menu_ids = existed
+        .iter()
+        .map(|x| x.menu_id.clone())
+        .collect::<HashSet<_>>();
+    let db = database::get().await;
+    for model in existed {
+        if !target.contains(&model.menu_id) {
+            let mut active_model = model.into_active_model();
+            active_model.deleted = Set(true);
+            active_model.update(db).await?;
+        }
+    }
+    let added = target
+        .difference(&existed_menu_ids)
+        .cloned()
+        .collect::<Vec<_>>();
+    insert_role_menus(db, tenant_id, role_id, &added).await
+}
+
+/// 为指定租户下的角色新增菜单关联
+pub async fn insert_role_menus<C: ConnectionTrait>(
+    db: &C,
+    tenant_id: &str,
+    role_id: &str,
+    menu_ids: &[String],
+) -> ApiResult<()> {
+    for menu_id in menu_ids {
+        let mut active_model = system_role_menu::ActiveModel::new();
+        active_model.role_id = Set(String::from(role_id));
+        active_model.menu_id = Set(menu_id.clone());
+        active_model.tenant_id = Set(String::from(tenant_id));
+        active_model.insert(db).await?;
+    }
+    Ok(())
+}
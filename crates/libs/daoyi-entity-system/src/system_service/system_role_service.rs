@@ -1,9 +1,12 @@
 use crate::system_entity::prelude::*;
 use crate::system_entity::system_role;
 use daoyi_common_support::enumeration::redis_keys::RedisKey;
-use daoyi_common_support::enumeration::RoleCodeEnum;
+use daoyi_common_support::enumeration::{
+    CommonStatusEnum, DataScopeEnum, RoleCodeEnum, RoleTypeEnum,
+};
 use daoyi_common_support::error::{ApiError, ApiResult};
 use daoyi_common_support::{database, redis_utils};
+use sea_orm::Set;
 use sea_orm::prelude::*;
 
 pub async fn get_role_list_by_ids(ids: &Vec<String>) -> ApiResult<Vec<system_role::Model>> {
@@ -16,15 +19,25 @@ pub async fn get_role_list_by_ids(ids: &Vec<String>) -> ApiResult<Vec<system_rol
     Ok(list)
 }
 
+/// 忽略当前请求的租户上下文，获取指定租户的全部角色
+pub async fn get_role_list_by_tenant_id(tenant_id: &str) -> ApiResult<Vec<system_role::Model>> {
+    let db = database::get().await;
+    let list = SystemRole::find_ignore_tenant()
+        .filter(system_role::Column::TenantId.eq(tenant_id))
+        .all(db)
+        .await?;
+    Ok(list)
+}
+
 pub async fn has_any_super_admin(ids: &Vec<String>) -> ApiResult<bool> {
     if ids.is_empty() {
         return Ok(false);
     }
     for id in ids {
-        if let Ok(role) = get_role_from_cache(id).await {
-            if RoleCodeEnum::is_super_admin(&role.code) {
-                return Ok(true);
-            }
+        if let Ok(role) = get_role_from_cache(id).await
+            && RoleCodeEnum::is_super_admin(&role.code)
+        {
+            return Ok(true);
         }
     }
     Ok(false)
@@ -51,3 +64,21 @@ pub async fn get_role_from_cache(id: &str) -> ApiResult<system_role::Model> {
     redis_utils::cache_set_json(&redis_key, &role).await?;
     Ok(role)
 }
+
+/// 创建租户管理员角色，角色归属于指定租户
+pub async fn create_tenant_admin_role<C: ConnectionTrait>(
+    db: &C,
+    tenant_id: &str,
+) -> ApiResult<system_role::Model> {
+    let mut active_model = system_role::ActiveModel::new();
+    active_model.name = Set(String::from("租户管理员"));
+    active_model.code = Set(String::from("tenant_admin"));
+    active_model.sort = Set(0);
+    active_model.data_scope = Set(DataScopeEnum::ALL);
+    active_model.data_scope_dept_ids = Set(Default::default());
+    active_model.status = Set(CommonStatusEnum::Enable);
+    active_model.r#type = Set(RoleTypeEnum::SYSTEM);
+    active_model.remark = Set(Some(String::from("系统自动生成")));
+    active_model.tenant_id = Set(String::from(tenant_id));
+    Ok(active_model.insert(db).await?)
+}
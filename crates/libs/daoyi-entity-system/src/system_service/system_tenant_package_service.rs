@@ -0,0 +1,171 @@
+use crate::system_entity::prelude::*;
+use crate::system_entity::system_tenant_package;
+use crate::system_service::{system_role_menu_service, system_tenant_service};
+use daoyi_common_support::database;
+use daoyi_common_support::enumeration::CommonStatusEnum;
+use daoyi_common_support::error::{ApiError, ApiResult};
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::vo::system_vo::{
+    TenantPackagePageReqVO, TenantPackageRespVO, TenantPackageSaveReqVO,
+};
+use sea_orm::entity::prelude::*;
+use sea_orm::{IntoActiveModel, QueryOrder, QueryTrait, Set};
+use std::collections::HashSet;
+
+/// 系统租户使用的套餐编号，拥有全部菜单，不受套餐限制
+pub const SYSTEM_TENANT_PACKAGE_ID: &str = "0";
+
+pub async fn create_tenant_package(req: TenantPackageSaveReqVO) -> ApiResult<String> {
+    validate_tenant_package_name_unique(None, &req.name).await?;
+    let db = database::get().await;
+    let mut active_model = system_tenant_package::ActiveModel::new();
+    active_model.name = Set(req.name);
+    active_model.status = Set(req.status);
+    active_model.remark = Set(req.remark);
+    active_model.menu_ids = Set(req.menu_ids.into());
+    let model = active_model.insert(db).await?;
+    Ok(model.id)
+}
+
+pub async fn update_tenant_package(req: TenantPackageSaveReqVO) -> ApiResult<()> {
+    let id = req
+        .id
+        .as_deref()
+        .ok_or_else(|| ApiError::biz("租户套餐编号不能为空"))?;
+    let existed = get_tenant_package_by_id(id).await?;
+    validate_tenant_package_name_unique(Some(id), &req.name).await?;
+    let menu_changed = existed.menu_ids.iter().collect::<HashSet<_>>()
+        != req.menu_ids.iter().collect::<HashSet<_>>();
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.name = Set(req.name);
+    active_model.status = Set(req.status);
+    active_model.remark = Set(req.remark);
+    active_model.menu_ids = Set(req.menu_ids.clone().into());
+    active_model.update(db).await?;
+    // 菜单发生变化时，同步使用该套餐的租户的角色菜单
+    if menu_changed {
+        for tenant in system_tenant_service::get_tenant_list_by_package_id(id).await? {
+            system_role_menu_service::sync_tenant_role_menus(&tenant.id, &req.menu_ids).await?;
+        }
+    }
+    Ok(())
+}
+
+pub async fn delete_tenant_package(id: &str) -> ApiResult<()> {
+    let existed = get_tenant_package_by_id(id).await?;
+    if !system_tenant_service::get_tenant_list_by_package_id(id)
+        .await?
+        .is_empty()
+    {
+        return Err(ApiError::biz(
+            "租户正在使用该套餐，请给租户重新设置套餐后再尝试删除",
+        ));
+    }
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.deleted = Set(true);
+    active_model.update(db).await?;
+    Ok(())
+}
+
+pub async fn get_tenant_package_by_id(id: &str) -> ApiResult<system_tenant_package::Model> {
+    let db = database::get().await;
+    SystemTenantPackage::find_ignore_tenant()
+        .filter(system_tenant_package::Column::Id.eq(id))
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::biz("租户套餐不存在"))
+}
+
+pub async fn get_tenant_package_list_by_status(
+    status: Option<CommonStatusEnum>,
+) -> ApiResult<Vec<system_tenant_package::Model>> {
+    let db = database::get().await;
+    let list = SystemTenantPackage::find_perm()
+        .await
+        .apply_if(status, |query, status| {
+            query.filter(system_tenant_package::Column::Status.eq(status))
+        })
+        .order_by_desc(system_tenant_package::Column::Id)
+        .all(db)
+        .await?;
+    Ok(list)
+}
+
+pub async fn get_tenant_package_page(
+    req: TenantPackagePageReqVO,
+) -> ApiResult<Page<TenantPackageRespVO>> {
+    let db = database::get().await;
+    let paginator = SystemTenantPackage::find_perm()
+        .await
+        .apply_if(req.name.as_ref(), |query, name| {
+            query.filter(system_tenant_package::Column::Name.contains(name))
+        })
+        .apply_if(req.status, |query, status| {
+            query.filter(system_tenant_package::Column::Status.eq(status))
+        })
+        .order_by_desc(system_tenant_package::Column::Id)
+        .paginate(db, req.pagination.size);
+    let total = paginator.num_items().await?;
+    let items = paginator
+        .fetch_page(req.pagination.page - 1)
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+    Ok(Page::from_pagination(req.pagination, total, items))
+}
+
+/// 校验租户套餐是否有效：存在且处于开启状态
+pub async fn validate_tenant_package(id: &str) -> ApiResult<system_tenant_package::Model> {
+    let package = get_tenant_package_by_id(id).await?;
+    if package.status == CommonStatusEnum::Disable {
+        return Err(ApiError::biz(format!(
+            "名字为【{}】的租户套餐已被禁用",
+            package.name
+        )));
+    }
+    Ok(package)
+}
+
+/// 获得租户可使用的菜单编号，系统租户返回 None 表示不限制
+pub async fn get_tenant_menu_ids(tenant_id: &str) -> ApiResult<Option<HashSet<String>>> {
+    let tenant = system_tenant_service::get_tenant_by_id_ignore_tenant(tenant_id).await?;
+    if tenant.package_id == SYSTEM_TENANT_PACKAGE_ID {
+        return Ok(None);
+    }
+    let package = get_tenant_package_by_id(&tenant.package_id).await?;
+    if package.status == CommonStatusEnum::Disable {
+        return Ok(Some(HashSet::new()));
+    }
+    Ok(Some(package.menu_ids.into_iter().collect()))
+}
+
+/// 按租户套餐过滤菜单编号，只保留套餐内的菜单
+pub async fn filter_menu_ids_by_tenant(
+    tenant_id: &str,
+    menu_ids: Vec<String>,
+) -> ApiResult<Vec<String>> {
+    match get_tenant_menu_ids(tenant_id).await? {
+        Some(allowed) => Ok(menu_ids
+            .into_iter()
+            .filter(|id| allowed.contains(id))
+            .collect()),
+        None => Ok(menu_ids),
+    }
+}
+
+async fn validate_tenant_package_name_unique(id: Option<&str>, name: &str) -> ApiResult<()> {
+    let db = database::get().await;
+    let existed = SystemTenantPackage::find_ignore_tenant()
+        .filter(system_tenant_package::Column::Name.eq(name))
+        .one(db)
+        .await?;
+    if let Some(existed) = existed
+        && id != Some(existed.id.as_str())
+    {
+        return Err(ApiError::biz("已经存在该名字的租户套餐"));
+    }
+    Ok(())
+}
@@ -1,13 +1,23 @@
 use crate::system_entity::prelude::*;
 use crate::system_entity::system_tenant;
+use crate::system_service::{
+    system_access_token_service, system_dict_sync_service, system_role_menu_service,
+    system_role_service, system_tenant_package_service, system_user_role_service,
+    system_users_service,
+};
 use daoyi_common_support::enumeration::redis_keys::RedisKey;
 use daoyi_common_support::enumeration::CommonStatusEnum;
 use daoyi_common_support::error::{ApiError, ApiResult};
-use daoyi_common_support::vo::system_vo::TenantRespVO;
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::vo::system_vo::{
+    TenantPageReqVO, TenantRespVO, TenantSaveReqVO, TenantUpdateExpireTimeReqVO,
+    TenantUpdateStatusReqVO,
+};
+use daoyi_common_support::database::json_array;
 use daoyi_common_support::{database, redis_utils};
 use sea_orm::entity::prelude::*;
-use sea_orm::sqlx::types::chrono::Local;
-use sea_orm::QueryTrait;
+use chrono::Local;
+use sea_orm::{IntoActiveModel, QueryOrder, QueryTrait, Set, TransactionTrait};
 
 pub async fn get_tenant_list_by_status(
     status: Option<CommonStatusEnum>,
@@ -33,6 +43,27 @@ pub async fn get_tenant_by_id(tenant_id: &str) -> ApiResult<system_tenant::Model
     Ok(option)
 }
 
+/// 忽略当前请求的租户上下文，按编号获取租户
+pub async fn get_tenant_by_id_ignore_tenant(tenant_id: &str) -> ApiResult<system_tenant::Model> {
+    let db = database::get().await;
+    SystemTenant::find_ignore_tenant()
+        .filter(system_tenant::Column::Id.eq(tenant_id))
+        .one(db)
+        .await?
+        .ok_or_else(|| ApiError::biz("租户不存在"))
+}
+
+pub async fn get_tenant_list_by_package_id(
+    package_id: &str,
+) -> ApiResult<Vec<system_tenant::Model>> {
+    let db = database::get().await;
+    let list = SystemTenant::find_ignore_tenant()
+        .filter(system_tenant::Column::PackageId.eq(package_id))
+        .all(db)
+        .await?;
+    Ok(list)
+}
+
 pub async fn get_tenant_by_name(name: &str) -> ApiResult<system_tenant::Model> {
     let db = database::get().await;
     let option = SystemTenant::find_perm()
@@ -44,15 +75,33 @@ pub async fn get_tenant_by_name(name: &str) -> ApiResult<system_tenant::Model> {
     Ok(option)
 }
 
+/// 按域名获取租户：优先精确匹配，其次匹配 `*.example.com` 形式的通配子域名
 pub async fn get_tenant_by_website(website: &str) -> ApiResult<system_tenant::Model> {
+    let website = normalize_website(website);
     let db = database::get().await;
-    let option = SystemTenant::find_perm()
-        .await
-        .filter(system_tenant::Column::Websites.eq(website))
+    let exact = SystemTenant::find_ignore_tenant()
+        .filter(json_array::contains_any(
+            db.get_database_backend(),
+            system_tenant::Column::Websites,
+            std::slice::from_ref(&website),
+        ))
         .one(db)
+        .await?;
+    if let Some(tenant) = exact {
+        return Ok(tenant);
+    }
+    // 通配域名无法直接在数据库中匹配，租户数量有限，加载后逐个匹配
+    SystemTenant::find_ignore_tenant()
+        .all(db)
         .await?
-        .ok_or_else(|| ApiError::biz("租户不存在"))?;
-    Ok(option)
+        .into_iter()
+        .find(|tenant| {
+            tenant
+                .websites
+                .iter()
+                .any(|pattern| website_matches(pattern, &website))
+        })
+        .ok_or_else(|| ApiError::biz("租户不存在"))
 }
 
 pub async fn check_tenant_id(tenant_id: &str) -> ApiResult<TenantRespVO> {
@@ -76,3 +125,242 @@ pub async fn check_tenant_id(tenant_id: &str) -> ApiResult<TenantRespVO> {
     }
     Ok(vo)
 }
+
+/// 创建租户：在同一事务中创建租户、租户管理员角色（拥有套餐全部菜单）和管理员账号，
+/// 并将管理员账号回写为租户联系人
+pub async fn create_tenant(req: TenantSaveReqVO) -> ApiResult<String> {
+    validate_tenant_name_unique(None, &req.name).await?;
+    let package = system_tenant_package_service::validate_tenant_package(&req.package_id).await?;
+    let username = req
+        .username
+        .filter(|u| !u.is_empty())
+        .ok_or_else(|| ApiError::biz("租户管理员账号不能为空"))?;
+    let password = req
+        .password
+        .filter(|p| !p.is_empty())
+        .ok_or_else(|| ApiError::biz("租户管理员密码不能为空"))?;
+    let db = database::get().await;
+    let txn = db.begin().await?;
+    // 1. 创建租户
+    let mut active_model = system_tenant::ActiveModel::new();
+    active_model.name = Set(req.name);
+    active_model.contact_name = Set(req.contact_name.clone());
+    active_model.contact_mobile = Set(req.contact_mobile.clone());
+    active_model.status = Set(req.status);
+    active_model.websites = Set(req.websites.into());
+    active_model.package_id = Set(req.package_id);
+    active_model.expire_time = Set(req.expire_time);
+    active_model.account_count = Set(req.account_count);
+    let tenant = active_model.insert(&txn).await?;
+    // 2. 创建租户管理员角色，并分配套餐菜单
+    let role = system_role_service::create_tenant_admin_role(&txn, &tenant.id).await?;
+    system_role_menu_service::insert_role_menus(&txn, &tenant.id, &role.id, &package.menu_ids)
+        .await?;
+    // 3. 创建租户管理员账号，并分配角色
+    let user = system_users_service::create_tenant_admin_user(
+        &txn,
+        &tenant.id,
+        username,
+        password,
+        req.contact_name,
+        req.contact_mobile,
+    )
+    .await?;
+    system_user_role_service::create_user_role(&txn, &tenant.id, &user.id, &role.id).await?;
+    // 4. 初始化枚举字典
+    system_dict_sync_service::sync_enum_dicts_for_tenant(&txn, &tenant.id).await?;
+    // 5. 回写租户联系人
+    let tenant_id = tenant.id.clone();
+    let mut active_model = tenant.into_active_model();
+    active_model.contact_user_id = Set(Some(user.id));
+    active_model.update(&txn).await?;
+    txn.commit().await?;
+    Ok(tenant_id)
+}
+
+pub async fn update_tenant(req: TenantSaveReqVO) -> ApiResult<()> {
+    let id = req.id.as_deref().ok_or_else(|| ApiError::biz("租户编号不能为空"))?;
+    let existed = get_tenant_by_id_ignore_tenant(id).await?;
+    validate_update_tenant(&existed)?;
+    validate_tenant_name_unique(Some(id), &req.name).await?;
+    let package = system_tenant_package_service::validate_tenant_package(&req.package_id).await?;
+    let package_changed = existed.package_id != req.package_id;
+    let status_changed = existed.status != req.status;
+    evict_tenant_website_cache(&existed.websites).await?;
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.name = Set(req.name);
+    active_model.contact_name = Set(req.contact_name);
+    active_model.contact_mobile = Set(req.contact_mobile);
+    active_model.status = Set(req.status);
+    active_model.websites = Set(req.websites.into());
+    active_model.package_id = Set(req.package_id);
+    active_model.expire_time = Set(req.expire_time);
+    active_model.account_count = Set(req.account_count);
+    active_model.update(db).await?;
+    // 套餐变化时，同步租户的角色菜单
+    if package_changed {
+        system_role_menu_service::sync_tenant_role_menus(id, &package.menu_ids).await?;
+    }
+    evict_tenant_cache(id).await?;
+    if status_changed && req.status == CommonStatusEnum::Disable {
+        system_access_token_service::revoke_tenant_tokens(id).await?;
+    }
+    Ok(())
+}
+
+/// 启用或禁用租户，禁用时吊销租户下的全部令牌
+pub async fn update_tenant_status(req: TenantUpdateStatusReqVO) -> ApiResult<()> {
+    let existed = get_tenant_by_id_ignore_tenant(&req.id).await?;
+    validate_update_tenant(&existed)?;
+    evict_tenant_website_cache(&existed.websites).await?;
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.status = Set(req.status);
+    active_model.update(db).await?;
+    evict_tenant_cache(&req.id).await?;
+    if req.status == CommonStatusEnum::Disable {
+        system_access_token_service::revoke_tenant_tokens(&req.id).await?;
+    }
+    Ok(())
+}
+
+/// 延长或调整租户过期时间
+pub async fn update_tenant_expire_time(req: TenantUpdateExpireTimeReqVO) -> ApiResult<()> {
+    let existed = get_tenant_by_id_ignore_tenant(&req.id).await?;
+    validate_update_tenant(&existed)?;
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.expire_time = Set(req.expire_time);
+    active_model.update(db).await?;
+    evict_tenant_cache(&req.id).await
+}
+
+/// 禁用已过期的租户并吊销其令牌，返回禁用数量，供定时任务调用
+pub async fn disable_expired_tenants() -> ApiResult<u64> {
+    let db = database::get().await;
+    let tenants = SystemTenant::find_ignore_tenant()
+        .filter(system_tenant::Column::Status.eq(CommonStatusEnum::Enable))
+        .filter(system_tenant::Column::ExpireTime.lt(Local::now().naive_local()))
+        .filter(
+            system_tenant::Column::PackageId
+                .ne(system_tenant_package_service::SYSTEM_TENANT_PACKAGE_ID),
+        )
+        .all(db)
+        .await?;
+    let count = tenants.len() as u64;
+    for tenant in tenants {
+        let tenant_id = tenant.id.clone();
+        evict_tenant_website_cache(&tenant.websites).await?;
+        let mut active_model = tenant.into_active_model();
+        active_model.status = Set(CommonStatusEnum::Disable);
+        active_model.update(db).await?;
+        evict_tenant_cache(&tenant_id).await?;
+        system_access_token_service::revoke_tenant_tokens(&tenant_id).await?;
+    }
+    Ok(count)
+}
+
+pub async fn delete_tenant(id: &str) -> ApiResult<()> {
+    let existed = get_tenant_by_id_ignore_tenant(id).await?;
+    validate_update_tenant(&existed)?;
+    evict_tenant_website_cache(&existed.websites).await?;
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.deleted = Set(true);
+    active_model.update(db).await?;
+    evict_tenant_cache(id).await?;
+    system_access_token_service::revoke_tenant_tokens(id).await
+}
+
+pub async fn get_tenant_page(req: TenantPageReqVO) -> ApiResult<Page<TenantRespVO>> {
+    let db = database::get().await;
+    let paginator = SystemTenant::find_perm()
+        .await
+        .apply_if(req.name.as_ref(), |query, name| {
+            query.filter(system_tenant::Column::Name.contains(name))
+        })
+        .apply_if(req.contact_name.as_ref(), |query, contact_name| {
+            query.filter(system_tenant::Column::ContactName.contains(contact_name))
+        })
+        .apply_if(req.contact_mobile.as_ref(), |query, contact_mobile| {
+            query.filter(system_tenant::Column::ContactMobile.contains(contact_mobile))
+        })
+        .apply_if(req.status, |query, status| {
+            query.filter(system_tenant::Column::Status.eq(status))
+        })
+        .order_by_desc(system_tenant::Column::Id)
+        .paginate(db, req.pagination.size);
+    let total = paginator.num_items().await?;
+    let items = paginator
+        .fetch_page(req.pagination.page - 1)
+        .await?
+        .into_iter()
+        .map(|x| x.into())
+        .collect();
+    Ok(Page::from_pagination(req.pagination, total, items))
+}
+
+fn validate_update_tenant(tenant: &system_tenant::Model) -> ApiResult<()> {
+    if tenant.package_id == system_tenant_package_service::SYSTEM_TENANT_PACKAGE_ID {
+        return Err(ApiError::biz("系统租户不能进行修改、删除等操作！"));
+    }
+    Ok(())
+}
+
+async fn validate_tenant_name_unique(id: Option<&str>, name: &str) -> ApiResult<()> {
+    let db = database::get().await;
+    let existed = SystemTenant::find_ignore_tenant()
+        .filter(system_tenant::Column::Name.eq(name))
+        .one(db)
+        .await?;
+    if let Some(existed) = existed
+        && id != Some(existed.id.as_str())
+    {
+        return Err(ApiError::biz(format!("名字为【{name}】的租户已存在")));
+    }
+    Ok(())
+}
+
+async fn evict_tenant_cache(tenant_id: &str) -> ApiResult<()> {
+    redis_utils::cache_del(&RedisKey::CheckTenantId.key(tenant_id)).await
+}
+
+/// 清除域名解析租户的缓存，通配域名解析出的缓存只能等待过期
+async fn evict_tenant_website_cache(websites: &[String]) -> ApiResult<()> {
+    for website in websites {
+        redis_utils::cache_del(&RedisKey::TenantIdByWebsite.key(normalize_website(website)))
+            .await?;
+    }
+    Ok(())
+}
+
+/// 统一域名格式：去除首尾空白、端口号并转为小写
+fn normalize_website(website: &str) -> String {
+    let website = website.trim().to_ascii_lowercase();
+    match website.rsplit_once(':') {
+        Some((host, port)) if port.chars().all(|c| c.is_ascii_digit()) => host.to_string(),
+        _ => website,
+    }
+}
+
+/// 判断域名是否匹配：`*.example.com` 匹配任意层级的子域名，但不匹配 `example.com` 本身
+fn website_matches(pattern: &str, website: &str) -> bool {
+    let pattern = normalize_website(pattern);
+    match pattern.strip_prefix("*.") {
+        Some(suffix) => website
+            .strip_suffix(suffix)
+            .is_some_and(|prefix| prefix.len() > 1 && prefix.ends_with('.')),
+        None => pattern == website,
+    }
+}
+
+#[test]
+fn test_website_matches() {
+    assert!(website_matches("a.example.com", "a.example.com"));
+    assert!(website_matches("*.example.com", "a.example.com"));
+    assert!(website_matches("*.example.com", "b.a.example.com"));
+    assert!(!website_matches("*.example.com", "example.com"));
+    assert!(!website_matches("*.example.com", "badexample.com"));
+    assert!(website_matches("*.Example.com:8080", &normalize_website("A.example.com:80")));
+}
@@ -2,6 +2,7 @@ use crate::system_entity::prelude::*;
 use crate::system_entity::system_user_role;
 use daoyi_common_support::database;
 use daoyi_common_support::error::ApiResult;
+use sea_orm::Set;
 use sea_orm::prelude::*;
 use std::collections::HashSet;
 
@@ -19,3 +20,17 @@ pub async fn get_user_role_id_list_by_user_id(user_id: &str) -> ApiResult<Vec<St
         .collect();
     Ok(list)
 }
+
+pub async fn create_user_role<C: ConnectionTrait>(
+    db: &C,
+    tenant_id: &str,
+    user_id: &str,
+    role_id: &str,
+) -> ApiResult<()> {
+    let mut active_model = system_user_role::ActiveModel::new();
+    active_model.user_id = Set(String::from(user_id));
+    active_model.role_id = Set(String::from(role_id));
+    active_model.tenant_id = Set(String::from(tenant_id));
+    active_model.insert(db).await?;
+    Ok(())
+}
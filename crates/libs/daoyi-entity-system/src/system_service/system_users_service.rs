@@ -1,8 +1,17 @@
 use crate::system_entity::prelude::*;
 use crate::system_entity::system_users;
+use crate::system_service::system_post_service;
+use daoyi_common_support::context::HttpRequestContext;
 use daoyi_common_support::database;
+use daoyi_common_support::database::json_array;
+use daoyi_common_support::enumeration::CommonStatusEnum;
 use daoyi_common_support::error::{ApiError, ApiResult};
+use daoyi_common_support::models::pagination::Page;
+use daoyi_common_support::vo::system_vo::{UserPageReqVO, UserRespVO, UserSaveReqVO, UserVO};
 use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::SimpleExpr;
+use chrono::Local;
+use sea_orm::{DbBackend, IntoActiveModel, QueryOrder, QueryTrait, Set};
 
 pub async fn get_by_username(username: &str) -> ApiResult<Option<system_users::Model>> {
     let db = database::get().await;
@@ -14,6 +23,13 @@ pub async fn get_by_username(username: &str) -> ApiResult<Option<system_users::M
     Ok(option)
 }
 
+/// 获得当前登录用户编号，未登录时返回未授权错误
+pub async fn get_login_user_id() -> ApiResult<String> {
+    HttpRequestContext::get_login_id()
+        .await
+        .ok_or_else(|| ApiError::unauthenticated("用户未登录"))
+}
+
 pub async fn get_by_id(id: &str) -> ApiResult<system_users::Model> {
     let db = database::get().await;
     SystemUsers::find_perm()
@@ -23,3 +39,153 @@ pub async fn get_by_id(id: &str) -> ApiResult<system_users::Model> {
         .await?
         .ok_or(ApiError::biz("用户不存在"))
 }
+
+pub async fn create_user(req: UserSaveReqVO) -> ApiResult<String> {
+    validate_user_for_create_or_update(None, &req).await?;
+    let password = req
+        .password
+        .filter(|p| !p.is_empty())
+        .ok_or_else(|| ApiError::biz("密码不能为空"))?;
+    let db = database::get().await;
+    let mut active_model = system_users::ActiveModel::new();
+    active_model.username = Set(req.username);
+    active_model.password = Set(password);
+    active_model.nickname = Set(req.nickname);
+    active_model.remark = Set(req.remark);
+    active_model.dept_id = Set(req.dept_id);
+    active_model.post_ids = Set(req.post_ids.map(Into::into));
+    active_model.email = Set(req.email);
+    active_model.mobile = Set(req.mobile);
+    active_model.sex = Set(req.sex);
+    active_model.avatar = Set(req.avatar);
+    active_model.status = Set(CommonStatusEnum::Enable);
+    let model = active_model.insert(db).await?;
+    Ok(model.id)
+}
+
+/// 创建租户管理员账号，账号归属于指定租户
+pub async fn create_tenant_admin_user<C: ConnectionTrait>(
+    db: &C,
+    tenant_id: &str,
+    username: String,
+    password: String,
+    nickname: String,
+    mobile: Option<String>,
+) -> ApiResult<system_users::Model> {
+    let mut active_model = system_users::ActiveModel::new();
+    active_model.username = Set(username);
+    active_model.password = Set(password);
+    active_model.nickname = Set(nickname);
+    active_model.mobile = Set(mobile);
+    active_model.status = Set(CommonStatusEnum::Enable);
+    active_model.tenant_id = Set(String::from(tenant_id));
+    Ok(active_model.insert(db).await?)
+}
+
+pub async fn update_user(req: UserSaveReqVO) -> ApiResult<()> {
+    let id = req
+        .id
+        .as_deref()
+        .ok_or_else(|| ApiError::biz("用户编号不能为空"))?;
+    let existed = get_by_id(id).await?;
+    validate_user_for_create_or_update(Some(id), &req).await?;
+    let db = database::get().await;
+    let mut active_model = existed.into_active_model();
+    active_model.username = Set(req.username);
+    active_model.nickname = Set(req.nickname);
+    active_model.remark = Set(req.remark);
+    active_model.dept_id = Set(req.dept_id);
+    active_model.post_ids = Set(req.post_ids.map(Into::into));
+    active_model.email = Set(req.email);
+    active_model.mobile = Set(req.mobile);
+    active_model.sex = Set(req.sex);
+    active_model.avatar = Set(req.avatar);
+    active_model.update(db).await?;
+    Ok(())
+}
+
+/// 登录成功后更新用户最后登录的 IP 与时间
+pub async fn update_user_login(user: system_users::Model, login_ip: &str) -> ApiResult<()> {
+    let db = database::get().await;
+    let mut active_model = user.into_active_model();
+    active_model.login_ip = Set(Some(login_ip.to_string()));
+    active_model.login_date = Set(Some(Local::now().naive_local()));
+    active_model.update(db).await?;
+    Ok(())
+}
+
+pub async fn get_user_page(req: UserPageReqVO) -> ApiResult<Page<UserRespVO>> {
+    let db = database::get().await;
+    let post_ids = req.post_id.map(|post_id| vec![post_id]);
+    let paginator = SystemUsers::find_perm()
+        .await
+        .apply_if(req.username.as_ref(), |query, username| {
+            query.filter(system_users::Column::Username.contains(username))
+        })
+        .apply_if(req.mobile.as_ref(), |query, mobile| {
+            query.filter(system_users::Column::Mobile.contains(mobile))
+        })
+        .apply_if(req.status, |query, status| {
+            query.filter(system_users::Column::Status.eq(status))
+        })
+        .apply_if(req.dept_id.as_ref(), |query, dept_id| {
+            query.filter(system_users::Column::DeptId.eq(dept_id))
+        })
+        .apply_if(post_ids.as_ref(), |query, post_ids| {
+            query.filter(post_ids_overlap(db.get_database_backend(), post_ids))
+        })
+        .order_by_desc(system_users::Column::Id)
+        .paginate(db, req.pagination.size);
+    let total = paginator.num_items().await?;
+    let mut items = vec![];
+    for model in paginator.fetch_page(req.pagination.page - 1).await? {
+        items.push(build_user_resp_vo(model).await?);
+    }
+    Ok(Page::from_pagination(req.pagination, total, items))
+}
+
+/// 获得拥有任一指定岗位的用户列表
+pub async fn get_user_list_by_post_ids(post_ids: &[String]) -> ApiResult<Vec<system_users::Model>> {
+    if post_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let db = database::get().await;
+    let list = SystemUsers::find_perm()
+        .await
+        .filter(post_ids_overlap(db.get_database_backend(), post_ids))
+        .all(db)
+        .await?;
+    Ok(list)
+}
+
+pub async fn build_user_vo(model: system_users::Model) -> ApiResult<UserVO> {
+    let post_ids = model.post_ids.clone().unwrap_or_default();
+    let mut vo: UserVO = model.into();
+    vo.post_names = system_post_service::get_post_names(database::get().await, &post_ids).await?;
+    Ok(vo)
+}
+
+pub async fn build_user_resp_vo(model: system_users::Model) -> ApiResult<UserRespVO> {
+    let mut vo: UserRespVO = model.into();
+    vo.post_names = system_post_service::get_post_names(database::get().await, &vo.post_ids).await?;
+    Ok(vo)
+}
+
+fn post_ids_overlap(backend: DbBackend, post_ids: &[String]) -> SimpleExpr {
+    json_array::contains_any(backend, system_users::Column::PostIds, post_ids)
+}
+
+async fn validate_user_for_create_or_update(
+    id: Option<&str>,
+    req: &UserSaveReqVO,
+) -> ApiResult<()> {
+    if let Some(existed) = get_by_username(&req.username).await?
+        && id != Some(existed.id.as_str())
+    {
+        return Err(ApiError::biz("用户账号已经存在"));
+    }
+    if let Some(post_ids) = req.post_ids.as_ref() {
+        system_post_service::validate_post_list(database::get().await, post_ids).await?;
+    }
+    Ok(())
+}
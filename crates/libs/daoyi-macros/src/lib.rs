@@ -51,6 +51,12 @@ pub fn daoyi_model(_args: TokenStream, input: TokenStream) -> TokenStream {
                 }
                 query
             }
+
+            /// 忽略租户过滤，仅排除已删除数据，用于跨租户的系统级查询
+            pub fn find_ignore_tenant() -> sea_orm::Select<Entity> {
+                use sea_orm::{EntityTrait, QueryFilter, ColumnTrait};
+                <Self as EntityTrait>::find().filter(Column::Deleted.eq(false))
+            }
         }
     })
 }
@@ -102,7 +108,7 @@ pub fn derive_active_model_behavior(input: TokenStream) -> TokenStream {
                 use daoyi_common_support::id;
                 use daoyi_common_support::password::hash_password;
                 use daoyi_common_support::context::HttpRequestContext;
-                use sea_orm::sqlx::types::chrono::Local;
+                use chrono::Local;
 
                 if insert {
                     self.id = Set(id::next_string());
@@ -113,7 +119,9 @@ pub fn derive_active_model_behavior(input: TokenStream) -> TokenStream {
                         self.creator = Set(Some(login_id.clone()));
                         self.updater = Set(Some(login_id));
                     }
-                    if let Ok(tenant_id) = HttpRequestContext::get_tenant_id_as_string().await {
+                    if self.tenant_id.is_not_set()
+                        && let Ok(tenant_id) = HttpRequestContext::get_tenant_id_as_string().await
+                    {
                         self.tenant_id = Set(tenant_id);
                     }
                 } else {
@@ -138,7 +146,7 @@ pub fn derive_active_model_behavior(input: TokenStream) -> TokenStream {
 ///
 /// # 示例
 ///
-/// ```rust
+/// ```rust,ignore
 /// #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
 /// #[sea_orm(schema_name = "demo", table_name = "sys_user")]
 /// #[derive(BeforeInsert)]
@@ -236,7 +244,7 @@ pub fn derive_before_insert(input: TokenStream) -> TokenStream {
 ///
 /// # 示例
 ///
-/// ```rust
+/// ```rust,ignore
 /// #[derive(DaoyiIntoActiveValue)]
 /// pub enum Gender {
 ///     Male,
@@ -258,3 +266,234 @@ pub fn derive_daoyi_into_active_value(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// 自动实现基于字典数据的异步校验 `ValidateDict`
+///
+/// 在字段上标注 `#[validate_dict("字典类型")]`，`ValidJson`/`ValidQuery` 会在常规校验通过后
+/// 检查字段值是否为该字典类型下开启的字典键值。未标注任何字段时生成空实现。
+///
+/// # 示例
+///
+/// ```rust,ignore
+/// #[derive(Deserialize, Validate, ValidateDict)]
+/// pub struct UserSaveReqVO {
+///     #[validate_dict("system_user_sex")]
+///     pub sex: Option<String>,
+/// }
+/// ```
+#[proc_macro_derive(ValidateDict, attributes(validate_dict))]
+pub fn derive_validate_dict(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => panic!("ValidateDict only supports structs with named fields"),
+        },
+        _ => panic!("ValidateDict only supports structs"),
+    };
+
+    // 收集需要字典校验的字段及其字典类型
+    let mut validate_statements = Vec::new();
+    for field in fields {
+        let field_name = field.ident.as_ref().unwrap();
+        let field_name_str = field_name.to_string();
+        for attr in &field.attrs {
+            if !attr.path().is_ident("validate_dict") {
+                continue;
+            }
+            let dict_type: syn::LitStr = attr.parse_args().expect(
+                "validate_dict expects a dict type, e.g. #[validate_dict(\"system_user_sex\")]",
+            );
+            validate_statements.push(quote! {
+                if let Some(error) = daoyi_common_support::request::validation::validate_dict_value(
+                    &self.#field_name,
+                    #dict_type,
+                )
+                .await?
+                {
+                    errors.add(#field_name_str, error);
+                }
+            });
+        }
+    }
+
+    let body = if validate_statements.is_empty() {
+        quote! { Ok(()) }
+    } else {
+        quote! {
+            let mut errors = daoyi_common_support::request::validation::ValidationErrors::new();
+            #(#validate_statements)*
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(errors.into())
+            }
+        }
+    };
+
+    let expanded = quote! {
+        impl #impl_generics daoyi_common_support::request::validation::ValidateDict
+            for #name #ty_generics #where_clause
+        {
+            async fn validate_dict(&self) -> daoyi_common_support::error::ApiResult<()> {
+                #body
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// 为枚举生成字典定义 `DaoyiDict`，启动时同步到 `system_dict_type`/`system_dict_data`
+///
+/// - 枚举上：`#[dict(dict_type = "...", name = "...")]` 指定字典类型与名称
+/// - 变体上：`#[dict(label = "...", sort = 1, color_type = "...", css_class = "...")]`，
+///   仅 `label` 必填，`sort` 默认为变体序号（从 1 开始）
+/// - 字典键值默认取 `#[sea_orm(string_value = "...")]`，也可用 `#[dict(value = "...")]` 指定
+///
+/// 同时实现 `DictValue`，枚举字段可直接使用 `#[validate_dict]` 校验。
+///
+/// # 示例
+///
+/// ```rust,ignore
+/// #[derive(DeriveActiveEnum, DaoyiDict)]
+/// #[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+/// #[dict(dict_type = "common_status", name = "系统状态")]
+/// pub enum CommonStatusEnum {
+///     #[sea_orm(string_value = "0")]
+///     #[dict(label = "开启", color_type = "primary")]
+///     Enable,
+///     #[sea_orm(string_value = "1")]
+///     #[dict(label = "关闭", color_type = "info")]
+///     Disable,
+/// }
+/// ```
+#[proc_macro_derive(DaoyiDict, attributes(dict))]
+pub fn derive_daoyi_dict(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => panic!("DaoyiDict only supports enums"),
+    };
+
+    // 解析枚举上的字典类型与名称
+    let mut dict_type = None;
+    let mut dict_name = None;
+    for attr in input.attrs.iter().filter(|x| x.path().is_ident("dict")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("dict_type") {
+                dict_type = Some(meta.value()?.parse::<syn::LitStr>()?);
+            } else if meta.path.is_ident("name") {
+                dict_name = Some(meta.value()?.parse::<syn::LitStr>()?);
+            } else {
+                return Err(meta.error("unsupported dict attribute"));
+            }
+            Ok(())
+        })
+        .expect("failed to parse #[dict(...)] on enum");
+    }
+    let dict_type = dict_type.expect("DaoyiDict requires #[dict(dict_type = \"...\")]");
+    let dict_name = dict_name.expect("DaoyiDict requires #[dict(name = \"...\")]");
+
+    let mut data_definitions = Vec::new();
+    let mut value_arms = Vec::new();
+    for (index, variant) in variants.iter().enumerate() {
+        let variant_name = &variant.ident;
+        let mut value = None;
+        let mut label = None;
+        let mut sort = None;
+        let mut color_type = None;
+        let mut css_class = None;
+        for attr in &variant.attrs {
+            if attr.path().is_ident("sea_orm") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("string_value") {
+                        value.get_or_insert(meta.value()?.parse::<syn::LitStr>()?);
+                    } else if meta.input.peek(syn::Token![=]) {
+                        meta.value()?.parse::<syn::Expr>()?;
+                    }
+                    Ok(())
+                })
+                .expect("failed to parse #[sea_orm(...)] on variant");
+            } else if attr.path().is_ident("dict") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("value") {
+                        value = Some(meta.value()?.parse::<syn::LitStr>()?);
+                    } else if meta.path.is_ident("label") {
+                        label = Some(meta.value()?.parse::<syn::LitStr>()?);
+                    } else if meta.path.is_ident("sort") {
+                        sort = Some(meta.value()?.parse::<syn::LitInt>()?);
+                    } else if meta.path.is_ident("color_type") {
+                        color_type = Some(meta.value()?.parse::<syn::LitStr>()?);
+                    } else if meta.path.is_ident("css_class") {
+                        css_class = Some(meta.value()?.parse::<syn::LitStr>()?);
+                    } else {
+                        return Err(meta.error("unsupported dict attribute"));
+                    }
+                    Ok(())
+                })
+                .expect("failed to parse #[dict(...)] on variant");
+            }
+        }
+        let value = value.unwrap_or_else(|| {
+            panic!("variant {variant_name} requires #[sea_orm(string_value)] or #[dict(value)]")
+        });
+        let label = label
+            .unwrap_or_else(|| panic!("variant {variant_name} requires #[dict(label = \"...\")]"));
+        let sort = match sort {
+            Some(sort) => quote! { #sort },
+            None => {
+                let sort = index as i32 + 1;
+                quote! { #sort }
+            }
+        };
+        let color_type = match color_type {
+            Some(color_type) => quote! { Some(#color_type) },
+            None => quote! { None },
+        };
+        let css_class = match css_class {
+            Some(css_class) => quote! { Some(#css_class) },
+            None => quote! { None },
+        };
+        data_definitions.push(quote! {
+            daoyi_common_support::dict::DictDataDefinition {
+                value: #value,
+                label: #label,
+                sort: #sort,
+                color_type: #color_type,
+                css_class: #css_class,
+            }
+        });
+        value_arms.push(quote! { Self::#variant_name => #value });
+    }
+
+    let expanded = quote! {
+        impl daoyi_common_support::dict::DaoyiDict for #name {
+            const DICT: daoyi_common_support::dict::DictTypeDefinition =
+                daoyi_common_support::dict::DictTypeDefinition {
+                    dict_type: #dict_type,
+                    name: #dict_name,
+                    data: &[#(#data_definitions),*],
+                };
+
+            fn dict_value(&self) -> &'static str {
+                match self {
+                    #(#value_arms),*
+                }
+            }
+        }
+
+        impl daoyi_common_support::request::validation::DictValue for #name {
+            fn dict_values(&self) -> Vec<String> {
+                vec![daoyi_common_support::dict::DaoyiDict::dict_value(self).to_string()]
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
@@ -0,0 +1,221 @@
+//! 迁移使用的建表辅助函数，列定义与 `docs/db/*.sql` 保持一致，按数据库类型生成可移植的 DDL
+
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::{ConnectionTrait, DbBackend};
+
+pub const SYSTEM: &str = "system";
+pub const DEMO: &str = "demo";
+
+pub fn table_ref(schema: &str, name: &str) -> TableRef {
+    (Alias::new(schema), Alias::new(name)).into_table_ref()
+}
+
+pub fn table(schema: &str, name: &str) -> TableCreateStatement {
+    Table::create()
+        .table(table_ref(schema, name))
+        .if_not_exists()
+        .to_owned()
+}
+
+/// 创建 schema：Postgres 为 schema，MySQL 为同名数据库，SQLite 的 schema 是连接时附加的数据库文件
+pub async fn create_schema(manager: &SchemaManager<'_>, schema: &str) -> Result<(), DbErr> {
+    let sql = match manager.get_database_backend() {
+        DbBackend::Postgres => format!("CREATE SCHEMA IF NOT EXISTS \"{schema}\""),
+        DbBackend::MySql => format!("CREATE DATABASE IF NOT EXISTS `{schema}`"),
+        DbBackend::Sqlite => return Ok(()),
+    };
+    manager.get_connection().execute_unprepared(&sql).await?;
+    Ok(())
+}
+
+/// 创建索引，SQLite 的索引名需要带 schema，表名不能带 schema
+pub async fn create_index(
+    manager: &SchemaManager<'_>,
+    schema: &str,
+    table: &str,
+    name: &str,
+    columns: &[&str],
+) -> Result<(), DbErr> {
+    let backend = manager.get_database_backend();
+    if backend == DbBackend::Sqlite {
+        let columns = columns
+            .iter()
+            .map(|column| format!("\"{column}\""))
+            .collect::<Vec<_>>()
+            .join(", ");
+        manager
+            .get_connection()
+            .execute_unprepared(&format!(
+                "CREATE INDEX IF NOT EXISTS \"{schema}\".\"{name}\" ON \"{table}\" ({columns})"
+            ))
+            .await?;
+        return Ok(());
+    }
+    let mut index = Index::create();
+    index.name(name).table(table_ref(schema, table));
+    // MySQL 不支持 CREATE INDEX IF NOT EXISTS
+    if backend != DbBackend::MySql {
+        index.if_not_exists();
+    }
+    for column in columns {
+        index.col(Alias::new(*column));
+    }
+    manager.create_index(index).await
+}
+
+pub async fn drop_tables(
+    manager: &SchemaManager<'_>,
+    schema: &str,
+    tables: &[&str],
+) -> Result<(), DbErr> {
+    for name in tables.iter().rev() {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(table_ref(schema, name))
+                    .if_exists()
+                    .to_owned(),
+            )
+            .await?;
+    }
+    Ok(())
+}
+
+/// 主键，雪花算法生成的字符串编号
+pub fn id() -> ColumnDef {
+    ColumnDef::new(Alias::new("id"))
+        .string_len(32)
+        .not_null()
+        .primary_key()
+        .to_owned()
+}
+
+pub fn string(name: &str, len: u32) -> ColumnDef {
+    ColumnDef::new(Alias::new(name))
+        .string_len(len)
+        .not_null()
+        .to_owned()
+}
+
+pub fn string_null(name: &str, len: u32) -> ColumnDef {
+    ColumnDef::new(Alias::new(name))
+        .string_len(len)
+        .null()
+        .to_owned()
+}
+
+pub fn text(name: &str) -> ColumnDef {
+    ColumnDef::new(Alias::new(name))
+        .text()
+        .not_null()
+        .to_owned()
+}
+
+pub fn text_null(name: &str) -> ColumnDef {
+    ColumnDef::new(Alias::new(name)).text().null().to_owned()
+}
+
+pub fn integer(name: &str) -> ColumnDef {
+    ColumnDef::new(Alias::new(name))
+        .integer()
+        .not_null()
+        .to_owned()
+}
+
+pub fn big_integer(name: &str) -> ColumnDef {
+    ColumnDef::new(Alias::new(name))
+        .big_integer()
+        .not_null()
+        .to_owned()
+}
+
+pub fn big_integer_null(name: &str) -> ColumnDef {
+    ColumnDef::new(Alias::new(name))
+        .big_integer()
+        .null()
+        .to_owned()
+}
+
+pub fn boolean(name: &str) -> ColumnDef {
+    ColumnDef::new(Alias::new(name))
+        .boolean()
+        .not_null()
+        .to_owned()
+}
+
+pub fn date(name: &str) -> ColumnDef {
+    ColumnDef::new(Alias::new(name))
+        .date()
+        .not_null()
+        .to_owned()
+}
+
+pub fn date_time(name: &str) -> ColumnDef {
+    ColumnDef::new(Alias::new(name))
+        .date_time()
+        .not_null()
+        .to_owned()
+}
+
+pub fn date_time_null(name: &str) -> ColumnDef {
+    ColumnDef::new(Alias::new(name))
+        .date_time()
+        .null()
+        .to_owned()
+}
+
+/// 二进制内容，MySQL 的 blob 最大 64KB，改用 longblob
+pub fn binary(backend: DbBackend, name: &str) -> ColumnDef {
+    let mut column = ColumnDef::new(Alias::new(name));
+    match backend {
+        DbBackend::MySql => column.custom(Alias::new("longblob")),
+        _ => column.blob(),
+    };
+    column.not_null().to_owned()
+}
+
+pub fn json_null(name: &str) -> ColumnDef {
+    ColumnDef::new(Alias::new(name))
+        .json_binary()
+        .null()
+        .to_owned()
+}
+
+/// 以 JSON 数组存储的字符串列表，默认空数组
+pub fn string_array(backend: DbBackend, name: &str) -> ColumnDef {
+    json_with_default(backend, name, "[]", "(JSON_ARRAY())")
+}
+
+/// JSON 对象，默认空对象
+pub fn json_object(backend: DbBackend, name: &str) -> ColumnDef {
+    json_with_default(backend, name, "{}", "(JSON_OBJECT())")
+}
+
+/// MySQL 的 JSON 列不支持字面量默认值，只能使用表达式默认值
+fn json_with_default(backend: DbBackend, name: &str, literal: &str, mysql: &str) -> ColumnDef {
+    let default = match backend {
+        DbBackend::MySql => Expr::cust(mysql),
+        _ => Expr::val(literal).into(),
+    };
+    ColumnDef::new(Alias::new(name))
+        .json_binary()
+        .not_null()
+        .default(default)
+        .to_owned()
+}
+
+/// 追加所有业务表共有的创建者、更新者、逻辑删除与租户字段
+pub trait AuditColumns {
+    fn audit_columns(&mut self) -> &mut Self;
+}
+
+impl AuditColumns for TableCreateStatement {
+    fn audit_columns(&mut self) -> &mut Self {
+        self.col(string_null("creator", 64).default(""))
+            .col(date_time("create_time").default(Expr::current_timestamp()))
+            .col(string_null("updater", 64).default(""))
+            .col(date_time("update_time").default(Expr::current_timestamp()))
+            .col(boolean("deleted").default(false))
+            .col(string("tenant_id", 32).default("0"))
+    }
+}
@@ -0,0 +1,46 @@
+//! 数据库迁移：`system`、`demo` 两个 schema 的表结构与初始数据
+//!
+//! 由 `daoyi-migrate` 命令行执行，也可以通过 `database.auto_migrate` 配置在服务启动时自动执行
+
+mod ddl;
+mod m20260101_000001_create_system_tables;
+mod m20260101_000002_create_demo_tables;
+mod m20260101_000003_seed_system_data;
+
+pub use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseConnection;
+
+pub struct Migrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for Migrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(m20260101_000001_create_system_tables::Migration),
+            Box::new(m20260101_000002_create_demo_tables::Migration),
+            Box::new(m20260101_000003_seed_system_data::Migration),
+        ]
+    }
+}
+
+/// 删除全部迁移创建的表与迁移记录后重新执行全部迁移
+///
+/// sea-orm 自带的 `fresh` 只清理默认 schema 中的表，这里按迁移中的表清单逐个删除
+pub async fn fresh(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let manager = SchemaManager::new(db);
+    m20260101_000002_create_demo_tables::Migration
+        .down(&manager)
+        .await?;
+    m20260101_000001_create_system_tables::Migration
+        .down(&manager)
+        .await?;
+    manager
+        .drop_table(
+            Table::drop()
+                .table(Migrator::migration_table_name())
+                .if_exists()
+                .to_owned(),
+        )
+        .await?;
+    Migrator::up(db, None).await
+}
@@ -0,0 +1,497 @@
+//! `system` schema 的全部表，表结构与 `docs/db/system-schema.sql` 一致
+
+use crate::ddl::*;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DbBackend;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 按创建顺序排列，回滚时逆序删除
+const TABLES: &[&str] = &[
+    "system_users",
+    "system_access_token",
+    "system_tenant",
+    "system_dict_data",
+    "system_dict_type",
+    "system_user_role",
+    "system_role",
+    "system_role_menu",
+    "system_menu",
+    "system_post",
+    "system_tenant_package",
+    "system_notice",
+    "system_notice_read",
+    "system_notify_template",
+    "system_notify_message",
+    "system_mail_account",
+    "system_mail_template",
+    "system_mail_log",
+    "system_file_config",
+    "system_file",
+    "system_file_content",
+    "system_config",
+    "system_job",
+    "system_job_log",
+    "system_login_log",
+];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let backend = manager.get_database_backend();
+        create_schema(manager, SYSTEM).await?;
+        for table in [
+            system_users(),
+            system_access_token(),
+            system_tenant(backend),
+            system_dict_data(),
+            system_dict_type(),
+            system_user_role(),
+            system_role(backend),
+            system_role_menu(),
+            system_menu(),
+            system_post(),
+            system_tenant_package(backend),
+            system_notice(backend),
+            system_notice_read(),
+            system_notify_template(backend),
+            system_notify_message(backend),
+            system_mail_account(),
+            system_mail_template(backend),
+            system_mail_log(backend),
+            system_file_config(backend),
+            system_file(),
+            system_file_content(backend),
+            system_config(),
+            system_job(),
+            system_job_log(),
+            system_login_log(),
+        ] {
+            manager.create_table(table).await?;
+        }
+        create_index(
+            manager,
+            SYSTEM,
+            "system_access_token",
+            "idx_system_access_token_01",
+            &["access_token"],
+        )
+        .await?;
+        create_index(
+            manager,
+            SYSTEM,
+            "system_notice_read",
+            "idx_system_notice_read_user_id",
+            &["user_id"],
+        )
+        .await?;
+        create_index(
+            manager,
+            SYSTEM,
+            "system_notify_message",
+            "idx_system_notify_message_user_id",
+            &["user_id"],
+        )
+        .await?;
+        create_index(
+            manager,
+            SYSTEM,
+            "system_mail_log",
+            "idx_system_mail_log_to_mail",
+            &["to_mail"],
+        )
+        .await?;
+        create_index(
+            manager,
+            SYSTEM,
+            "system_file_content",
+            "idx_system_file_content_config_id_path",
+            &["config_id", "path"],
+        )
+        .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        drop_tables(manager, SYSTEM, TABLES).await
+    }
+}
+
+/// 用户信息表
+fn system_users() -> TableCreateStatement {
+    table(SYSTEM, "system_users")
+        .col(id())
+        .col(string("username", 30))
+        .col(string("password", 100).default(""))
+        .col(string("nickname", 256).default(""))
+        .col(string_null("remark", 500))
+        .col(string_null("dept_id", 32))
+        .col(json_null("post_ids"))
+        .col(string_null("email", 128).default(""))
+        .col(string_null("mobile", 128).default(""))
+        .col(string_null("sex", 1).default("0"))
+        .col(string_null("avatar", 512).default(""))
+        .col(string("status", 1).default("0"))
+        .col(string_null("login_ip", 128).default(""))
+        .col(date_time_null("login_date"))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 访问令牌
+fn system_access_token() -> TableCreateStatement {
+    table(SYSTEM, "system_access_token")
+        .col(id())
+        .col(string("user_id", 32))
+        .col(string("access_token", 255))
+        .col(date_time("expires_time"))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 租户表
+fn system_tenant(backend: DbBackend) -> TableCreateStatement {
+    table(SYSTEM, "system_tenant")
+        .col(id())
+        .col(string("name", 128))
+        .col(string_null("contact_user_id", 32))
+        .col(string("contact_name", 128))
+        .col(string_null("contact_mobile", 128))
+        .col(string("status", 1).default("0"))
+        .col(string_array(backend, "websites"))
+        .col(string("package_id", 32))
+        .col(date_time("expire_time"))
+        .col(integer("account_count"))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 字典数据表
+fn system_dict_data() -> TableCreateStatement {
+    table(SYSTEM, "system_dict_data")
+        .col(id())
+        .col(integer("sort").default(0))
+        .col(string("label", 100).default(""))
+        .col(string("value", 100).default(""))
+        .col(string("dict_type", 100).default(""))
+        .col(string("status", 1).default("0"))
+        .col(string_null("color_type", 100).default(""))
+        .col(string_null("css_class", 100).default(""))
+        .col(string_null("remark", 500))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 字典类型表
+fn system_dict_type() -> TableCreateStatement {
+    table(SYSTEM, "system_dict_type")
+        .col(id())
+        .col(string("name", 100).default(""))
+        .col(string("type", 100).default(""))
+        .col(string("status", 1).default("0"))
+        .col(string_null("remark", 500))
+        .col(date_time_null("deleted_time"))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 用户和角色关联表
+fn system_user_role() -> TableCreateStatement {
+    table(SYSTEM, "system_user_role")
+        .col(id())
+        .col(string("user_id", 32))
+        .col(string("role_id", 32))
+        .col(string_null("creator", 64).default(""))
+        .col(date_time_null("create_time").default(Expr::current_timestamp()))
+        .col(string_null("updater", 64).default(""))
+        .col(date_time_null("update_time").default(Expr::current_timestamp()))
+        .col(boolean("deleted").default(false))
+        .col(string("tenant_id", 32).default("0"))
+        .to_owned()
+}
+
+/// 角色信息表
+fn system_role(backend: DbBackend) -> TableCreateStatement {
+    table(SYSTEM, "system_role")
+        .col(id())
+        .col(string("name", 30))
+        .col(string("code", 100))
+        .col(integer("sort"))
+        .col(string("data_scope", 1).default("1"))
+        .col(string_array(backend, "data_scope_dept_ids"))
+        .col(string("status", 1))
+        .col(string("type", 1))
+        .col(string_null("remark", 500))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 角色和菜单关联表
+fn system_role_menu() -> TableCreateStatement {
+    table(SYSTEM, "system_role_menu")
+        .col(id())
+        .col(string("role_id", 32))
+        .col(string("menu_id", 32))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 菜单权限表
+fn system_menu() -> TableCreateStatement {
+    table(SYSTEM, "system_menu")
+        .col(id())
+        .col(string("name", 50))
+        .col(string("permission", 100).default(""))
+        .col(string("type", 1))
+        .col(integer("sort").default(0))
+        .col(string("parent_id", 32).default("0"))
+        .col(string_null("path", 200).default(""))
+        .col(string_null("icon", 100).default("#"))
+        .col(string_null("component", 255))
+        .col(string_null("component_name", 255))
+        .col(string("status", 1).default("0"))
+        .col(boolean("visible").default(true))
+        .col(boolean("keep_alive").default(true))
+        .col(boolean("always_show").default(true))
+        .col(string_null("api", 128))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 岗位信息表
+fn system_post() -> TableCreateStatement {
+    table(SYSTEM, "system_post")
+        .col(id())
+        .col(string("code", 64))
+        .col(string("name", 50))
+        .col(integer("sort").default(0))
+        .col(string("status", 1).default("0"))
+        .col(string_null("remark", 500))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 租户套餐表
+fn system_tenant_package(backend: DbBackend) -> TableCreateStatement {
+    table(SYSTEM, "system_tenant_package")
+        .col(id())
+        .col(string("name", 30))
+        .col(string("status", 1).default("0"))
+        .col(string_null("remark", 256).default(""))
+        .col(string_array(backend, "menu_ids"))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 通知公告表
+fn system_notice(backend: DbBackend) -> TableCreateStatement {
+    table(SYSTEM, "system_notice")
+        .col(id())
+        .col(string("title", 50))
+        .col(text("content"))
+        .col(string("type", 1))
+        .col(string("status", 1).default("0"))
+        .col(string_array(backend, "target_role_ids"))
+        .col(string_array(backend, "target_dept_ids"))
+        .col(date_time_null("publish_time"))
+        .col(boolean("pushed").default(false))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 公告阅读记录表
+fn system_notice_read() -> TableCreateStatement {
+    table(SYSTEM, "system_notice_read")
+        .col(id())
+        .col(string("notice_id", 32))
+        .col(string("user_id", 32))
+        .col(date_time("read_time").default(Expr::current_timestamp()))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 站内信模板表
+fn system_notify_template(backend: DbBackend) -> TableCreateStatement {
+    table(SYSTEM, "system_notify_template")
+        .col(id())
+        .col(string("name", 63))
+        .col(string("code", 64))
+        .col(string("nickname", 255))
+        .col(string("content", 1024))
+        .col(string("type", 1))
+        .col(string_array(backend, "params"))
+        .col(string("status", 1).default("0"))
+        .col(string_null("remark", 255))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 站内信消息表
+fn system_notify_message(backend: DbBackend) -> TableCreateStatement {
+    table(SYSTEM, "system_notify_message")
+        .col(id())
+        .col(string("user_id", 32))
+        .col(string("template_id", 32))
+        .col(string("template_code", 64))
+        .col(string("template_nickname", 63))
+        .col(string("template_content", 1024))
+        .col(string("template_type", 1))
+        .col(json_object(backend, "template_params"))
+        .col(boolean("read_status").default(false))
+        .col(date_time_null("read_time"))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 邮箱账号表
+fn system_mail_account() -> TableCreateStatement {
+    table(SYSTEM, "system_mail_account")
+        .col(id())
+        .col(string("mail", 255))
+        .col(string_null("username", 255))
+        .col(string_null("smtp_password", 255))
+        .col(string("host", 255))
+        .col(integer("port"))
+        .col(boolean("ssl_enable").default(false))
+        .col(boolean("starttls_enable").default(false))
+        .col(string_null("remark", 255))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 邮件模版表
+fn system_mail_template(backend: DbBackend) -> TableCreateStatement {
+    table(SYSTEM, "system_mail_template")
+        .col(id())
+        .col(string("name", 63))
+        .col(string("code", 63))
+        .col(string("account_id", 32))
+        .col(string_null("nickname", 255))
+        .col(string("title", 255))
+        .col(text("content"))
+        .col(string_array(backend, "params"))
+        .col(string("status", 1))
+        .col(string_null("remark", 255))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 邮件日志表
+fn system_mail_log(backend: DbBackend) -> TableCreateStatement {
+    table(SYSTEM, "system_mail_log")
+        .col(id())
+        .col(string_null("user_id", 32))
+        .col(string("to_mail", 255))
+        .col(string("account_id", 32))
+        .col(string("from_mail", 255))
+        .col(string("template_id", 32))
+        .col(string("template_code", 63))
+        .col(string_null("template_nickname", 255))
+        .col(string("template_title", 255))
+        .col(text("template_content"))
+        .col(json_object(backend, "template_params"))
+        .col(string("send_status", 2).default("0"))
+        .col(date_time_null("send_time"))
+        .col(string_null("send_message_id", 255))
+        .col(string_null("send_exception", 4096))
+        .col(integer("retry_count").default(0))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 文件配置表
+fn system_file_config(backend: DbBackend) -> TableCreateStatement {
+    table(SYSTEM, "system_file_config")
+        .col(id())
+        .col(string("name", 63))
+        .col(string("storage", 2))
+        .col(json_object(backend, "config"))
+        .col(boolean("master").default(false))
+        .col(string_null("remark", 255))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 文件表
+fn system_file() -> TableCreateStatement {
+    table(SYSTEM, "system_file")
+        .col(id())
+        .col(string("config_id", 32))
+        .col(string("name", 255))
+        .col(string("path", 512))
+        .col(string("type", 127))
+        .col(big_integer("size"))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 文件内容表
+fn system_file_content(backend: DbBackend) -> TableCreateStatement {
+    table(SYSTEM, "system_file_content")
+        .col(id())
+        .col(string("config_id", 32))
+        .col(string("path", 512))
+        .col(binary(backend, "content"))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 参数配置表
+fn system_config() -> TableCreateStatement {
+    table(SYSTEM, "system_config")
+        .col(id())
+        .col(string("category", 50))
+        .col(string("name", 100))
+        .col(string("config_key", 100))
+        .col(string("value", 500).default(""))
+        .col(string("type", 1))
+        .col(boolean("visible").default(false))
+        .col(string_null("remark", 255))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 定时任务表
+fn system_job() -> TableCreateStatement {
+    table(SYSTEM, "system_job")
+        .col(id())
+        .col(string("name", 63))
+        .col(string("handler_name", 63))
+        .col(string_null("handler_param", 255))
+        .col(string("cron_expression", 63))
+        .col(integer("retry_count").default(0))
+        .col(integer("retry_interval").default(0))
+        .col(string("status", 1))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 定时任务日志表
+fn system_job_log() -> TableCreateStatement {
+    table(SYSTEM, "system_job_log")
+        .col(id())
+        .col(string("job_id", 32))
+        .col(string("handler_name", 63))
+        .col(string_null("handler_param", 255))
+        .col(integer("execute_index").default(1))
+        .col(date_time("begin_time"))
+        .col(date_time_null("end_time"))
+        .col(big_integer_null("duration"))
+        .col(string("status", 1))
+        .col(text_null("result"))
+        .audit_columns()
+        .to_owned()
+}
+
+/// 登录日志表
+fn system_login_log() -> TableCreateStatement {
+    table(SYSTEM, "system_login_log")
+        .col(id())
+        .col(string_null("user_id", 32))
+        .col(string("username", 50).default(""))
+        .col(string("result", 2))
+        .col(string("user_ip", 50))
+        .col(string_null("user_agent", 512))
+        .audit_columns()
+        .to_owned()
+}
@@ -0,0 +1,100 @@
+//! `demo` schema 的示例表与示例数据，与 `docs/db/demo-schema.sql` 一致
+
+use crate::ddl::*;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::prelude::Date;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        create_schema(manager, DEMO).await?;
+        manager.create_table(sys_user()).await?;
+        let mut insert = Query::insert();
+        insert
+            .into_table(table_ref(DEMO, "sys_user"))
+            .columns(
+                [
+                    "id",
+                    "name",
+                    "gender",
+                    "account",
+                    "password",
+                    "mobile_phone",
+                    "birthday",
+                    "enabled",
+                ]
+                .map(Alias::new),
+            )
+            .on_conflict(OnConflict::column(Alias::new("id")).do_nothing().to_owned());
+        for (id, name, gender, account, password, mobile_phone, birthday, enabled) in [
+            (
+                "6202954260741",
+                "李四",
+                "female",
+                "lisi",
+                "$2b$12$PsumwxjxX/o1RNOKpkc.Kuxea0izqSuhaod4PCudXoRh3zet1TASK",
+                "17361631996",
+                "2025-05-13",
+                true,
+            ),
+            (
+                "6161671639301",
+                "张三",
+                "male",
+                "admin",
+                "$2b$12$PsumwxjxX/o1RNOKpkc.Kuxea0izqSuhaod4PCudXoRh3zet1TASK",
+                "19909407240",
+                "2025-05-18",
+                false,
+            ),
+            (
+                "11467064770821",
+                "赵六",
+                "female",
+                "zhaoliu",
+                "$2b$12$EJOKHLJLnfHrgrXbZl8uge3N4VEgR9FWHwq3a6pgTIM8O66Lf/9DW",
+                "18361631783",
+                "2025-06-11",
+                true,
+            ),
+        ] {
+            insert.values_panic([
+                id.into(),
+                name.into(),
+                gender.into(),
+                account.into(),
+                password.into(),
+                mobile_phone.into(),
+                birthday
+                    .parse::<Date>()
+                    .map_err(|e| DbErr::Custom(e.to_string()))?
+                    .into(),
+                enabled.into(),
+            ]);
+        }
+        manager.exec_stmt(insert).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        drop_tables(manager, DEMO, &["sys_user"]).await
+    }
+}
+
+/// 示例用户表
+fn sys_user() -> TableCreateStatement {
+    table(DEMO, "sys_user")
+        .col(id())
+        .col(string("name", 16))
+        .col(string("gender", 8))
+        .col(string("account", 16))
+        .col(string("password", 64))
+        .col(string("mobile_phone", 16))
+        .col(date("birthday"))
+        .col(boolean("enabled").default(true))
+        .col(date_time("created_at").default(Expr::current_timestamp()))
+        .col(date_time("updated_at").default(Expr::current_timestamp()))
+        .to_owned()
+}
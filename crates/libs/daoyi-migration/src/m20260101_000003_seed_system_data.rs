@@ -0,0 +1,431 @@
+//! 系统初始数据：系统租户、超级管理员、菜单、枚举字典、参数配置与内置定时任务
+
+use crate::ddl::*;
+use daoyi_common_support::enumeration::{
+    CommonStatusEnum, ConfigTypeEnum, DataScopeEnum, ENUM_DICTS, JobStatusEnum, MenuTypeEnum,
+    RoleTypeEnum,
+};
+use daoyi_entity_system::system_service::system_dict_sync_service;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::prelude::DateTime;
+use serde_json::json;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 系统租户与超级管理员的编号，与租户套餐、权限判断中的约定一致
+const SYSTEM_ID: &str = "0";
+
+/// 菜单：目录下挂页面，每个页面按操作生成按钮权限 `<权限前缀>:<操作>`
+struct SeedMenu {
+    id: &'static str,
+    parent_id: &'static str,
+    name: &'static str,
+    path: &'static str,
+    icon: &'static str,
+    /// 目录为 `None`，页面为 (组件路径, 组件名, 权限前缀, 按钮操作)
+    page: Option<(&'static str, &'static str, &'static str, &'static [Action])>,
+}
+
+type Action = (&'static str, &'static str);
+
+const CRUD: &[Action] = &[
+    ("query", "查询"),
+    ("create", "新增"),
+    ("update", "修改"),
+    ("delete", "删除"),
+];
+const READ_ONLY: &[Action] = &[("query", "查询")];
+
+const fn dir(
+    id: &'static str,
+    name: &'static str,
+    path: &'static str,
+    icon: &'static str,
+) -> SeedMenu {
+    SeedMenu {
+        id,
+        parent_id: "0",
+        name,
+        path,
+        icon,
+        page: None,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+const fn page(
+    id: &'static str,
+    parent_id: &'static str,
+    name: &'static str,
+    path: &'static str,
+    icon: &'static str,
+    component: &'static str,
+    component_name: &'static str,
+    permission: &'static str,
+    actions: &'static [Action],
+) -> SeedMenu {
+    SeedMenu {
+        id,
+        parent_id,
+        name,
+        path,
+        icon,
+        page: Some((component, component_name, permission, actions)),
+    }
+}
+
+#[rustfmt::skip]
+const MENUS: &[SeedMenu] = &[
+    dir("1", "系统管理", "/system", "ep:tools"),
+    page("100", "1", "用户管理", "user", "ep:avatar", "system/user/index", "SystemUser", "system:user", CRUD),
+    page("101", "1", "角色管理", "role", "ep:user", "system/role/index", "SystemRole", "system:role", CRUD),
+    page("102", "1", "菜单管理", "menu", "ep:menu", "system/menu/index", "SystemMenu", "system:menu", CRUD),
+    page("103", "1", "部门管理", "dept", "fa:address-card", "system/dept/index", "SystemDept", "system:dept", CRUD),
+    page("104", "1", "岗位管理", "post", "fa:address-book-o", "system/post/index", "SystemPost", "system:post", CRUD),
+    page("105", "1", "字典管理", "dict", "ep:collection", "system/dict/index", "SystemDictType", "system:dict", CRUD),
+    page("106", "1", "通知公告", "notice", "ep:takeaway-box", "system/notice/index", "SystemNotice", "system:notice", CRUD),
+    page("107", "1", "参数配置", "config", "ep:edit", "infra/config/index", "InfraConfig", "system:config", CRUD),
+    page("108", "1", "文件管理", "file", "ep:upload-filled", "infra/file/index", "InfraFile", "system:file", CRUD),
+    page("109", "1", "定时任务", "job", "fa-solid:tasks", "infra/job/index", "InfraJob", "system:job", CRUD),
+    page("110", "1", "登录日志", "login-log", "ep:histogram", "system/loginlog/index", "SystemLoginLog", "system:login-log", READ_ONLY),
+    page("111", "1", "邮箱账号", "mail-account", "fa:universal-access", "system/mail/account/index", "SystemMailAccount", "system:mail-account", CRUD),
+    page("112", "1", "邮件模板", "mail-template", "fa-solid:server", "system/mail/template/index", "SystemMailTemplate", "system:mail-template", CRUD),
+    page("113", "1", "邮件记录", "mail-log", "fa:edit", "system/mail/log/index", "SystemMailLog", "system:mail-log", READ_ONLY),
+    page("114", "1", "站内信模板", "notify-template", "fa:archive", "system/notify/template/index", "SystemNotifyTemplate", "system:notify-template", CRUD),
+    dir("2", "租户管理", "/tenant", "ep:house"),
+    page("200", "2", "租户列表", "list", "ep:house", "system/tenant/index", "SystemTenant", "system:tenant", CRUD),
+    page("201", "2", "租户套餐", "package", "fa:bars", "system/tenant/package/index", "SystemTenantPackage", "system:tenant-package", CRUD),
+];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        seed_tenant_and_admin(manager).await?;
+        seed_menus(manager).await?;
+        system_dict_sync_service::sync_enum_dicts_for_tenant(manager.get_connection(), SYSTEM_ID)
+            .await
+            .map_err(|e| DbErr::Custom(e.to_string()))?;
+        seed_config_and_jobs(manager).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let menu_ids = menu_rows()
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect::<Vec<_>>();
+        delete_by_ids(manager, "system_menu", menu_ids).await?;
+        let dict_types = ENUM_DICTS.iter().map(|x| x.dict_type).collect::<Vec<_>>();
+        for name in ["system_dict_type", "system_dict_data"] {
+            let column = if name == "system_dict_type" {
+                "type"
+            } else {
+                "dict_type"
+            };
+            manager
+                .exec_stmt(
+                    Query::delete()
+                        .from_table(table_ref(SYSTEM, name))
+                        .and_where(Expr::col(Alias::new("tenant_id")).eq(SYSTEM_ID))
+                        .and_where(Expr::col(Alias::new(column)).is_in(dict_types.clone()))
+                        .to_owned(),
+                )
+                .await?;
+        }
+        delete_by_ids(
+            manager,
+            "system_job",
+            vec![String::from("1"), String::from("2")],
+        )
+        .await?;
+        delete_by_ids(manager, "system_config", vec![String::from("1")]).await?;
+        for name in [
+            "system_user_role",
+            "system_role",
+            "system_users",
+            "system_tenant",
+        ] {
+            delete_by_ids(manager, name, vec![String::from(SYSTEM_ID)]).await?;
+        }
+        Ok(())
+    }
+}
+
+async fn seed_tenant_and_admin(manager: &SchemaManager<'_>) -> Result<(), DbErr> {
+    insert(
+        manager,
+        "system_tenant",
+        &[
+            "id",
+            "name",
+            "contact_user_id",
+            "contact_name",
+            "contact_mobile",
+            "status",
+            "websites",
+            "package_id",
+            "expire_time",
+            "account_count",
+        ],
+        vec![vec![
+            SYSTEM_ID.into(),
+            "系统租户".into(),
+            SYSTEM_ID.into(),
+            "兰陵王".into(),
+            "17621038080".into(),
+            CommonStatusEnum::Enable.into(),
+            json!(["localhost"]).into(),
+            SYSTEM_ID.into(),
+            date_time("2035-12-26 16:15:46")?.into(),
+            3.into(),
+        ]],
+    )
+    .await?;
+    // 初始密码 Aa123456
+    insert(
+        manager,
+        "system_users",
+        &[
+            "id", "username", "password", "nickname", "remark", "dept_id", "post_ids", "email",
+            "mobile", "sex", "status",
+        ],
+        vec![vec![
+            SYSTEM_ID.into(),
+            "admin".into(),
+            "$2b$04$oVX9LhAfLryctEw7L5iAk.R1XFXnW8Pq1KLi9MBvOA47nXisTnKKu".into(),
+            "系统管理员".into(),
+            "系统管理员，默认初始化，密码：Aa123456".into(),
+            SYSTEM_ID.into(),
+            json!([SYSTEM_ID]).into(),
+            "gemiman@vip.qq.com".into(),
+            "17621038080".into(),
+            "1".into(),
+            CommonStatusEnum::Enable.into(),
+        ]],
+    )
+    .await?;
+    insert(
+        manager,
+        "system_role",
+        &[
+            "id",
+            "name",
+            "code",
+            "sort",
+            "data_scope",
+            "status",
+            "type",
+            "remark",
+        ],
+        vec![vec![
+            SYSTEM_ID.into(),
+            "超级管理员".into(),
+            "super_admin".into(),
+            1.into(),
+            DataScopeEnum::ALL.into(),
+            CommonStatusEnum::Enable.into(),
+            RoleTypeEnum::SYSTEM.into(),
+            "超级管理员".into(),
+        ]],
+    )
+    .await?;
+    insert(
+        manager,
+        "system_user_role",
+        &["id", "user_id", "role_id"],
+        vec![vec![SYSTEM_ID.into(), SYSTEM_ID.into(), SYSTEM_ID.into()]],
+    )
+    .await
+}
+
+/// 展开菜单与按钮，返回 (编号, 插入的列值)，列顺序与 [`MENU_COLUMNS`] 一致
+fn menu_rows() -> Vec<(String, Vec<SimpleExpr>)> {
+    let row = |id: String,
+               parent_id: &str,
+               name: String,
+               permission: String,
+               menu_type: MenuTypeEnum,
+               sort: i32,
+               menu: Option<&SeedMenu>| {
+        let (path, icon, component, component_name) = match menu {
+            Some(menu) => (
+                menu.path,
+                menu.icon,
+                menu.page.map(|page| page.0),
+                menu.page.map(|page| page.1),
+            ),
+            None => ("", "", None, None),
+        };
+        let values = vec![
+            id.as_str().into(),
+            parent_id.into(),
+            name.into(),
+            permission.into(),
+            menu_type.into(),
+            sort.into(),
+            path.into(),
+            icon.into(),
+            component.into(),
+            component_name.into(),
+            CommonStatusEnum::Enable.into(),
+        ];
+        (id, values)
+    };
+    let mut rows = vec![];
+    for (index, menu) in MENUS.iter().enumerate() {
+        let sort = index as i32 + 1;
+        let Some((_, _, permission, actions)) = menu.page else {
+            rows.push(row(
+                menu.id.to_string(),
+                menu.parent_id,
+                menu.name.to_string(),
+                String::new(),
+                MenuTypeEnum::DIR,
+                sort,
+                Some(menu),
+            ));
+            continue;
+        };
+        rows.push(row(
+            menu.id.to_string(),
+            menu.parent_id,
+            menu.name.to_string(),
+            format!("{permission}:list"),
+            MenuTypeEnum::MENU,
+            sort,
+            Some(menu),
+        ));
+        for (action_index, (action, label)) in actions.iter().enumerate() {
+            rows.push(row(
+                format!("{}{:02}", menu.id, action_index + 1),
+                menu.id,
+                format!("{}{label}", menu.name.trim_end_matches("管理")),
+                format!("{permission}:{action}"),
+                MenuTypeEnum::BUTTON,
+                action_index as i32 + 1,
+                None,
+            ));
+        }
+    }
+    rows
+}
+
+const MENU_COLUMNS: &[&str] = &[
+    "id",
+    "parent_id",
+    "name",
+    "permission",
+    "type",
+    "sort",
+    "path",
+    "icon",
+    "component",
+    "component_name",
+    "status",
+];
+
+async fn seed_menus(manager: &SchemaManager<'_>) -> Result<(), DbErr> {
+    let rows = menu_rows().into_iter().map(|(_, values)| values).collect();
+    insert(manager, "system_menu", MENU_COLUMNS, rows).await
+}
+
+async fn seed_config_and_jobs(manager: &SchemaManager<'_>) -> Result<(), DbErr> {
+    insert(
+        manager,
+        "system_config",
+        &[
+            "id",
+            "category",
+            "name",
+            "config_key",
+            "value",
+            "type",
+            "visible",
+            "remark",
+        ],
+        vec![vec![
+            "1".into(),
+            "biz".into(),
+            "用户管理-账号初始密码".into(),
+            "system.user.init-password".into(),
+            "123456".into(),
+            ConfigTypeEnum::System.into(),
+            false.into(),
+            "初始化密码 123456".into(),
+        ]],
+    )
+    .await?;
+    insert(
+        manager,
+        "system_job",
+        &[
+            "id",
+            "name",
+            "handler_name",
+            "cron_expression",
+            "retry_count",
+            "retry_interval",
+            "status",
+        ],
+        vec![
+            vec![
+                "1".into(),
+                "清理过期访问令牌".into(),
+                "purge_expired_access_token".into(),
+                "0 0 3 * * *".into(),
+                0.into(),
+                0.into(),
+                JobStatusEnum::Normal.into(),
+            ],
+            vec![
+                "2".into(),
+                "禁用过期租户".into(),
+                "disable_expired_tenant".into(),
+                "0 */10 * * * *".into(),
+                3.into(),
+                10000.into(),
+                JobStatusEnum::Normal.into(),
+            ],
+        ],
+    )
+    .await
+}
+
+/// 插入初始数据，编号已存在的行跳过，便于在已有数据的库上执行
+async fn insert(
+    manager: &SchemaManager<'_>,
+    name: &str,
+    columns: &[&str],
+    rows: Vec<Vec<SimpleExpr>>,
+) -> Result<(), DbErr> {
+    let mut statement = Query::insert();
+    statement
+        .into_table(table_ref(SYSTEM, name))
+        .columns(columns.iter().map(|column| Alias::new(*column)))
+        .on_conflict(OnConflict::column(Alias::new("id")).do_nothing().to_owned());
+    for row in rows {
+        statement
+            .values(row)
+            .map_err(|e| DbErr::Custom(e.to_string()))?;
+    }
+    manager.exec_stmt(statement).await
+}
+
+async fn delete_by_ids(
+    manager: &SchemaManager<'_>,
+    name: &str,
+    ids: Vec<String>,
+) -> Result<(), DbErr> {
+    manager
+        .exec_stmt(
+            Query::delete()
+                .from_table(table_ref(SYSTEM, name))
+                .and_where(Expr::col(Alias::new("id")).is_in(ids))
+                .to_owned(),
+        )
+        .await
+}
+
+fn date_time(value: &str) -> Result<DateTime, DbErr> {
+    DateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S").map_err(|e| DbErr::Custom(e.to_string()))
+}
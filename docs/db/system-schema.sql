@@ -17,7 +17,7 @@ CREATE TABLE system.system_users
     nickname    varchar(256)   NOT NULL DEFAULT '',
     remark      varchar(500)   NULL     DEFAULT NULL,
     dept_id     varchar(32)    NULL     DEFAULT NULL,
-    post_ids    varchar(255)[] NULL     DEFAULT NULL,
+    post_ids    jsonb          NULL     DEFAULT NULL,
     email       varchar(128)   NULL     DEFAULT '',
     mobile      varchar(128)   NULL     DEFAULT '',
     sex         varchar(1)     NULL     DEFAULT '0',
@@ -59,7 +59,7 @@ INSERT INTO system.system_users (id, username, password, nickname, remark, dept_
                                  deleted, tenant_id)
 VALUES ('0'::varchar(32), 'admin'::varchar(30),
         '$2b$04$oVX9LhAfLryctEw7L5iAk.R1XFXnW8Pq1KLi9MBvOA47nXisTnKKu'::varchar(100), '系统管理员'::varchar(256),
-        '系统管理员，默认初始化，密码：Aa123456'::varchar(500), '0'::varchar(32), '{0}', 'gemiman@vip.qq.com'::varchar(50),
+        '系统管理员，默认初始化，密码：Aa123456'::varchar(500), '0'::varchar(32), '["0"]', 'gemiman@vip.qq.com'::varchar(50),
         '17621038080'::varchar(11), '1'::varchar(1), DEFAULT, '0'::varchar(1), '0.0.0.0'::varchar(50),
         '2025-12-26 12:16:02.000000'::timestamp, '0'::varchar(64), '2025-12-26 12:16:12.000000'::timestamp,
         '0'::varchar(64), '2025-12-26 12:16:18.000000'::timestamp, false::boolean, '0'::varchar(32));
@@ -110,7 +110,7 @@ CREATE TABLE system.system_tenant
     contact_name    varchar(128) NOT NULL,
     contact_mobile  varchar(128) NULL     DEFAULT NULL,
     status          varchar(1)   NOT NULL DEFAULT '0',
-    websites        varchar(256) NULL     DEFAULT '',
+    websites        jsonb NOT NULL DEFAULT '[]',
     package_id      varchar(32)  NOT NULL,
     expire_time     timestamp    NOT NULL,
     account_count   int4         NOT NULL,
@@ -128,7 +128,7 @@ COMMENT ON COLUMN system.system_tenant.contact_user_id IS '联系人的用户编
 COMMENT ON COLUMN system.system_tenant.contact_name IS '联系人';
 COMMENT ON COLUMN system.system_tenant.contact_mobile IS '联系手机';
 COMMENT ON COLUMN system.system_tenant.status IS '租户状态（0正常 1停用）';
-COMMENT ON COLUMN system.system_tenant.websites IS '绑定域名数组';
+COMMENT ON COLUMN system.system_tenant.websites IS '绑定域名数组，支持 *.example.com 通配子域名';
 COMMENT ON COLUMN system.system_tenant.package_id IS '租户套餐编号';
 COMMENT ON COLUMN system.system_tenant.expire_time IS '过期时间';
 COMMENT ON COLUMN system.system_tenant.account_count IS '账号数量';
@@ -142,7 +142,7 @@ COMMENT ON TABLE system.system_tenant IS '租户表';
 INSERT INTO system.system_tenant (id, name, contact_user_id, contact_name, contact_mobile, status, websites, package_id,
                                   expire_time, account_count, creator, create_time, updater, update_time, deleted,
                                   tenant_id)
-VALUES ('0', '系统租户', '0', '兰陵王', '17621038080', '0', 'localhost', '0', '2035-12-26 16:15:46.000000', 3, '0',
+VALUES ('0', '系统租户', '0', '兰陵王', '17621038080', '0', '["localhost"]', '0', '2035-12-26 16:15:46.000000', 3, '0',
         '2025-12-26 16:16:16.000000', '0', '2025-12-26 16:16:25.000000', false, '0');
 commit;
 
@@ -264,7 +264,7 @@ CREATE TABLE system.system_role
     code                varchar(100) NOT NULL,
     sort                int4         NOT NULL,
     data_scope          varchar(1)   NOT NULL DEFAULT '1',
-    data_scope_dept_ids varchar(1)[] NOT NULL DEFAULT '{}',
+    data_scope_dept_ids jsonb NOT NULL DEFAULT '[]',
     status              varchar(1)   NOT NULL,
     type                varchar(1)   NOT NULL,
     remark              varchar(500) NULL     DEFAULT NULL,
@@ -374,3 +374,631 @@ COMMENT ON COLUMN system.system_menu.update_time IS '更新时间';
 COMMENT ON COLUMN system.system_menu.deleted IS '是否删除';
 COMMENT ON COLUMN system.system_menu.tenant_id IS '租户编号';
 COMMENT ON TABLE system.system_menu IS '菜单权限表';
+
+
+-- ----------------------------
+-- Table structure for system.system_post
+-- ----------------------------
+DROP TABLE IF EXISTS system.system_post;
+CREATE TABLE system.system_post
+(
+    id          varchar(32)  NOT NULL primary key,
+    code        varchar(64)  NOT NULL,
+    name        varchar(50)  NOT NULL,
+    sort        int4         NOT NULL DEFAULT 0,
+    status      varchar(1)   NOT NULL DEFAULT '0',
+    remark      varchar(500) NULL     DEFAULT NULL,
+    creator     varchar(32)  NULL     DEFAULT '',
+    create_time timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updater     varchar(32)  NULL     DEFAULT '',
+    update_time timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted     boolean      NOT NULL DEFAULT false,
+    tenant_id   varchar(32)  NOT NULL DEFAULT '0'
+);
+
+COMMENT ON COLUMN system.system_post.id IS '岗位ID';
+COMMENT ON COLUMN system.system_post.code IS '岗位编码';
+COMMENT ON COLUMN system.system_post.name IS '岗位名称';
+COMMENT ON COLUMN system.system_post.sort IS '显示顺序';
+COMMENT ON COLUMN system.system_post.status IS '状态（0正常 1停用）';
+COMMENT ON COLUMN system.system_post.remark IS '备注';
+COMMENT ON COLUMN system.system_post.creator IS '创建者';
+COMMENT ON COLUMN system.system_post.create_time IS '创建时间';
+COMMENT ON COLUMN system.system_post.updater IS '更新者';
+COMMENT ON COLUMN system.system_post.update_time IS '更新时间';
+COMMENT ON COLUMN system.system_post.deleted IS '是否删除';
+COMMENT ON COLUMN system.system_post.tenant_id IS '租户编号';
+COMMENT ON TABLE system.system_post IS '岗位信息表';
+
+
+-- ----------------------------
+-- Table structure for system.system_tenant_package
+-- ----------------------------
+DROP TABLE IF EXISTS system.system_tenant_package;
+CREATE TABLE system.system_tenant_package
+(
+    id          varchar(32)   NOT NULL primary key,
+    name        varchar(30)   NOT NULL,
+    status      varchar(1)    NOT NULL DEFAULT '0',
+    remark      varchar(256)  NULL     DEFAULT '',
+    menu_ids    jsonb NOT NULL DEFAULT '[]',
+    creator     varchar(32)   NULL     DEFAULT '',
+    create_time timestamp     NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updater     varchar(32)   NULL     DEFAULT '',
+    update_time timestamp     NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted     boolean       NOT NULL DEFAULT false,
+    tenant_id   varchar(32)   NOT NULL DEFAULT '0'
+);
+
+COMMENT ON COLUMN system.system_tenant_package.id IS '套餐编号';
+COMMENT ON COLUMN system.system_tenant_package.name IS '套餐名';
+COMMENT ON COLUMN system.system_tenant_package.status IS '租户状态（0正常 1停用）';
+COMMENT ON COLUMN system.system_tenant_package.remark IS '备注';
+COMMENT ON COLUMN system.system_tenant_package.menu_ids IS '关联的菜单编号数组';
+COMMENT ON COLUMN system.system_tenant_package.creator IS '创建者';
+COMMENT ON COLUMN system.system_tenant_package.create_time IS '创建时间';
+COMMENT ON COLUMN system.system_tenant_package.updater IS '更新者';
+COMMENT ON COLUMN system.system_tenant_package.update_time IS '更新时间';
+COMMENT ON COLUMN system.system_tenant_package.deleted IS '是否删除';
+COMMENT ON COLUMN system.system_tenant_package.tenant_id IS '租户编号';
+COMMENT ON TABLE system.system_tenant_package IS '租户套餐表';
+
+-- ----------------------------
+-- Table structure for system.system_notice
+-- ----------------------------
+DROP TABLE IF EXISTS system.system_notice;
+CREATE TABLE system.system_notice
+(
+    id              varchar(32)   NOT NULL primary key,
+    title           varchar(50)   NOT NULL,
+    content         text          NOT NULL,
+    type            varchar(1)    NOT NULL,
+    status          varchar(1)    NOT NULL DEFAULT '0',
+    target_role_ids jsonb NOT NULL DEFAULT '[]',
+    target_dept_ids jsonb NOT NULL DEFAULT '[]',
+    publish_time    timestamp     NULL     DEFAULT NULL,
+    pushed          boolean       NOT NULL DEFAULT false,
+    creator         varchar(32)   NULL     DEFAULT '',
+    create_time     timestamp     NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updater         varchar(32)   NULL     DEFAULT '',
+    update_time     timestamp     NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted         boolean       NOT NULL DEFAULT false,
+    tenant_id       varchar(32)   NOT NULL DEFAULT '0'
+);
+
+COMMENT ON COLUMN system.system_notice.id IS '公告编号';
+COMMENT ON COLUMN system.system_notice.title IS '公告标题';
+COMMENT ON COLUMN system.system_notice.content IS '公告内容';
+COMMENT ON COLUMN system.system_notice.type IS '公告类型（1通知 2公告）';
+COMMENT ON COLUMN system.system_notice.status IS '公告状态（0正常 1关闭）';
+COMMENT ON COLUMN system.system_notice.target_role_ids IS '通知的角色编号数组';
+COMMENT ON COLUMN system.system_notice.target_dept_ids IS '通知的部门编号数组';
+COMMENT ON COLUMN system.system_notice.publish_time IS '发布时间';
+COMMENT ON COLUMN system.system_notice.pushed IS '是否已推送';
+COMMENT ON COLUMN system.system_notice.creator IS '创建者';
+COMMENT ON COLUMN system.system_notice.create_time IS '创建时间';
+COMMENT ON COLUMN system.system_notice.updater IS '更新者';
+COMMENT ON COLUMN system.system_notice.update_time IS '更新时间';
+COMMENT ON COLUMN system.system_notice.deleted IS '是否删除';
+COMMENT ON COLUMN system.system_notice.tenant_id IS '租户编号';
+COMMENT ON TABLE system.system_notice IS '通知公告表';
+
+-- ----------------------------
+-- Table structure for system.system_notice_read
+-- ----------------------------
+DROP TABLE IF EXISTS system.system_notice_read;
+CREATE TABLE system.system_notice_read
+(
+    id          varchar(32) NOT NULL primary key,
+    notice_id   varchar(32) NOT NULL,
+    user_id     varchar(32) NOT NULL,
+    read_time   timestamp   NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    creator     varchar(32) NULL     DEFAULT '',
+    create_time timestamp   NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updater     varchar(32) NULL     DEFAULT '',
+    update_time timestamp   NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted     boolean     NOT NULL DEFAULT false,
+    tenant_id   varchar(32) NOT NULL DEFAULT '0'
+);
+
+CREATE INDEX idx_system_notice_read_user_id ON system.system_notice_read (user_id);
+
+COMMENT ON COLUMN system.system_notice_read.id IS '编号';
+COMMENT ON COLUMN system.system_notice_read.notice_id IS '公告编号';
+COMMENT ON COLUMN system.system_notice_read.user_id IS '用户编号';
+COMMENT ON COLUMN system.system_notice_read.read_time IS '阅读时间';
+COMMENT ON COLUMN system.system_notice_read.creator IS '创建者';
+COMMENT ON COLUMN system.system_notice_read.create_time IS '创建时间';
+COMMENT ON COLUMN system.system_notice_read.updater IS '更新者';
+COMMENT ON COLUMN system.system_notice_read.update_time IS '更新时间';
+COMMENT ON COLUMN system.system_notice_read.deleted IS '是否删除';
+COMMENT ON COLUMN system.system_notice_read.tenant_id IS '租户编号';
+COMMENT ON TABLE system.system_notice_read IS '公告阅读记录表';
+
+-- ----------------------------
+-- Table structure for system.system_notify_template
+-- ----------------------------
+DROP TABLE IF EXISTS system.system_notify_template;
+CREATE TABLE system.system_notify_template
+(
+    id          varchar(32)   NOT NULL primary key,
+    name        varchar(63)   NOT NULL,
+    code        varchar(64)   NOT NULL,
+    nickname    varchar(255)  NOT NULL,
+    content     varchar(1024) NOT NULL,
+    type        varchar(1)    NOT NULL,
+    params      jsonb NOT NULL DEFAULT '[]',
+    status      varchar(1)    NOT NULL DEFAULT '0',
+    remark      varchar(255)  NULL     DEFAULT NULL,
+    creator     varchar(32)   NULL     DEFAULT '',
+    create_time timestamp     NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updater     varchar(32)   NULL     DEFAULT '',
+    update_time timestamp     NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted     boolean       NOT NULL DEFAULT false,
+    tenant_id   varchar(32)   NOT NULL DEFAULT '0'
+);
+
+COMMENT ON COLUMN system.system_notify_template.id IS '主键';
+COMMENT ON COLUMN system.system_notify_template.name IS '模板名称';
+COMMENT ON COLUMN system.system_notify_template.code IS '模版编码';
+COMMENT ON COLUMN system.system_notify_template.nickname IS '发送人名称';
+COMMENT ON COLUMN system.system_notify_template.content IS '模版内容';
+COMMENT ON COLUMN system.system_notify_template.type IS '类型（1通知公告 2系统消息）';
+COMMENT ON COLUMN system.system_notify_template.params IS '参数数组';
+COMMENT ON COLUMN system.system_notify_template.status IS '状态（0正常 1停用）';
+COMMENT ON COLUMN system.system_notify_template.remark IS '备注';
+COMMENT ON COLUMN system.system_notify_template.creator IS '创建者';
+COMMENT ON COLUMN system.system_notify_template.create_time IS '创建时间';
+COMMENT ON COLUMN system.system_notify_template.updater IS '更新者';
+COMMENT ON COLUMN system.system_notify_template.update_time IS '更新时间';
+COMMENT ON COLUMN system.system_notify_template.deleted IS '是否删除';
+COMMENT ON COLUMN system.system_notify_template.tenant_id IS '租户编号';
+COMMENT ON TABLE system.system_notify_template IS '站内信模板表';
+
+-- ----------------------------
+-- Table structure for system.system_notify_message
+-- ----------------------------
+DROP TABLE IF EXISTS system.system_notify_message;
+CREATE TABLE system.system_notify_message
+(
+    id                varchar(32)   NOT NULL primary key,
+    user_id           varchar(32)   NOT NULL,
+    template_id       varchar(32)   NOT NULL,
+    template_code     varchar(64)   NOT NULL,
+    template_nickname varchar(63)   NOT NULL,
+    template_content  varchar(1024) NOT NULL,
+    template_type     varchar(1)    NOT NULL,
+    template_params   jsonb         NOT NULL DEFAULT '{}',
+    read_status       boolean       NOT NULL DEFAULT false,
+    read_time         timestamp     NULL     DEFAULT NULL,
+    creator           varchar(32)   NULL     DEFAULT '',
+    create_time       timestamp     NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updater           varchar(32)   NULL     DEFAULT '',
+    update_time       timestamp     NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted           boolean       NOT NULL DEFAULT false,
+    tenant_id         varchar(32)   NOT NULL DEFAULT '0'
+);
+
+CREATE INDEX idx_system_notify_message_user_id ON system.system_notify_message (user_id);
+
+COMMENT ON COLUMN system.system_notify_message.id IS '编号';
+COMMENT ON COLUMN system.system_notify_message.user_id IS '用户编号';
+COMMENT ON COLUMN system.system_notify_message.template_id IS '模版编号';
+COMMENT ON COLUMN system.system_notify_message.template_code IS '模板编码';
+COMMENT ON COLUMN system.system_notify_message.template_nickname IS '模版发送人名称';
+COMMENT ON COLUMN system.system_notify_message.template_content IS '模版内容';
+COMMENT ON COLUMN system.system_notify_message.template_type IS '模版类型';
+COMMENT ON COLUMN system.system_notify_message.template_params IS '模版参数';
+COMMENT ON COLUMN system.system_notify_message.read_status IS '是否已读';
+COMMENT ON COLUMN system.system_notify_message.read_time IS '阅读时间';
+COMMENT ON COLUMN system.system_notify_message.creator IS '创建者';
+COMMENT ON COLUMN system.system_notify_message.create_time IS '创建时间';
+COMMENT ON COLUMN system.system_notify_message.updater IS '更新者';
+COMMENT ON COLUMN system.system_notify_message.update_time IS '更新时间';
+COMMENT ON COLUMN system.system_notify_message.deleted IS '是否删除';
+COMMENT ON COLUMN system.system_notify_message.tenant_id IS '租户编号';
+COMMENT ON TABLE system.system_notify_message IS '站内信消息表';
+
+-- ----------------------------
+-- Table structure for system.system_mail_account
+-- ----------------------------
+DROP TABLE IF EXISTS system.system_mail_account;
+CREATE TABLE system.system_mail_account
+(
+    id              varchar(32)  NOT NULL primary key,
+    mail            varchar(255) NOT NULL,
+    username        varchar(255) NULL     DEFAULT NULL,
+    smtp_password   varchar(255) NULL     DEFAULT NULL,
+    host            varchar(255) NOT NULL,
+    port            int4         NOT NULL,
+    ssl_enable      boolean      NOT NULL DEFAULT false,
+    starttls_enable boolean      NOT NULL DEFAULT false,
+    remark          varchar(255) NULL     DEFAULT NULL,
+    creator         varchar(32)  NULL     DEFAULT '',
+    create_time     timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updater         varchar(32)  NULL     DEFAULT '',
+    update_time     timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted         boolean      NOT NULL DEFAULT false,
+    tenant_id       varchar(32)  NOT NULL DEFAULT '0'
+);
+
+COMMENT ON COLUMN system.system_mail_account.id IS '编号';
+COMMENT ON COLUMN system.system_mail_account.mail IS '邮箱';
+COMMENT ON COLUMN system.system_mail_account.username IS '用户名';
+COMMENT ON COLUMN system.system_mail_account.smtp_password IS '密码';
+COMMENT ON COLUMN system.system_mail_account.host IS 'SMTP 服务器域名';
+COMMENT ON COLUMN system.system_mail_account.port IS 'SMTP 服务器端口';
+COMMENT ON COLUMN system.system_mail_account.ssl_enable IS '是否开启 SSL';
+COMMENT ON COLUMN system.system_mail_account.starttls_enable IS '是否开启 STARTTLS';
+COMMENT ON COLUMN system.system_mail_account.remark IS '备注';
+COMMENT ON COLUMN system.system_mail_account.creator IS '创建者';
+COMMENT ON COLUMN system.system_mail_account.create_time IS '创建时间';
+COMMENT ON COLUMN system.system_mail_account.updater IS '更新者';
+COMMENT ON COLUMN system.system_mail_account.update_time IS '更新时间';
+COMMENT ON COLUMN system.system_mail_account.deleted IS '是否删除';
+COMMENT ON COLUMN system.system_mail_account.tenant_id IS '租户编号';
+COMMENT ON TABLE system.system_mail_account IS '邮箱账号表';
+
+-- ----------------------------
+-- Table structure for system.system_mail_template
+-- ----------------------------
+DROP TABLE IF EXISTS system.system_mail_template;
+CREATE TABLE system.system_mail_template
+(
+    id          varchar(32)   NOT NULL primary key,
+    name        varchar(63)   NOT NULL,
+    code        varchar(63)   NOT NULL,
+    account_id  varchar(32)   NOT NULL,
+    nickname    varchar(255)  NULL     DEFAULT NULL,
+    title       varchar(255)  NOT NULL,
+    content     text          NOT NULL,
+    params      jsonb NOT NULL DEFAULT '[]',
+    status      varchar(1)    NOT NULL,
+    remark      varchar(255)  NULL     DEFAULT NULL,
+    creator     varchar(32)   NULL     DEFAULT '',
+    create_time timestamp     NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updater     varchar(32)   NULL     DEFAULT '',
+    update_time timestamp     NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted     boolean       NOT NULL DEFAULT false,
+    tenant_id   varchar(32)   NOT NULL DEFAULT '0'
+);
+
+COMMENT ON COLUMN system.system_mail_template.id IS '编号';
+COMMENT ON COLUMN system.system_mail_template.name IS '模板名称';
+COMMENT ON COLUMN system.system_mail_template.code IS '模板编码';
+COMMENT ON COLUMN system.system_mail_template.account_id IS '发送的邮箱账号编号';
+COMMENT ON COLUMN system.system_mail_template.nickname IS '发送人名称';
+COMMENT ON COLUMN system.system_mail_template.title IS '模板标题';
+COMMENT ON COLUMN system.system_mail_template.content IS '模板内容';
+COMMENT ON COLUMN system.system_mail_template.params IS '参数数组';
+COMMENT ON COLUMN system.system_mail_template.status IS '开启状态';
+COMMENT ON COLUMN system.system_mail_template.remark IS '备注';
+COMMENT ON COLUMN system.system_mail_template.creator IS '创建者';
+COMMENT ON COLUMN system.system_mail_template.create_time IS '创建时间';
+COMMENT ON COLUMN system.system_mail_template.updater IS '更新者';
+COMMENT ON COLUMN system.system_mail_template.update_time IS '更新时间';
+COMMENT ON COLUMN system.system_mail_template.deleted IS '是否删除';
+COMMENT ON COLUMN system.system_mail_template.tenant_id IS '租户编号';
+COMMENT ON TABLE system.system_mail_template IS '邮件模版表';
+
+-- ----------------------------
+-- Table structure for system.system_mail_log
+-- ----------------------------
+DROP TABLE IF EXISTS system.system_mail_log;
+CREATE TABLE system.system_mail_log
+(
+    id                varchar(32)   NOT NULL primary key,
+    user_id           varchar(32)   NULL     DEFAULT NULL,
+    to_mail           varchar(255)  NOT NULL,
+    account_id        varchar(32)   NOT NULL,
+    from_mail         varchar(255)  NOT NULL,
+    template_id       varchar(32)   NOT NULL,
+    template_code     varchar(63)   NOT NULL,
+    template_nickname varchar(255)  NULL     DEFAULT NULL,
+    template_title    varchar(255)  NOT NULL,
+    template_content  text          NOT NULL,
+    template_params   jsonb         NOT NULL DEFAULT '{}',
+    send_status       varchar(2)    NOT NULL DEFAULT '0',
+    send_time         timestamp     NULL     DEFAULT NULL,
+    send_message_id   varchar(255)  NULL     DEFAULT NULL,
+    send_exception    varchar(4096) NULL     DEFAULT NULL,
+    retry_count       int4          NOT NULL DEFAULT 0,
+    creator           varchar(32)   NULL     DEFAULT '',
+    create_time       timestamp     NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updater           varchar(32)   NULL     DEFAULT '',
+    update_time       timestamp     NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted           boolean       NOT NULL DEFAULT false,
+    tenant_id         varchar(32)   NOT NULL DEFAULT '0'
+);
+
+CREATE INDEX idx_system_mail_log_to_mail ON system.system_mail_log (to_mail);
+
+COMMENT ON COLUMN system.system_mail_log.id IS '编号';
+COMMENT ON COLUMN system.system_mail_log.user_id IS '用户编号';
+COMMENT ON COLUMN system.system_mail_log.to_mail IS '接收邮箱地址';
+COMMENT ON COLUMN system.system_mail_log.account_id IS '邮箱账号编号';
+COMMENT ON COLUMN system.system_mail_log.from_mail IS '发送邮箱地址';
+COMMENT ON COLUMN system.system_mail_log.template_id IS '模板编号';
+COMMENT ON COLUMN system.system_mail_log.template_code IS '模板编码';
+COMMENT ON COLUMN system.system_mail_log.template_nickname IS '模版发送人名称';
+COMMENT ON COLUMN system.system_mail_log.template_title IS '邮件标题';
+COMMENT ON COLUMN system.system_mail_log.template_content IS '邮件内容';
+COMMENT ON COLUMN system.system_mail_log.template_params IS '邮件参数';
+COMMENT ON COLUMN system.system_mail_log.send_status IS '发送状态';
+COMMENT ON COLUMN system.system_mail_log.send_time IS '发送时间';
+COMMENT ON COLUMN system.system_mail_log.send_message_id IS '发送返回的消息 ID';
+COMMENT ON COLUMN system.system_mail_log.send_exception IS '发送异常';
+COMMENT ON COLUMN system.system_mail_log.retry_count IS '重试次数';
+COMMENT ON COLUMN system.system_mail_log.creator IS '创建者';
+COMMENT ON COLUMN system.system_mail_log.create_time IS '创建时间';
+COMMENT ON COLUMN system.system_mail_log.updater IS '更新者';
+COMMENT ON COLUMN system.system_mail_log.update_time IS '更新时间';
+COMMENT ON COLUMN system.system_mail_log.deleted IS '是否删除';
+COMMENT ON COLUMN system.system_mail_log.tenant_id IS '租户编号';
+COMMENT ON TABLE system.system_mail_log IS '邮件日志表';
+
+-- ----------------------------
+-- Table structure for system.system_file_config
+-- ----------------------------
+DROP TABLE IF EXISTS system.system_file_config;
+CREATE TABLE system.system_file_config
+(
+    id          varchar(32)  NOT NULL primary key,
+    name        varchar(63)  NOT NULL,
+    storage     varchar(2)   NOT NULL,
+    config      jsonb        NOT NULL DEFAULT '{}',
+    master      boolean      NOT NULL DEFAULT false,
+    remark      varchar(255) NULL     DEFAULT NULL,
+    creator     varchar(32)  NULL     DEFAULT '',
+    create_time timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updater     varchar(32)  NULL     DEFAULT '',
+    update_time timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted     boolean      NOT NULL DEFAULT false,
+    tenant_id   varchar(32)  NOT NULL DEFAULT '0'
+);
+
+COMMENT ON COLUMN system.system_file_config.id IS '编号';
+COMMENT ON COLUMN system.system_file_config.name IS '配置名';
+COMMENT ON COLUMN system.system_file_config.storage IS '存储器';
+COMMENT ON COLUMN system.system_file_config.config IS '存储配置';
+COMMENT ON COLUMN system.system_file_config.master IS '是否为主配置';
+COMMENT ON COLUMN system.system_file_config.remark IS '备注';
+COMMENT ON COLUMN system.system_file_config.creator IS '创建者';
+COMMENT ON COLUMN system.system_file_config.create_time IS '创建时间';
+COMMENT ON COLUMN system.system_file_config.updater IS '更新者';
+COMMENT ON COLUMN system.system_file_config.update_time IS '更新时间';
+COMMENT ON COLUMN system.system_file_config.deleted IS '是否删除';
+COMMENT ON COLUMN system.system_file_config.tenant_id IS '租户编号';
+COMMENT ON TABLE system.system_file_config IS '文件配置表';
+
+-- ----------------------------
+-- Table structure for system.system_file
+-- ----------------------------
+DROP TABLE IF EXISTS system.system_file;
+CREATE TABLE system.system_file
+(
+    id          varchar(32)  NOT NULL primary key,
+    config_id   varchar(32)  NOT NULL,
+    name        varchar(255) NOT NULL,
+    path        varchar(512) NOT NULL,
+    type        varchar(127) NOT NULL,
+    size        int8         NOT NULL,
+    creator     varchar(32)  NULL     DEFAULT '',
+    create_time timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updater     varchar(32)  NULL     DEFAULT '',
+    update_time timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted     boolean      NOT NULL DEFAULT false,
+    tenant_id   varchar(32)  NOT NULL DEFAULT '0'
+);
+
+COMMENT ON COLUMN system.system_file.id IS '编号';
+COMMENT ON COLUMN system.system_file.config_id IS '配置编号';
+COMMENT ON COLUMN system.system_file.name IS '原文件名';
+COMMENT ON COLUMN system.system_file.path IS '存储路径';
+COMMENT ON COLUMN system.system_file.type IS '文件类型';
+COMMENT ON COLUMN system.system_file.size IS '文件大小';
+COMMENT ON COLUMN system.system_file.creator IS '创建者';
+COMMENT ON COLUMN system.system_file.create_time IS '创建时间';
+COMMENT ON COLUMN system.system_file.updater IS '更新者';
+COMMENT ON COLUMN system.system_file.update_time IS '更新时间';
+COMMENT ON COLUMN system.system_file.deleted IS '是否删除';
+COMMENT ON COLUMN system.system_file.tenant_id IS '租户编号';
+COMMENT ON TABLE system.system_file IS '文件表';
+
+-- ----------------------------
+-- Table structure for system.system_file_content
+-- ----------------------------
+DROP TABLE IF EXISTS system.system_file_content;
+CREATE TABLE system.system_file_content
+(
+    id          varchar(32)  NOT NULL primary key,
+    config_id   varchar(32)  NOT NULL,
+    path        varchar(512) NOT NULL,
+    content     bytea        NOT NULL,
+    creator     varchar(32)  NULL     DEFAULT '',
+    create_time timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updater     varchar(32)  NULL     DEFAULT '',
+    update_time timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted     boolean      NOT NULL DEFAULT false,
+    tenant_id   varchar(32)  NOT NULL DEFAULT '0'
+);
+
+CREATE INDEX idx_system_file_content_config_id_path ON system.system_file_content (config_id, path);
+
+COMMENT ON COLUMN system.system_file_content.id IS '编号';
+COMMENT ON COLUMN system.system_file_content.config_id IS '配置编号';
+COMMENT ON COLUMN system.system_file_content.path IS '存储路径';
+COMMENT ON COLUMN system.system_file_content.content IS '文件内容';
+COMMENT ON COLUMN system.system_file_content.creator IS '创建者';
+COMMENT ON COLUMN system.system_file_content.create_time IS '创建时间';
+COMMENT ON COLUMN system.system_file_content.updater IS '更新者';
+COMMENT ON COLUMN system.system_file_content.update_time IS '更新时间';
+COMMENT ON COLUMN system.system_file_content.deleted IS '是否删除';
+COMMENT ON COLUMN system.system_file_content.tenant_id IS '租户编号';
+COMMENT ON TABLE system.system_file_content IS '文件内容表';
+
+-- ----------------------------
+-- Table structure for system.system_config
+-- ----------------------------
+DROP TABLE IF EXISTS system.system_config;
+CREATE TABLE system.system_config
+(
+    id          varchar(32)  NOT NULL primary key,
+    category    varchar(50)  NOT NULL,
+    name        varchar(100) NOT NULL,
+    config_key  varchar(100) NOT NULL,
+    value       varchar(500) NOT NULL DEFAULT '',
+    type        varchar(1)   NOT NULL,
+    visible     boolean      NOT NULL DEFAULT false,
+    remark      varchar(255) NULL     DEFAULT NULL,
+    creator     varchar(32)  NULL     DEFAULT '',
+    create_time timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updater     varchar(32)  NULL     DEFAULT '',
+    update_time timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted     boolean      NOT NULL DEFAULT false,
+    tenant_id   varchar(32)  NOT NULL DEFAULT '0'
+);
+
+COMMENT ON COLUMN system.system_config.id IS '编号';
+COMMENT ON COLUMN system.system_config.category IS '参数分类';
+COMMENT ON COLUMN system.system_config.name IS '参数名称';
+COMMENT ON COLUMN system.system_config.config_key IS '参数键名';
+COMMENT ON COLUMN system.system_config.value IS '参数键值';
+COMMENT ON COLUMN system.system_config.type IS '参数类型';
+COMMENT ON COLUMN system.system_config.visible IS '是否对前端可见';
+COMMENT ON COLUMN system.system_config.remark IS '备注';
+COMMENT ON COLUMN system.system_config.creator IS '创建者';
+COMMENT ON COLUMN system.system_config.create_time IS '创建时间';
+COMMENT ON COLUMN system.system_config.updater IS '更新者';
+COMMENT ON COLUMN system.system_config.update_time IS '更新时间';
+COMMENT ON COLUMN system.system_config.deleted IS '是否删除';
+COMMENT ON COLUMN system.system_config.tenant_id IS '租户编号';
+COMMENT ON TABLE system.system_config IS '参数配置表';
+
+INSERT INTO system.system_config (id, category, name, config_key, value, type, visible, remark, creator, create_time,
+                                  updater, update_time, deleted, tenant_id)
+VALUES ('1', 'biz', '用户管理-账号初始密码', 'system.user.init-password', '123456', '1', false, '初始化密码 123456',
+        '0', '2025-12-26 16:16:16.000000', '0', '2025-12-26 16:16:16.000000', false, '0');
+commit;
+
+-- ----------------------------
+-- Table structure for system.system_job
+-- ----------------------------
+DROP TABLE IF EXISTS system.system_job;
+CREATE TABLE system.system_job
+(
+    id              varchar(32)  NOT NULL primary key,
+    name            varchar(63)  NOT NULL,
+    handler_name    varchar(63)  NOT NULL,
+    handler_param   varchar(255) NULL     DEFAULT NULL,
+    cron_expression varchar(63)  NOT NULL,
+    retry_count     int4         NOT NULL DEFAULT 0,
+    retry_interval  int4         NOT NULL DEFAULT 0,
+    status          varchar(1)   NOT NULL,
+    creator         varchar(32)  NULL     DEFAULT '',
+    create_time     timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updater         varchar(32)  NULL     DEFAULT '',
+    update_time     timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted         boolean      NOT NULL DEFAULT false,
+    tenant_id       varchar(32)  NOT NULL DEFAULT '0'
+);
+
+COMMENT ON COLUMN system.system_job.id IS '任务编号';
+COMMENT ON COLUMN system.system_job.name IS '任务名称';
+COMMENT ON COLUMN system.system_job.handler_name IS '处理器名称';
+COMMENT ON COLUMN system.system_job.handler_param IS '处理器参数';
+COMMENT ON COLUMN system.system_job.cron_expression IS 'CRON 表达式';
+COMMENT ON COLUMN system.system_job.retry_count IS '重试次数';
+COMMENT ON COLUMN system.system_job.retry_interval IS '重试间隔（毫秒）';
+COMMENT ON COLUMN system.system_job.status IS '任务状态';
+COMMENT ON COLUMN system.system_job.creator IS '创建者';
+COMMENT ON COLUMN system.system_job.create_time IS '创建时间';
+COMMENT ON COLUMN system.system_job.updater IS '更新者';
+COMMENT ON COLUMN system.system_job.update_time IS '更新时间';
+COMMENT ON COLUMN system.system_job.deleted IS '是否删除';
+COMMENT ON COLUMN system.system_job.tenant_id IS '租户编号';
+COMMENT ON TABLE system.system_job IS '定时任务表';
+
+INSERT INTO system.system_job (id, name, handler_name, handler_param, cron_expression, retry_count, retry_interval,
+                               status, creator, create_time, updater, update_time, deleted, tenant_id)
+VALUES ('1', '清理过期访问令牌', 'purge_expired_access_token', NULL, '0 0 3 * * *', 0, 0, '1',
+        '0', '2025-12-26 16:16:16.000000', '0', '2025-12-26 16:16:16.000000', false, '0'),
+       ('2', '禁用过期租户', 'disable_expired_tenant', NULL, '0 */10 * * * *', 3, 10000, '1',
+        '0', '2025-12-26 16:16:16.000000', '0', '2025-12-26 16:16:16.000000', false, '0');
+commit;
+
+-- ----------------------------
+-- Table structure for system.system_job_log
+-- ----------------------------
+DROP TABLE IF EXISTS system.system_job_log;
+CREATE TABLE system.system_job_log
+(
+    id            varchar(32)  NOT NULL primary key,
+    job_id        varchar(32)  NOT NULL,
+    handler_name  varchar(63)  NOT NULL,
+    handler_param varchar(255) NULL     DEFAULT NULL,
+    execute_index int4         NOT NULL DEFAULT 1,
+    begin_time    timestamp    NOT NULL,
+    end_time      timestamp    NULL     DEFAULT NULL,
+    duration      int8         NULL     DEFAULT NULL,
+    status        varchar(1)   NOT NULL,
+    result        text         NULL     DEFAULT NULL,
+    creator       varchar(32)  NULL     DEFAULT '',
+    create_time   timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updater       varchar(32)  NULL     DEFAULT '',
+    update_time   timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted       boolean      NOT NULL DEFAULT false,
+    tenant_id     varchar(32)  NOT NULL DEFAULT '0'
+);
+
+COMMENT ON COLUMN system.system_job_log.id IS '日志编号';
+COMMENT ON COLUMN system.system_job_log.job_id IS '任务编号';
+COMMENT ON COLUMN system.system_job_log.handler_name IS '处理器名称';
+COMMENT ON COLUMN system.system_job_log.handler_param IS '处理器参数';
+COMMENT ON COLUMN system.system_job_log.execute_index IS '执行次数（含重试）';
+COMMENT ON COLUMN system.system_job_log.begin_time IS '开始执行时间';
+COMMENT ON COLUMN system.system_job_log.end_time IS '结束执行时间';
+COMMENT ON COLUMN system.system_job_log.duration IS '执行时长（毫秒）';
+COMMENT ON COLUMN system.system_job_log.status IS '执行状态';
+COMMENT ON COLUMN system.system_job_log.result IS '执行结果';
+COMMENT ON COLUMN system.system_job_log.creator IS '创建者';
+COMMENT ON COLUMN system.system_job_log.create_time IS '创建时间';
+COMMENT ON COLUMN system.system_job_log.updater IS '更新者';
+COMMENT ON COLUMN system.system_job_log.update_time IS '更新时间';
+COMMENT ON COLUMN system.system_job_log.deleted IS '是否删除';
+COMMENT ON COLUMN system.system_job_log.tenant_id IS '租户编号';
+COMMENT ON TABLE system.system_job_log IS '定时任务日志表';
+
+-- ----------------------------
+-- Table structure for system.system_login_log
+-- ----------------------------
+DROP TABLE IF EXISTS system.system_login_log;
+CREATE TABLE system.system_login_log
+(
+    id          varchar(32)  NOT NULL primary key,
+    user_id     varchar(32)  NULL     DEFAULT NULL,
+    username    varchar(50)  NOT NULL DEFAULT '',
+    result      varchar(2)   NOT NULL,
+    user_ip     varchar(50)  NOT NULL,
+    user_agent  varchar(512) NULL     DEFAULT NULL,
+    creator     varchar(32)  NULL     DEFAULT '',
+    create_time timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updater     varchar(32)  NULL     DEFAULT '',
+    update_time timestamp    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted     boolean      NOT NULL DEFAULT false,
+    tenant_id   varchar(32)  NOT NULL DEFAULT '0'
+);
+
+COMMENT ON COLUMN system.system_login_log.id IS '日志编号';
+COMMENT ON COLUMN system.system_login_log.user_id IS '用户编号';
+COMMENT ON COLUMN system.system_login_log.username IS '用户账号';
+COMMENT ON COLUMN system.system_login_log.result IS '登录结果';
+COMMENT ON COLUMN system.system_login_log.user_ip IS '用户 IP';
+COMMENT ON COLUMN system.system_login_log.user_agent IS '浏览器 UA';
+COMMENT ON COLUMN system.system_login_log.creator IS '创建者';
+COMMENT ON COLUMN system.system_login_log.create_time IS '创建时间';
+COMMENT ON COLUMN system.system_login_log.updater IS '更新者';
+COMMENT ON COLUMN system.system_login_log.update_time IS '更新时间';
+COMMENT ON COLUMN system.system_login_log.deleted IS '是否删除';
+COMMENT ON COLUMN system.system_login_log.tenant_id IS '租户编号';
+COMMENT ON TABLE system.system_login_log IS '登录日志表';
@@ -13,7 +13,8 @@ pub struct Model {
     pub code: String,
     pub sort: i32,
     pub data_scope: String,
-    pub data_scope_dept_ids: Vec<String>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub data_scope_dept_ids: Json,
     pub status: String,
     pub r#type: String,
     pub remark: Option<String>,
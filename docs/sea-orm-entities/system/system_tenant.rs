@@ -14,7 +14,8 @@ pub struct Model {
     pub contact_name: String,
     pub contact_mobile: Option<String>,
     pub status: String,
-    pub websites: Option<String>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub websites: Json,
     pub package_id: String,
     pub expire_time: DateTime,
     pub account_count: i32,
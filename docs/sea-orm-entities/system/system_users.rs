@@ -14,7 +14,8 @@ pub struct Model {
     pub nickname: String,
     pub remark: Option<String>,
     pub dept_id: Option<String>,
-    pub post_ids: Option<Vec<String>>,
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub post_ids: Option<Json>,
     pub email: Option<String>,
     pub mobile: Option<String>,
     pub sex: Option<String>,